@@ -12,7 +12,7 @@ fi
 echo ""
 echo "cargo build"
 cargo build \
---target wasm32-unknown-unknown \
+--target wasm32v1-none \
 --release
 if [ $? -ne 0 ]; then
     echo "cargo build failed. Exiting..."
@@ -21,12 +21,12 @@ fi
 
 #----------------------------------------------------------------------
 echo ""
-echo "soroban contract deploy"
-soroban contract deploy \
---wasm target/wasm32-unknown-unknown/release/cowchain_farm.wasm \
+echo "stellar contract deploy"
+stellar contract deploy \
+--wasm target/wasm32v1-none/release/cowchain_farm.wasm \
 --rpc-url https://soroban-testnet.stellar.org:443 \
 --network-passphrase 'Test SDF Network ; September 2015'
 if [ $? -ne 0 ]; then
-    echo "soroban contract deploy failed. Exiting..."
+    echo "stellar contract deploy failed. Exiting..."
     exit 1
 fi
\ No newline at end of file
@@ -0,0 +1,465 @@
+// Cowchain Farm economy simulation.
+//
+// runs the contract in the host test environment with scripted farmers,
+// and prints daily treasury, cow population, appraisal and auction price figures.
+// the run is deterministic, the same arguments always print the same report.
+//
+// cargo run --release --example simulation -- --agents 100 --days 14 --format csv
+//
+// run time grows with the square of agents times days: every authorized call leaves a nonce
+// entry in the host test environment, which never evicts entries and copies the whole ledger
+// on every call. 100 agents for 14 days take about 1.5 minutes, 200 agents for 10 days about
+// 4 minutes, while 1000 agents for 90 days would take days.
+
+use cowchain_farm::constants::*;
+use cowchain_farm::enums::*;
+use cowchain_farm::{CowContract, CowContractClient};
+use soroban_sdk::testutils::{Address as _, EnvTestConfig, Ledger};
+use soroban_sdk::{token, Address, BytesN, Env, String, Symbol};
+use std::process::exit;
+
+const XLM: i128 = 10_000_000;
+const INIT_MESSAGE: &str = "y3QKiJ5iq7y9JGAfN23vY8hwXa";
+
+// agents act at most once every tick.
+const TICK: u32 = 720;
+
+struct Config {
+    agents: u32,
+    days: u32,
+    seed: u64,
+    treasury: i128,
+    agent_balance: i128,
+    json: bool,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Behaviour {
+    // feeds on time and sells grown cows.
+    Diligent,
+    // feeds late and sells grown cows.
+    Late,
+    // sometimes forgets to feed, cows may die of hunger.
+    Forgetful,
+    // feeds on time, auctions grown cows and bids on other auctions.
+    Trader,
+}
+
+struct Agent {
+    user: Address,
+    behaviour: Behaviour,
+    next_ledger: u32,
+    bought: u32,
+    // cow ordered on the last visit and its secret salt, hatched on the next visit.
+    order: Option<(String, BytesN<32>)>,
+    // every cow is sold or auctioned once it reaches this age.
+    selling_age: u32,
+}
+
+struct OpenAuction {
+    auction_id: String,
+    limit_ledger: u32,
+}
+
+#[derive(Default)]
+struct DailyReport {
+    cows_bought: u32,
+    cows_sold: u32,
+    cows_died: u32,
+    auctions_closed: u32,
+    auction_price_total: i128,
+    auction_sold: u32,
+}
+
+// xorshift random number generator, keeps agent behaviour reproducible.
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        let mut x = self.0;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.0 = x;
+        x
+    }
+
+    fn range(&mut self, low: u32, high: u32) -> u32 {
+        low + (self.next() % ((high - low + 1) as u64)) as u32
+    }
+}
+
+fn main() {
+    let config = parse_args();
+    let mut rng = Rng(config.seed.max(1));
+
+    let env = Env::new_with_config(EnvTestConfig {
+        capture_snapshot_at_drop: false,
+    });
+    env.mock_all_auths();
+    env.cost_estimate().budget().reset_unlimited();
+    // the host test environment charges every invocation for the whole ledger it holds,
+    // that is far from what a real transaction pays once the farm has thousands of cows.
+    env.cost_estimate().disable_resource_limits();
+    env.ledger().with_mut(|ledger| {
+        ledger.sequence_number = 100;
+        ledger.min_temp_entry_ttl = 16;
+        ledger.min_persistent_entry_ttl = 16;
+        ledger.max_entry_ttl = 6 * LEDGER_AMOUNT_IN_1_MONTH;
+    });
+
+    // register the farm and a Stellar Asset Contract as native token.
+    let contract_id = env.register(CowContract, ());
+    let client = CowContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let native_token = env.register_stellar_asset_contract_v2(admin.clone());
+    let token_client = token::Client::new(&env, &native_token.address());
+    let token_admin = token::StellarAssetClient::new(&env, &native_token.address());
+    token_admin.mint(&contract_id, &config.treasury);
+    let message = String::from_str(&env, INIT_MESSAGE);
+    client.init(&admin, &native_token.address(), &message);
+
+    let mut agents: Vec<Agent> = (0..config.agents)
+        .map(|index| {
+            let user = Address::generate(&env);
+            token_admin.mint(&user, &config.agent_balance);
+            // every farmer starts with a medium plot, enough room for the cows they win.
+            client.buy_plot(&user, &PlotTier::Medium);
+            let behaviour = match index % 4 {
+                0 => Behaviour::Diligent,
+                1 => Behaviour::Late,
+                2 => Behaviour::Forgetful,
+                _ => Behaviour::Trader,
+            };
+            Agent {
+                user,
+                behaviour,
+                next_ledger: env.ledger().sequence() + rng.range(0, LEDGER_AMOUNT_IN_24_HOURS),
+                bought: 0,
+                order: None,
+                selling_age: rng.range(7, 45) * LEDGER_AMOUNT_IN_24_HOURS,
+            }
+        })
+        .collect();
+    let mut auctions: Vec<OpenAuction> = Vec::new();
+
+    print_header(&config);
+    let end_ledger = env.ledger().sequence() + config.days * LEDGER_AMOUNT_IN_24_HOURS;
+    let mut report = DailyReport::default();
+    let mut day: u32 = 0;
+    while env.ledger().sequence() < end_ledger {
+        env.ledger()
+            .with_mut(|ledger| ledger.sequence_number += TICK);
+        let current_ledger = env.ledger().sequence();
+
+        // admin keeps the contract instance alive.
+        if current_ledger % LEDGER_AMOUNT_IN_24_HOURS < TICK {
+            client.bump_instance(&LEDGER_AMOUNT_IN_1_MONTH);
+        }
+
+        // keeper closes every auction that has reached its ledger limit.
+        auctions.retain(|auction| {
+            if auction.limit_ledger >= current_ledger {
+                return true;
+            }
+            let auction_data = client.get_auction(&auction.auction_id);
+            if auction_data.status == Status::Ok {
+                let auction_data = auction_data.auction_data.get(0).unwrap();
+                let is_cow_alive = client.get_cow(&auction_data.cow_id).status == Status::Ok;
+                let result = client.finalize_auction(&auction.auction_id);
+                if result.status == Status::Ok {
+                    report.auctions_closed += 1;
+                    if is_cow_alive && auction_data.owner != auction_data.highest_bidder.user {
+                        report.auction_sold += 1;
+                        report.auction_price_total += auction_data.highest_bidder.price;
+                    }
+                }
+            }
+            false
+        });
+
+        for (index, agent) in agents.iter_mut().enumerate() {
+            if agent.next_ledger > current_ledger {
+                continue;
+            }
+            act(
+                &env,
+                &client,
+                &token_client,
+                &mut rng,
+                index,
+                agent,
+                &mut auctions,
+                &mut report,
+            );
+        }
+
+        // print the report at the end of every simulated day.
+        if current_ledger % LEDGER_AMOUNT_IN_24_HOURS < TICK {
+            day += 1;
+            print_day(
+                &config,
+                &client,
+                &token_client,
+                &contract_id,
+                &agents,
+                day,
+                &report,
+            );
+            report = DailyReport::default();
+        }
+    }
+    print_footer(&config);
+}
+
+#[allow(clippy::too_many_arguments)]
+fn act(
+    env: &Env,
+    client: &CowContractClient,
+    token_client: &token::Client,
+    rng: &mut Rng,
+    index: usize,
+    agent: &mut Agent,
+    auctions: &mut Vec<OpenAuction>,
+    report: &mut DailyReport,
+) {
+    let current_ledger = env.ledger().sequence();
+
+    // hatch the cow ordered on the last visit.
+    if let Some((cow_id, salt)) = agent.order.take() {
+        client.hatch_cow(&agent.user, &cow_id, &salt);
+    }
+
+    // feed every cow, then drop the cows that died since the last visit.
+    if agent.bought > 0 {
+        let mut start: u32 = 0;
+        loop {
+            start = client.feed_all(&agent.user, &start).next;
+            if start == 0 {
+                break;
+            }
+        }
+        let clean_result = client.clean_ownership(&agent.user);
+        report.cows_died += clean_result.dead_cow_ids.len();
+    }
+
+    // sell or auction the cows that have grown old enough.
+    let cows = client.get_all_cow(&agent.user).data;
+    let mut alive_cows = cows.len();
+    for cow_data in cows.iter() {
+        let cow_age = current_ledger - cow_data.born_ledger;
+        if cow_age < agent.selling_age || !cow_data.auction_id.is_empty() {
+            continue;
+        }
+        if agent.behaviour == Behaviour::Trader {
+            let appraisal = client.cow_appraisal(&cow_data.id);
+            if appraisal.status != Status::Ok {
+                continue;
+            }
+            let price = (appraisal.price * 9 / 10 / XLM).max(1) as u32;
+            let external_ref = String::from_str(env, "");
+            let result = client.register_auction(&agent.user, &cow_data.id, &external_ref, &price);
+            if result.status == Status::Ok {
+                let auction_data = result.auction_data.get(0).unwrap();
+                auctions.push(OpenAuction {
+                    auction_id: auction_data.auction_id,
+                    limit_ledger: auction_data.auction_limit_ledger,
+                });
+            }
+        } else if client.sell_cow(&agent.user, &cow_data.id).status == Status::Ok {
+            report.cows_sold += 1;
+            alive_cows -= 1;
+        }
+    }
+
+    // traders outbid each other on open auctions.
+    if agent.behaviour == Behaviour::Trader && !auctions.is_empty() {
+        let auction = &auctions[rng.range(0, auctions.len() as u32 - 1) as usize];
+        let auction_data = client.get_auction(&auction.auction_id);
+        if auction_data.status == Status::Ok {
+            let auction_data = auction_data.auction_data.get(0).unwrap();
+            // trader never bids above its own valuation of the cow.
+            let appraisal = client.cow_appraisal(&auction_data.cow_id);
+            let valuation = appraisal.price / XLM * rng.range(100, 120) as i128 / 100;
+            let highest_price = auction_data.highest_bidder.price;
+            let bid_price = highest_price + (highest_price * rng.range(1, 10) as i128 / 100).max(1);
+            let balance_after_bid =
+                token_client.balance(&agent.user) - MINIMUM_USER_BALANCE - bid_price * XLM;
+            if appraisal.status == Status::Ok
+                && bid_price <= valuation
+                && auction_data.owner != agent.user
+                && auction_data.highest_bidder.user != agent.user
+                && balance_after_bid > 1_000 * XLM
+            {
+                client.bidding(&agent.user, &auction.auction_id, &(bid_price as u32));
+            }
+        }
+    }
+
+    // order a new cow when the farm is empty.
+    if alive_cows == 0 {
+        let cow_breed = match rng.range(1, 10) {
+            1..=3 => CowBreed::Jersey,
+            4..=5 => CowBreed::Limousin,
+            6..=7 => CowBreed::Hallikar,
+            8 => CowBreed::Hereford,
+            9 => CowBreed::Holstein,
+            _ => CowBreed::Simmental,
+        };
+        let cow_name = Symbol::new(env, &format!("a{}c{}", index, agent.bought + 1));
+        let external_ref = String::from_str(env, "");
+        let salt = BytesN::from_array(env, &rng.next().to_be_bytes().repeat(4).try_into().unwrap());
+        let commitment: BytesN<32> = env.crypto().sha256(&salt.clone().into()).into();
+        let result = client.order_cow(
+            &agent.user,
+            &cow_name,
+            &external_ref,
+            &cow_breed,
+            &commitment,
+        );
+        if result.status == Status::Ok {
+            agent.bought += 1;
+            agent.order = Some((result.order.get(0).unwrap().cow_id, salt));
+            report.cows_bought += 1;
+        }
+    }
+
+    // schedule the next visit based on the agent feeding behaviour.
+    let feeding_interval = match agent.behaviour {
+        Behaviour::Diligent | Behaviour::Trader => rng.range(WELL_FED + 1, ON_TIME_FEED),
+        Behaviour::Late => rng.range(ON_TIME_FEED + 1, LATE_FEED),
+        Behaviour::Forgetful => rng.range(LATE_FEED + 1, LEDGER_AMOUNT_IN_24_HOURS + TICK),
+    };
+    agent.next_ledger = current_ledger + feeding_interval;
+}
+
+fn print_header(config: &Config) {
+    if config.json {
+        println!("[");
+    } else {
+        println!(
+            "day,ledger,treasury_xlm,cow_population,calf_population,average_appraisal_xlm,\
+             cows_bought,cows_sold,cows_died,auctions_closed,auctions_sold,average_auction_price_xlm"
+        );
+    }
+}
+
+fn print_footer(config: &Config) {
+    if config.json {
+        println!("]");
+    }
+}
+
+fn print_day(
+    config: &Config,
+    client: &CowContractClient,
+    token_client: &token::Client,
+    contract_id: &Address,
+    agents: &[Agent],
+    day: u32,
+    report: &DailyReport,
+) {
+    let mut cow_population: u32 = 0;
+    let mut calf_population: u32 = 0;
+    let mut appraisal_total: i128 = 0;
+    let mut appraisal_count: i128 = 0;
+    for agent in agents {
+        for cow_data in client.get_all_cow(&agent.user).data.iter() {
+            cow_population += 1;
+            if cow_data.life_stage == CowLifeStage::Calf {
+                calf_population += 1;
+                continue;
+            }
+            let appraisal = client.cow_appraisal(&cow_data.id);
+            if appraisal.status == Status::Ok {
+                appraisal_total += appraisal.price;
+                appraisal_count += 1;
+            }
+        }
+    }
+
+    let ledger = client.env.ledger().sequence();
+    let treasury = token_client.balance(contract_id) as f64 / XLM as f64;
+    let average_appraisal = appraisal_total as f64 / appraisal_count.max(1) as f64 / XLM as f64;
+    let average_auction_price =
+        report.auction_price_total as f64 / report.auction_sold.max(1) as f64;
+    if config.json {
+        let separator = if day == config.days { "" } else { "," };
+        println!(
+            "  {{\"day\": {}, \"ledger\": {}, \"treasury_xlm\": {:.2}, \"cow_population\": {}, \
+             \"calf_population\": {}, \"average_appraisal_xlm\": {:.2}, \"cows_bought\": {}, \
+             \"cows_sold\": {}, \"cows_died\": {}, \"auctions_closed\": {}, \
+             \"auctions_sold\": {}, \"average_auction_price_xlm\": {:.2}}}{}",
+            day,
+            ledger,
+            treasury,
+            cow_population,
+            calf_population,
+            average_appraisal,
+            report.cows_bought,
+            report.cows_sold,
+            report.cows_died,
+            report.auctions_closed,
+            report.auction_sold,
+            average_auction_price,
+            separator
+        );
+    } else {
+        println!(
+            "{},{},{:.2},{},{},{:.2},{},{},{},{},{},{:.2}",
+            day,
+            ledger,
+            treasury,
+            cow_population,
+            calf_population,
+            average_appraisal,
+            report.cows_bought,
+            report.cows_sold,
+            report.cows_died,
+            report.auctions_closed,
+            report.auction_sold,
+            average_auction_price
+        );
+    }
+}
+
+fn parse_args() -> Config {
+    let mut config = Config {
+        agents: 100,
+        days: 30,
+        seed: 1,
+        treasury: 1_000_000 * XLM,
+        agent_balance: 100_000 * XLM,
+        json: false,
+    };
+
+    let args: Vec<std::string::String> = std::env::args().skip(1).collect();
+    let mut index = 0;
+    while index < args.len() {
+        let value = args.get(index + 1).map(|value| value.as_str());
+        match (args[index].as_str(), value) {
+            ("--agents", Some(value)) => config.agents = parse_number(value),
+            ("--days", Some(value)) => config.days = parse_number(value),
+            ("--seed", Some(value)) => config.seed = parse_number(value),
+            ("--treasury", Some(value)) => config.treasury = parse_number::<i128>(value) * XLM,
+            ("--balance", Some(value)) => config.agent_balance = parse_number::<i128>(value) * XLM,
+            ("--format", Some("csv")) => config.json = false,
+            ("--format", Some("json")) => config.json = true,
+            _ => usage(),
+        }
+        index += 2;
+    }
+    config
+}
+
+fn parse_number<T: std::str::FromStr>(value: &str) -> T {
+    value.parse().unwrap_or_else(|_| usage())
+}
+
+fn usage() -> ! {
+    eprintln!(
+        "usage: simulation [--agents N] [--days N] [--seed N] [--treasury XLM] [--balance XLM] \
+         [--format csv|json]"
+    );
+    exit(2);
+}
@@ -13,6 +13,47 @@ pub const LEDGER_AMOUNT_IN_3_DAYS: u32 = 51840;
 pub const LEDGER_AMOUNT_IN_1_WEEK: u32 = 120960;
 pub const LEDGER_AMOUNT_IN_1_MONTH: u32 = 483840;
 
+// Cow life cycle based on breed (in ledger age).
+// [young, adult, senior, max lifespan]
+// every cow is a calf for the first 3 days, and dies of old age after its max lifespan.
+//
+pub const JERSEY_LIFE_CYCLE: [u32; 4] = [
+    LEDGER_AMOUNT_IN_3_DAYS,
+    7 * LEDGER_AMOUNT_IN_24_HOURS,
+    60 * LEDGER_AMOUNT_IN_24_HOURS,
+    90 * LEDGER_AMOUNT_IN_24_HOURS,
+];
+pub const LIMOUSIN_LIFE_CYCLE: [u32; 4] = [
+    LEDGER_AMOUNT_IN_3_DAYS,
+    10 * LEDGER_AMOUNT_IN_24_HOURS,
+    60 * LEDGER_AMOUNT_IN_24_HOURS,
+    90 * LEDGER_AMOUNT_IN_24_HOURS,
+];
+pub const HALLIKAR_LIFE_CYCLE: [u32; 4] = [
+    LEDGER_AMOUNT_IN_3_DAYS,
+    10 * LEDGER_AMOUNT_IN_24_HOURS,
+    75 * LEDGER_AMOUNT_IN_24_HOURS,
+    105 * LEDGER_AMOUNT_IN_24_HOURS,
+];
+pub const HEREFORD_LIFE_CYCLE: [u32; 4] = [
+    LEDGER_AMOUNT_IN_3_DAYS,
+    10 * LEDGER_AMOUNT_IN_24_HOURS,
+    75 * LEDGER_AMOUNT_IN_24_HOURS,
+    105 * LEDGER_AMOUNT_IN_24_HOURS,
+];
+pub const HOLSTEIN_LIFE_CYCLE: [u32; 4] = [
+    LEDGER_AMOUNT_IN_3_DAYS,
+    14 * LEDGER_AMOUNT_IN_24_HOURS,
+    90 * LEDGER_AMOUNT_IN_24_HOURS,
+    120 * LEDGER_AMOUNT_IN_24_HOURS,
+];
+pub const SIMMENTAL_LIFE_CYCLE: [u32; 4] = [
+    LEDGER_AMOUNT_IN_3_DAYS,
+    14 * LEDGER_AMOUNT_IN_24_HOURS,
+    90 * LEDGER_AMOUNT_IN_24_HOURS,
+    120 * LEDGER_AMOUNT_IN_24_HOURS,
+];
+
 // Cow price based on breed (in XLM unit).
 //
 pub const JERSEY_PRICE: i128 = 1000;
@@ -34,8 +75,190 @@ pub const LATE_REWARD: i128 = 25;
 pub const FORGET_FINE: i128 = 100;
 pub const PRECISION_100_PERCENT: i128 = 10_000;
 
+// Senior cow depreciation, with 2 digit decimal precision.
+// 1% fines for every day after the cow become senior -- 100 (1 x 100)
+//
+pub const SENIOR_DAILY_FINE: i128 = 100;
+
+// Cow condition, starting value and limit.
+// weight is in kilogram, while health and mood are points from 0 to 100.
+//
+pub const INITIAL_WEIGHT: u32 = 40;
+pub const MIN_WEIGHT: u32 = 20;
+pub const INITIAL_HEALTH: u32 = 80;
+pub const MAX_HEALTH: u32 = 100;
+pub const INITIAL_MOOD: u32 = 50;
+pub const MAX_MOOD: u32 = 100;
+
+// Cow condition changes for every feeding event.
+//
+pub const ON_TIME_WEIGHT_GAIN: u32 = 2;
+pub const ON_TIME_HEALTH_GAIN: u32 = 5;
+pub const ON_TIME_MOOD_GAIN: u32 = 10;
+pub const LATE_WEIGHT_GAIN: u32 = 1;
+pub const LATE_MOOD_LOSS: u32 = 5;
+pub const FORGET_WEIGHT_LOSS: u32 = 3;
+pub const FORGET_HEALTH_LOSS: u32 = 10;
+pub const FORGET_MOOD_LOSS: u32 = 20;
+
+// Cow condition multiplier, with 2 digit decimal precision.
+// compared to the starting condition, it will give you:
+// 0.1% rewards or fines for every health point -- 10 (0.1 x 100)
+// 0.05% rewards or fines for every kilogram of weight -- 5 (0.05 x 100)
+//
+pub const HEALTH_POINT_REWARD: i128 = 10;
+pub const WEIGHT_POINT_REWARD: i128 = 5;
+
 // Cow feeding ledger limit.
 //
 pub const WELL_FED: u32 = 4320;
 pub const ON_TIME_FEED: u32 = 8640;
 pub const LATE_FEED: u32 = 12960;
+
+// Maximum number of cows that can be fed in a single batch feeding call.
+// keeps feed_many and feed_all under the transaction resource limits.
+//
+pub const MAX_COWS_PER_FEEDING: u32 = 10;
+
+// Feeding plan keeper fee (in stroops unit).
+// keeper receive 0.1 XLM from the plan balance for every cow they feed.
+// 0.1 XLM is equal to 1_000_000 stroops
+//
+pub const FEEDING_PLAN_KEEPER_FEE: i128 = 1_000_000;
+
+// Cow daily milk production based on breed (in milliliter unit).
+// only adult or senior female cow with at least 60 health points will produce milk,
+// and the amount is scaled by the cow health points.
+//
+pub const JERSEY_DAILY_MILK: i128 = 20_000;
+pub const LIMOUSIN_DAILY_MILK: i128 = 8_000;
+pub const HALLIKAR_DAILY_MILK: i128 = 5_000;
+pub const HEREFORD_DAILY_MILK: i128 = 8_000;
+pub const HOLSTEIN_DAILY_MILK: i128 = 30_000;
+pub const SIMMENTAL_DAILY_MILK: i128 = 15_000;
+pub const MILKING_MIN_HEALTH: u32 = 60;
+
+// Milk price paid by supplier (in stroops unit).
+// 0.5 XLM for every liter of milk, equal to 5_000 stroops for every milliliter.
+//
+pub const MILK_PRICE_PER_MILLILITER: i128 = 5_000;
+
+// Maximum number of cows that can be milked in a single harvest call.
+//
+pub const MAX_COWS_PER_HARVEST: u32 = 20;
+
+// Maximum number of records returned by a single paginated query.
+//
+pub const MAX_PAGE_SIZE: u32 = 50;
+
+// Cow NFT collection metadata.
+// token URI is the base URI set by admin followed by the cow ID.
+//
+pub const COW_NFT_NAME: &str = "Cowchain Farm Cow";
+pub const COW_NFT_SYMBOL: &str = "COW";
+pub const MAX_BASE_URI_LENGTH: u32 = 128;
+pub const MAX_TOKEN_URI_LENGTH: usize = 256;
+
+// Cow renaming fee (in stroops unit) and cooldown.
+// 5 XLM for every rename, and a cow can only be renamed once a week.
+//
+pub const RENAME_FEE: i128 = 50_000_000;
+pub const RENAME_COOLDOWN: u32 = LEDGER_AMOUNT_IN_1_WEEK;
+
+// Maximum length of cow metadata.
+//
+pub const MAX_COW_DESCRIPTION_LENGTH: u32 = 256;
+pub const MAX_COW_TRAIT_LENGTH: u32 = 32;
+
+// Maximum length of farmer display name.
+//
+pub const MAX_DISPLAY_NAME_LENGTH: u32 = 32;
+
+// Number of top entries kept on every leaderboard.
+//
+pub const LEADERBOARD_SIZE: u32 = 10;
+
+// Achievement goals and maximum reward (in stroops unit).
+// a 30-day streak keeps the cow fed ON TIME, without a break, for 30 days.
+//
+pub const STREAK_ACHIEVEMENT_DURATION: u32 = 30 * LEDGER_AMOUNT_IN_24_HOURS;
+pub const AUCTIONS_WON_ACHIEVEMENT: u32 = 10;
+pub const MAX_ACHIEVEMENT_REWARD: i128 = 100_000_000;
+
+// Cow and auction IDs are generated by the contract from a counter.
+// client may attach an external reference for its own bookkeeping.
+//
+pub const COW_ID_PREFIX: &str = "COW";
+pub const AUCTION_ID_PREFIX: &str = "AUC";
+pub const MAX_EXTERNAL_REF_LENGTH: u32 = 64;
+
+// Cow order hatching window (in ledger amount).
+// an ordered cow can be hatched 12 ledgers (1 minute) after the order,
+// and the order is forfeited if it is not hatched within 1 week.
+//
+pub const HATCH_DELAY: u32 = 12;
+pub const HATCH_WINDOW: u32 = LEDGER_AMOUNT_IN_1_WEEK;
+
+// Cow genome layout.
+// every cow has a 16 bytes genome drawn when it is hatched, and every gene is a single byte.
+// bytes 6 to 15 are not used yet, they are kept for future traits.
+//
+pub const COW_GENOME_LENGTH: usize = 16;
+pub const COAT_GENE: usize = 0;
+pub const HORNS_GENE: usize = 1;
+pub const SIZE_GENE: usize = 2;
+pub const FEEDING_TOLERANCE_GENE: usize = 3;
+pub const MILK_YIELD_GENE: usize = 4;
+pub const APPRAISAL_GENE: usize = 5;
+
+// Visible trait gene thresholds and rarity points.
+// a gene picks the first variant whose threshold is above the gene value,
+// e.g. coat gene 0-99 is Brown, 100-159 is Black, ... and 253-255 is Golden.
+// every variant gives rarity points, rarer variants give more points.
+//
+pub const COAT_THRESHOLDS: [u32; 6] = [100, 160, 210, 240, 253, 256];
+pub const COAT_RARITY_POINTS: [u32; 6] = [0, 0, 1, 2, 3, 4];
+pub const HORNS_THRESHOLDS: [u32; 4] = [128, 220, 250, 256];
+pub const HORNS_RARITY_POINTS: [u32; 4] = [0, 1, 2, 3];
+pub const SIZE_THRESHOLDS: [u32; 4] = [64, 192, 248, 256];
+pub const SIZE_RARITY_POINTS: [u32; 4] = [1, 0, 2, 3];
+
+// Cow rarity tier based on total rarity points of the visible traits.
+// [uncommon, rare, epic, legendary] minimum points, every tier below uncommon is common.
+// rarer cows get an appraisal bonus, with 2 digit decimal precision.
+// [common, uncommon, rare, epic, legendary] -- 0%, 1%, 2.5%, 5%, 10%
+//
+pub const RARITY_THRESHOLDS: [u32; 4] = [2, 4, 6, 8];
+pub const RARITY_APPRAISAL_BONUS: [i128; 5] = [0, 100, 250, 500, 1000];
+
+// Hidden stat modifiers, scaled linearly by their gene from 0 to the maximum.
+// feeding tolerance extends the ON TIME and LATE feeding windows, up to 720 ledgers (1 hour).
+// milk yield bonus up to 20%, and appraisal bonus up to 10%, with 2 digit decimal precision.
+//
+pub const MAX_FEEDING_TOLERANCE: u32 = 720;
+pub const MAX_MILK_YIELD_BONUS: i128 = 2000;
+pub const MAX_APPRAISAL_BONUS: i128 = 1000;
+
+// Farm land plot tiers.
+// [small, medium, large] cow capacity, buying price and weekly rent price (in XLM unit).
+// a rented plot holds no new cows once its rent is over, until the rent is renewed.
+//
+pub const PLOT_ID_PREFIX: &str = "PLT";
+pub const PLOT_CAPACITY: [u32; 3] = [5, 10, 20];
+pub const PLOT_PRICE: [i128; 3] = [200, 500, 1200];
+pub const PLOT_RENT_PRICE: [i128; 3] = [10, 25, 60];
+pub const PLOT_RENT_DURATION: u32 = LEDGER_AMOUNT_IN_1_WEEK;
+pub const MAX_PLOTS_PER_USER: u32 = 5;
+
+// Plot upgrades price (in XLM unit) and feeding window extension (in ledger amount).
+// a barn extends the ON TIME and LATE feeding windows by 180 ledgers (15 minutes),
+// and a pasture by 360 ledgers (30 minutes), for every cow assigned to the plot.
+//
+pub const BARN_PRICE: i128 = 100;
+pub const PASTURE_PRICE: i128 = 150;
+pub const BARN_FEEDING_TOLERANCE: u32 = 180;
+pub const PASTURE_FEEDING_TOLERANCE: u32 = 360;
+
+// Maximum number of entries that can be bumped or checked in a single call.
+//
+pub const MAX_ENTRIES_PER_EXTEND: u32 = 50;
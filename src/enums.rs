@@ -1,4 +1,4 @@
-use soroban_sdk::contracttype;
+use soroban_sdk::{contracttype, Address, String, Symbol};
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
@@ -7,6 +7,34 @@ pub enum DataKey {
     InitializedLedger,
     NativeToken,
     AuctionList,
+    Caretaker(Address, Address),
+    FeedingPlan(Address),
+    MilkBalance(Address),
+    CowOwner(String),
+    Gift(String),
+    BaseUri,
+    Approval(String),
+    Operator(Address, Address),
+    Moderator,
+    CowName(Symbol),
+    NameRestriction(Symbol),
+    CowMetadata(String),
+    CowCounter,
+    AuctionCounter,
+    LiveUntil(FarmEntry),
+    CowOrder(String),
+    HatchEntropy(String),
+    Plot(String),
+    PlotList(Address),
+    PlotCounter,
+    Profile(Address),
+    Leaderboard(LeaderboardKind),
+    LeaderboardSeason,
+    SeasonAuctionsWon(u32, Address),
+    AchievementReward(Achievement),
+    PlanDeposits,
+    AuctionEscrow,
+    HerdValue(Address),
 }
 
 #[contracttype]
@@ -32,6 +60,15 @@ pub enum Status {
     BidIsOpen,
     CannotBidLower,
     NameAlreadyExist,
+    ExceedBatchLimit,
+    Unauthorized,
+    Expired,
+    NoMilk,
+    OldAge,
+    NameRestricted,
+    NotReady,
+    NoCapacity,
+    NotMigrated,
 }
 
 #[contracttype]
@@ -46,8 +83,114 @@ pub enum CowBreed {
 }
 
 #[contracttype]
-#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 pub enum CowGender {
     Male = 1,
     Female = 2,
 }
+
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum CowLifeStage {
+    Calf = 1,
+    Young = 2,
+    Adult = 3,
+    Senior = 4,
+}
+
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum CowCoat {
+    Brown = 1,
+    Black = 2,
+    White = 3,
+    Red = 4,
+    Spotted = 5,
+    Golden = 6,
+}
+
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum CowHorns {
+    Polled = 1,
+    Short = 2,
+    Long = 3,
+    Curved = 4,
+}
+
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum CowSize {
+    Small = 1,
+    Medium = 2,
+    Large = 3,
+    Giant = 4,
+}
+
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum CowRarity {
+    Common = 1,
+    Uncommon = 2,
+    Rare = 3,
+    Epic = 4,
+    Legendary = 5,
+}
+
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum PlotTier {
+    Small = 1,
+    Medium = 2,
+    Large = 3,
+}
+
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum PlotUpgrade {
+    Barn = 1,
+    Pasture = 2,
+}
+
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum LeaderboardKind {
+    BestFedCow = 1,
+    RichestHerd = 2,
+    MostAuctionsWon = 3,
+}
+
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum Achievement {
+    FirstCow = 1,
+    ThirtyDayStreak = 2,
+    TenAuctionsWon = 3,
+    RaisedCalf = 4,
+}
+
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum AuctionFilter {
+    All = 1,
+    Open = 2,
+    Closed = 3,
+}
+
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum NameRestriction {
+    Free = 0,
+    Reserved = 1,
+    Banned = 2,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum FarmEntry {
+    Ownership(Address),
+    Cow(String),
+    Auction(String),
+    AuctionList,
+    CowName(Symbol),
+}
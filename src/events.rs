@@ -0,0 +1,723 @@
+//! Cowchain Farm event schema.
+//!
+//! Every farm event is published with the topics `(event_name, version, account, cow_id)`,
+//! where `account` is the address the event is about (owner, bidder, sender or admin), and
+//! `cow_id` is only present for events about a single cow. The data of every farm event is a
+//! single details struct, and the same event name always carries the same details struct.
+//!
+//! `EVENT_VERSION` is bumped whenever a topic or a details struct changes, so indexers can
+//! detect schema changes from the topics alone.
+//!
+//! Standard NFT events (`transfer`, `approve` and `approve_for_all`) follow the NFT interface
+//! layout instead, and are not versioned. The farm transfer event is named `cow_xfer`, so it
+//! never shares a topic with the NFT `transfer` event.
+
+use crate::types::*;
+use soroban_sdk::{contractevent, Address, String};
+
+pub const EVENT_VERSION: u32 = 1;
+
+/// Contract initialized.
+#[contractevent(topics = ["init"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InitEvent {
+    #[topic]
+    pub version: u32,
+    #[topic]
+    pub admin: Address,
+    pub details: InitEventDetails,
+}
+
+impl InitEvent {
+    pub fn new(details: InitEventDetails) -> Self {
+        Self {
+            version: EVENT_VERSION,
+            admin: details.admin.clone(),
+            details,
+        }
+    }
+}
+
+/// Contract WASM upgraded.
+#[contractevent(topics = ["upgrade"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UpgradeEvent {
+    #[topic]
+    pub version: u32,
+    #[topic]
+    pub admin: Address,
+    pub details: UpgradeEventDetails,
+}
+
+impl UpgradeEvent {
+    pub fn new(details: UpgradeEventDetails) -> Self {
+        Self {
+            version: EVENT_VERSION,
+            admin: details.admin.clone(),
+            details,
+        }
+    }
+}
+
+/// Contract instance lifetime bumped.
+#[contractevent(topics = ["bump_instance"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BumpInstanceEvent {
+    #[topic]
+    pub version: u32,
+    #[topic]
+    pub admin: Address,
+    pub details: BumpEventDetails,
+}
+
+impl BumpInstanceEvent {
+    pub fn new(details: BumpEventDetails) -> Self {
+        Self {
+            version: EVENT_VERSION,
+            admin: details.admin.clone(),
+            details,
+        }
+    }
+}
+
+/// Donation received.
+#[contractevent(topics = ["donation"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DonationEvent {
+    #[topic]
+    pub version: u32,
+    #[topic]
+    pub from: Address,
+    pub details: DonationEventDetails,
+}
+
+impl DonationEvent {
+    pub fn new(details: DonationEventDetails) -> Self {
+        Self {
+            version: EVENT_VERSION,
+            from: details.from.clone(),
+            details,
+        }
+    }
+}
+
+/// Cow hatched from an order and delivered to the owner.
+#[contractevent(topics = ["buy"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BuyEvent {
+    #[topic]
+    pub version: u32,
+    #[topic]
+    pub owner: Address,
+    #[topic]
+    pub cow_id: String,
+    pub details: CowEventDetails,
+}
+
+impl BuyEvent {
+    pub fn new(details: CowEventDetails) -> Self {
+        Self {
+            version: EVENT_VERSION,
+            owner: details.owner.clone(),
+            cow_id: details.id.clone(),
+            details,
+        }
+    }
+}
+
+/// Cow saved by an older contract version upgraded to the current cow data layout.
+#[contractevent(topics = ["migrate"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MigrateEvent {
+    #[topic]
+    pub version: u32,
+    #[topic]
+    pub owner: Address,
+    #[topic]
+    pub cow_id: String,
+    pub details: CowEventDetails,
+}
+
+impl MigrateEvent {
+    pub fn new(details: CowEventDetails) -> Self {
+        Self {
+            version: EVENT_VERSION,
+            owner: details.owner.clone(),
+            cow_id: details.id.clone(),
+            details,
+        }
+    }
+}
+
+/// Cow ordered from supplier, waiting to be hatched.
+#[contractevent(topics = ["order"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OrderEvent {
+    #[topic]
+    pub version: u32,
+    #[topic]
+    pub owner: Address,
+    #[topic]
+    pub cow_id: String,
+    pub details: CowOrder,
+}
+
+impl OrderEvent {
+    pub fn new(details: CowOrder) -> Self {
+        Self {
+            version: EVENT_VERSION,
+            owner: details.owner.clone(),
+            cow_id: details.cow_id.clone(),
+            details,
+        }
+    }
+}
+
+/// Cow sold to supplier.
+#[contractevent(topics = ["sell"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SellEvent {
+    #[topic]
+    pub version: u32,
+    #[topic]
+    pub owner: Address,
+    #[topic]
+    pub cow_id: String,
+    pub details: CowEventDetails,
+}
+
+impl SellEvent {
+    pub fn new(details: CowEventDetails) -> Self {
+        Self {
+            version: EVENT_VERSION,
+            owner: details.owner.clone(),
+            cow_id: details.id.clone(),
+            details,
+        }
+    }
+}
+
+/// Cow fed.
+#[contractevent(topics = ["feed"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeedEvent {
+    #[topic]
+    pub version: u32,
+    #[topic]
+    pub owner: Address,
+    #[topic]
+    pub cow_id: String,
+    pub details: CowEventDetails,
+}
+
+impl FeedEvent {
+    pub fn new(details: CowEventDetails) -> Self {
+        Self {
+            version: EVENT_VERSION,
+            owner: details.owner.clone(),
+            cow_id: details.id.clone(),
+            details,
+        }
+    }
+}
+
+/// Several cows fed in a single call.
+#[contractevent(topics = ["feed_many"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeedManyEvent {
+    #[topic]
+    pub version: u32,
+    #[topic]
+    pub owner: Address,
+    pub details: FeedManyEventDetails,
+}
+
+impl FeedManyEvent {
+    pub fn new(details: FeedManyEventDetails) -> Self {
+        Self {
+            version: EVENT_VERSION,
+            owner: details.owner.clone(),
+            details,
+        }
+    }
+}
+
+/// Caretaker approved.
+#[contractevent(topics = ["caretaker"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CaretakerEvent {
+    #[topic]
+    pub version: u32,
+    #[topic]
+    pub owner: Address,
+    pub details: CaretakerData,
+}
+
+impl CaretakerEvent {
+    pub fn new(details: CaretakerData) -> Self {
+        Self {
+            version: EVENT_VERSION,
+            owner: details.owner.clone(),
+            details,
+        }
+    }
+}
+
+/// Caretaker revoked.
+#[contractevent(topics = ["revoke"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RevokeEvent {
+    #[topic]
+    pub version: u32,
+    #[topic]
+    pub owner: Address,
+    pub details: CaretakerData,
+}
+
+impl RevokeEvent {
+    pub fn new(details: CaretakerData) -> Self {
+        Self {
+            version: EVENT_VERSION,
+            owner: details.owner.clone(),
+            details,
+        }
+    }
+}
+
+/// Feeding plan registered.
+#[contractevent(topics = ["plan"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PlanEvent {
+    #[topic]
+    pub version: u32,
+    #[topic]
+    pub owner: Address,
+    pub details: FeedingPlanData,
+}
+
+impl PlanEvent {
+    pub fn new(details: FeedingPlanData) -> Self {
+        Self {
+            version: EVENT_VERSION,
+            owner: details.owner.clone(),
+            details,
+        }
+    }
+}
+
+/// Feeding plan cancelled.
+#[contractevent(topics = ["plan_stop"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PlanStopEvent {
+    #[topic]
+    pub version: u32,
+    #[topic]
+    pub owner: Address,
+    pub details: FeedingPlanData,
+}
+
+impl PlanStopEvent {
+    pub fn new(details: FeedingPlanData) -> Self {
+        Self {
+            version: EVENT_VERSION,
+            owner: details.owner.clone(),
+            details,
+        }
+    }
+}
+
+/// Feeding plan run by a keeper.
+#[contractevent(topics = ["plan_run"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PlanRunEvent {
+    #[topic]
+    pub version: u32,
+    #[topic]
+    pub owner: Address,
+    pub details: FeedingPlanEventDetails,
+}
+
+impl PlanRunEvent {
+    pub fn new(details: FeedingPlanEventDetails) -> Self {
+        Self {
+            version: EVENT_VERSION,
+            owner: details.owner.clone(),
+            details,
+        }
+    }
+}
+
+/// Milk harvested.
+#[contractevent(topics = ["harvest"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HarvestEvent {
+    #[topic]
+    pub version: u32,
+    #[topic]
+    pub owner: Address,
+    pub details: MilkEventDetails,
+}
+
+impl HarvestEvent {
+    pub fn new(details: MilkEventDetails) -> Self {
+        Self {
+            version: EVENT_VERSION,
+            owner: details.owner.clone(),
+            details,
+        }
+    }
+}
+
+/// Milk sold to supplier.
+#[contractevent(topics = ["sell_milk"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SellMilkEvent {
+    #[topic]
+    pub version: u32,
+    #[topic]
+    pub owner: Address,
+    pub details: MilkEventDetails,
+}
+
+impl SellMilkEvent {
+    pub fn new(details: MilkEventDetails) -> Self {
+        Self {
+            version: EVENT_VERSION,
+            owner: details.owner.clone(),
+            details,
+        }
+    }
+}
+
+/// Cow gift offered.
+#[contractevent(topics = ["gift"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GiftEvent {
+    #[topic]
+    pub version: u32,
+    #[topic]
+    pub from: Address,
+    #[topic]
+    pub cow_id: String,
+    pub details: GiftData,
+}
+
+impl GiftEvent {
+    pub fn new(details: GiftData) -> Self {
+        Self {
+            version: EVENT_VERSION,
+            from: details.from.clone(),
+            cow_id: details.cow_id.clone(),
+            details,
+        }
+    }
+}
+
+/// Cow gift declined.
+#[contractevent(topics = ["gift_deny"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GiftDenyEvent {
+    #[topic]
+    pub version: u32,
+    #[topic]
+    pub from: Address,
+    #[topic]
+    pub cow_id: String,
+    pub details: GiftData,
+}
+
+impl GiftDenyEvent {
+    pub fn new(details: GiftData) -> Self {
+        Self {
+            version: EVENT_VERSION,
+            from: details.from.clone(),
+            cow_id: details.cow_id.clone(),
+            details,
+        }
+    }
+}
+
+/// Cow moved to another farmer.
+#[contractevent(topics = ["cow_xfer"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TransferEvent {
+    #[topic]
+    pub version: u32,
+    #[topic]
+    pub from: Address,
+    #[topic]
+    pub cow_id: String,
+    pub details: TransferEventDetails,
+}
+
+impl TransferEvent {
+    pub fn new(details: TransferEventDetails) -> Self {
+        Self {
+            version: EVENT_VERSION,
+            from: details.from.clone(),
+            cow_id: details.cow_id.clone(),
+            details,
+        }
+    }
+}
+
+/// Cow renamed.
+#[contractevent(topics = ["rename"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RenameEvent {
+    #[topic]
+    pub version: u32,
+    #[topic]
+    pub owner: Address,
+    #[topic]
+    pub cow_id: String,
+    pub details: RenameEventDetails,
+}
+
+impl RenameEvent {
+    pub fn new(details: RenameEventDetails) -> Self {
+        Self {
+            version: EVENT_VERSION,
+            owner: details.owner.clone(),
+            cow_id: details.cow_id.clone(),
+            details,
+        }
+    }
+}
+
+/// Cow name reserved, banned or freed.
+#[contractevent(topics = ["name_rule"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NameRuleEvent {
+    #[topic]
+    pub version: u32,
+    #[topic]
+    pub moderator: Address,
+    pub details: NameRuleEventDetails,
+}
+
+impl NameRuleEvent {
+    pub fn new(details: NameRuleEventDetails) -> Self {
+        Self {
+            version: EVENT_VERSION,
+            moderator: details.moderator.clone(),
+            details,
+        }
+    }
+}
+
+/// Cow registered for auction.
+#[contractevent(topics = ["register"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RegisterEvent {
+    #[topic]
+    pub version: u32,
+    #[topic]
+    pub owner: Address,
+    #[topic]
+    pub cow_id: String,
+    pub details: AuctionEventDetails,
+}
+
+impl RegisterEvent {
+    pub fn new(details: AuctionEventDetails) -> Self {
+        Self {
+            version: EVENT_VERSION,
+            owner: details.owner.clone(),
+            cow_id: details.cow_id.clone(),
+            details,
+        }
+    }
+}
+
+/// Bidder refunded.
+#[contractevent(topics = ["refund"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RefundEvent {
+    #[topic]
+    pub version: u32,
+    #[topic]
+    pub bidder: Address,
+    #[topic]
+    pub cow_id: String,
+    pub details: AuctionEventDetails,
+}
+
+impl RefundEvent {
+    pub fn new(details: AuctionEventDetails) -> Self {
+        Self {
+            version: EVENT_VERSION,
+            bidder: details.bidder.clone(),
+            cow_id: details.cow_id.clone(),
+            details,
+        }
+    }
+}
+
+/// Auction won.
+#[contractevent(topics = ["auction"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AuctionEvent {
+    #[topic]
+    pub version: u32,
+    #[topic]
+    pub bidder: Address,
+    #[topic]
+    pub cow_id: String,
+    pub details: AuctionEventDetails,
+}
+
+impl AuctionEvent {
+    pub fn new(details: AuctionEventDetails) -> Self {
+        Self {
+            version: EVENT_VERSION,
+            bidder: details.bidder.clone(),
+            cow_id: details.cow_id.clone(),
+            details,
+        }
+    }
+}
+
+/// Auction finalized without any bid.
+#[contractevent(topics = ["no_bid"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NoBidEvent {
+    #[topic]
+    pub version: u32,
+    #[topic]
+    pub owner: Address,
+    #[topic]
+    pub cow_id: String,
+    pub details: AuctionEventDetails,
+}
+
+impl NoBidEvent {
+    pub fn new(details: AuctionEventDetails) -> Self {
+        Self {
+            version: EVENT_VERSION,
+            owner: details.owner.clone(),
+            cow_id: details.cow_id.clone(),
+            details,
+        }
+    }
+}
+
+/// Auction finalized after the cow died.
+#[contractevent(topics = ["dead_cow"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DeadCowEvent {
+    #[topic]
+    pub version: u32,
+    #[topic]
+    pub owner: Address,
+    #[topic]
+    pub cow_id: String,
+    pub details: AuctionEventDetails,
+}
+
+impl DeadCowEvent {
+    pub fn new(details: AuctionEventDetails) -> Self {
+        Self {
+            version: EVENT_VERSION,
+            owner: details.owner.clone(),
+            cow_id: details.cow_id.clone(),
+            details,
+        }
+    }
+}
+
+/// Plot bought, rented, renewed, upgraded, or a cow assigned to it.
+#[contractevent(topics = ["plot"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PlotEvent {
+    #[topic]
+    pub version: u32,
+    #[topic]
+    pub owner: Address,
+    pub details: PlotData,
+}
+
+impl PlotEvent {
+    pub fn new(details: PlotData) -> Self {
+        Self {
+            version: EVENT_VERSION,
+            owner: details.owner.clone(),
+            details,
+        }
+    }
+}
+
+/// Leaderboards reset for a new season.
+#[contractevent(topics = ["season"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SeasonEvent {
+    #[topic]
+    pub version: u32,
+    #[topic]
+    pub admin: Address,
+    pub details: SeasonEventDetails,
+}
+
+impl SeasonEvent {
+    pub fn new(details: SeasonEventDetails) -> Self {
+        Self {
+            version: EVENT_VERSION,
+            admin: details.admin.clone(),
+            details,
+        }
+    }
+}
+
+/// Farmer unlocked an achievement.
+#[contractevent(topics = ["achievement_unlocked"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AchievementEvent {
+    #[topic]
+    pub version: u32,
+    #[topic]
+    pub owner: Address,
+    pub details: AchievementEventDetails,
+}
+
+impl AchievementEvent {
+    pub fn new(details: AchievementEventDetails) -> Self {
+        Self {
+            version: EVENT_VERSION,
+            owner: details.owner.clone(),
+            details,
+        }
+    }
+}
+
+// Standard NFT events.
+// owner and cow ID are part of the topics so wallets and explorers can index them.
+//
+#[contractevent(topics = ["transfer"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NftTransferEvent {
+    #[topic]
+    pub from: Address,
+    #[topic]
+    pub to: Address,
+    pub cow_id: String,
+}
+
+#[contractevent(topics = ["approve"], data_format = "vec")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ApproveEvent {
+    #[topic]
+    pub owner: Address,
+    #[topic]
+    pub cow_id: String,
+    pub approved: Address,
+    pub expiry_ledger: u32,
+}
+
+#[contractevent(topics = ["approve_for_all"], data_format = "vec")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ApproveForAllEvent {
+    #[topic]
+    pub owner: Address,
+    pub operator: Address,
+    pub approved: bool,
+}
@@ -1,4 +1,4 @@
-use soroban_sdk::{Address, BytesN, Env, String, Symbol};
+use soroban_sdk::{Address, BytesN, Env, String, Symbol, Vec};
 
 use crate::enums::*;
 use crate::types::*;
@@ -24,15 +24,24 @@ pub trait CowContractTrait {
     /// Donate to contract
     fn open_donation(env: Env, from: Address, amount: i128) -> Status;
 
-    /// [CowContract::buy_cow]
-    /// Buy Cow from supplier
-    fn buy_cow(
+    /// [CowContract::order_cow]
+    /// Order Cow from supplier with a commitment to a secret salt
+    fn order_cow(
         env: Env,
         user: Address,
         cow_name: Symbol,
-        cow_id: String,
+        external_ref: String,
         cow_breed: CowBreed,
-    ) -> BuyCowResult;
+        commitment: BytesN<32>,
+    ) -> CowOrderResult;
+
+    /// [CowContract::hatch_cow]
+    /// Reveal the salt to hatch the ordered Cow
+    fn hatch_cow(env: Env, user: Address, cow_id: String, salt: BytesN<32>) -> BuyCowResult;
+
+    /// [CowContract::get_cow_order]
+    /// Retrieve cow order waiting to be hatched
+    fn get_cow_order(env: Env, cow_id: String) -> CowOrderResult;
 
     /// [CowContract::sell_cow]
     /// Sell Cow to supplier
@@ -46,17 +55,175 @@ pub trait CowContractTrait {
     /// Feed the cow in Cowchain Farm
     fn feed_the_cow(env: Env, user: Address, cow_id: String) -> CowStatus;
 
+    /// [CowContract::feed_many]
+    /// Feed several cows in Cowchain Farm at once
+    fn feed_many(env: Env, user: Address, cow_ids: Vec<String>) -> FeedManyResult;
+
+    /// [CowContract::feed_all]
+    /// Feed all cow listed in ownership
+    fn feed_all(env: Env, user: Address, start: u32) -> FeedManyResult;
+
+    /// [CowContract::approve_caretaker]
+    /// Allow caretaker to feed the owner's cows until the expiry ledger
+    fn approve_caretaker(
+        env: Env,
+        owner: Address,
+        caretaker: Address,
+        cow_ids: Vec<String>,
+        all_cows: bool,
+        expiry_ledger: u32,
+    ) -> Status;
+
+    /// [CowContract::revoke_caretaker]
+    /// Revoke caretaker approval
+    fn revoke_caretaker(env: Env, owner: Address, caretaker: Address) -> Status;
+
+    /// [CowContract::feed_as_caretaker]
+    /// Feed the owner's cows on their behalf as an approved caretaker
+    fn feed_as_caretaker(
+        env: Env,
+        caretaker: Address,
+        owner: Address,
+        cow_ids: Vec<String>,
+    ) -> FeedManyResult;
+
+    /// [CowContract::register_feeding_plan]
+    /// Register cows to a prepaid feeding plan
+    fn register_feeding_plan(
+        env: Env,
+        user: Address,
+        cow_ids: Vec<String>,
+        deposit: i128,
+    ) -> FeedingPlanResult;
+
+    /// [CowContract::cancel_feeding_plan]
+    /// Cancel the feeding plan and refund the remaining balance
+    fn cancel_feeding_plan(env: Env, user: Address) -> FeedingPlanResult;
+
+    /// [CowContract::run_feeding_plan]
+    /// Feed the hungry cows in a feeding plan in exchange for keeper fee
+    fn run_feeding_plan(env: Env, keeper: Address, user: Address) -> FeedManyResult;
+
+    /// [CowContract::get_feeding_plan]
+    /// Retrieve user feeding plan
+    fn get_feeding_plan(env: Env, user: Address) -> FeedingPlanResult;
+
+    /// [CowContract::harvest_milk]
+    /// Harvest milk from adult female cows
+    fn harvest_milk(env: Env, user: Address, cow_ids: Vec<String>) -> MilkResult;
+
+    /// [CowContract::sell_milk]
+    /// Sell milk to supplier
+    fn sell_milk(env: Env, user: Address, amount: i128) -> MilkResult;
+
+    /// [CowContract::get_milk_balance]
+    /// Retrieve user milk balance
+    fn get_milk_balance(env: Env, user: Address) -> MilkResult;
+
+    /// [CowContract::get_cow]
+    /// Retrieve cow data
+    fn get_cow(env: Env, cow_id: String) -> GetCowResult;
+
+    /// [CowContract::get_traits]
+    /// Retrieve cow genetic traits decoded from its genome
+    fn get_traits(env: Env, cow_id: String) -> CowTraitsResult;
+
+    /// [CowContract::get_cow_by_name]
+    /// Retrieve cow data by its unique name
+    fn get_cow_by_name(env: Env, cow_name: Symbol) -> GetCowResult;
+
+    /// [CowContract::get_owner_of]
+    /// Retrieve the owner of a cow
+    fn get_owner_of(env: Env, cow_id: String) -> OwnerResult;
+
+    /// [CowContract::get_cows]
+    /// Retrieve a page of cow data listed in ownership
+    fn get_cows(env: Env, user: Address, start: u32, limit: u32) -> GetAllCowResult;
+
+    /// [CowContract::check_ownership]
+    /// Check ownership list consistency against cow owner records
+    fn check_ownership(env: Env, user: Address) -> OwnershipCheckResult;
+
+    /// [CowContract::clean_ownership]
+    /// Remove dead cows from user's ownership list
+    fn clean_ownership(env: Env, user: Address) -> OwnershipCheckResult;
+
+    /// [CowContract::migrate_cow]
+    /// Upgrade a cow saved by an older contract version to the current cow data layout
+    fn migrate_cow(env: Env, user: Address, cow_id: String) -> GetCowResult;
+
     /// [CowContract::get_all_cow]
     /// Retrieve all cow data listed in ownership
     fn get_all_cow(env: Env, user: Address) -> GetAllCowResult;
 
+    /// [CowContract::transfer_cow]
+    /// Transfer cow to another farmer
+    fn transfer_cow(env: Env, from: Address, to: Address, cow_id: String) -> CowStatus;
+
+    /// [CowContract::offer_gift]
+    /// Offer cow as a gift to another farmer
+    fn offer_gift(env: Env, from: Address, to: Address, cow_id: String) -> CowStatus;
+
+    /// [CowContract::accept_gift]
+    /// Accept cow gift offer
+    fn accept_gift(env: Env, to: Address, cow_id: String) -> CowStatus;
+
+    /// [CowContract::decline_gift]
+    /// Decline cow gift offer
+    fn decline_gift(env: Env, to: Address, cow_id: String) -> CowStatus;
+
+    /// [CowContract::rename_cow]
+    /// Rename a cow, paid with a fee and limited by a cooldown
+    fn rename_cow(env: Env, user: Address, cow_id: String, new_name: Symbol) -> GetCowResult;
+
+    /// [CowContract::set_cow_metadata]
+    /// Save cow description, color and pattern
+    fn set_cow_metadata(env: Env, user: Address, cow_id: String, metadata: CowMetadata) -> Status;
+
+    /// [CowContract::get_cow_metadata]
+    /// Retrieve cow description, color and pattern
+    fn get_cow_metadata(env: Env, cow_id: String) -> CowMetadataResult;
+
+    /// [CowContract::set_moderator]
+    /// Set the Moderator who maintains reserved and banned names
+    fn set_moderator(env: Env, moderator: Address) -> Status;
+
+    /// [CowContract::set_name_restriction]
+    /// Reserve, ban or free a cow name
+    fn set_name_restriction(
+        env: Env,
+        moderator: Address,
+        cow_name: Symbol,
+        restriction: NameRestriction,
+    ) -> Status;
+
+    /// [CowContract::get_name_restriction]
+    /// Retrieve cow name restriction
+    fn get_name_restriction(env: Env, cow_name: Symbol) -> NameRestriction;
+
+    /// [CowContract::extend_my_farm]
+    /// Bump user ownership, cow, cow name and auction entries together
+    fn extend_my_farm(env: Env, user: Address) -> EntryTtlResult;
+
+    /// [CowContract::extend_entries]
+    /// Bump the lifetime of farm entries
+    fn extend_entries(env: Env, entries: Vec<FarmEntry>) -> EntryTtlResult;
+
+    /// [CowContract::get_entries_ttl]
+    /// Retrieve the remaining lifetime of farm entries
+    fn get_entries_ttl(env: Env, entries: Vec<FarmEntry>) -> EntryTtlResult;
+
+    /// [CowContract::get_farm_ttl]
+    /// Retrieve the remaining lifetime of every entry in user's farm
+    fn get_farm_ttl(env: Env, user: Address) -> EntryTtlResult;
+
     /// [CowContract::register_auction]
     /// Registering cow for auction
     fn register_auction(
         env: Env,
         user: Address,
         cow_id: String,
-        auction_id: String,
+        external_ref: String,
         price: u32,
     ) -> AuctionResult;
 
@@ -68,7 +235,125 @@ pub trait CowContractTrait {
     /// Finalize the auction
     fn finalize_auction(env: Env, auction_id: String) -> AuctionResult;
 
+    /// [CowContract::get_auction]
+    /// Retrieve auction data
+    fn get_auction(env: Env, auction_id: String) -> AuctionResult;
+
+    /// [CowContract::get_auctions]
+    /// Retrieve a page of auction data
+    fn get_auctions(env: Env, start: u32, limit: u32, filter: AuctionFilter) -> AuctionResult;
+
     /// [CowContract::get_all_auction]
     /// Retrieve all auction data
     fn get_all_auction(env: Env) -> AuctionResult;
+
+    /// [CowContract::buy_plot]
+    /// Buy a land plot to keep cows
+    fn buy_plot(env: Env, user: Address, tier: PlotTier) -> PlotResult;
+
+    /// [CowContract::rent_plot]
+    /// Rent a land plot for 1 week
+    fn rent_plot(env: Env, user: Address, tier: PlotTier) -> PlotResult;
+
+    /// [CowContract::renew_plot]
+    /// Renew the rent of a land plot for another week
+    fn renew_plot(env: Env, user: Address, plot_id: String) -> PlotResult;
+
+    /// [CowContract::upgrade_plot]
+    /// Build a barn or pasture on a land plot
+    fn upgrade_plot(env: Env, user: Address, plot_id: String, upgrade: PlotUpgrade) -> PlotResult;
+
+    /// [CowContract::assign_cow]
+    /// Move a cow to another land plot
+    fn assign_cow(env: Env, user: Address, cow_id: String, plot_id: String) -> PlotResult;
+
+    /// [CowContract::get_plot]
+    /// Retrieve land plot data
+    fn get_plot(env: Env, plot_id: String) -> PlotResult;
+
+    /// [CowContract::get_plots]
+    /// Retrieve all land plots owned or rented by user
+    fn get_plots(env: Env, user: Address) -> PlotResult;
+
+    /// [CowContract::set_display_name]
+    /// Set the display name shown on a farmer profile
+    fn set_display_name(env: Env, user: Address, display_name: String) -> Status;
+
+    /// [CowContract::get_profile]
+    /// Retrieve farmer profile and statistics
+    fn get_profile(env: Env, user: Address) -> ProfileResult;
+
+    /// [CowContract::get_leaderboard]
+    /// Retrieve the top entries of a leaderboard for the current season
+    fn get_leaderboard(env: Env, kind: LeaderboardKind) -> LeaderboardResult;
+
+    /// [CowContract::reset_leaderboards]
+    /// Clear every leaderboard and start a new season
+    fn reset_leaderboards(env: Env) -> Status;
+
+    /// [CowContract::set_achievement_reward]
+    /// Set the treasury reward paid when an achievement is unlocked
+    fn set_achievement_reward(env: Env, achievement: Achievement, reward: i128) -> Status;
+
+    /// [CowContract::get_achievement_reward]
+    /// Retrieve the treasury reward paid when an achievement is unlocked
+    fn get_achievement_reward(env: Env, achievement: Achievement) -> i128;
+}
+
+pub trait CowNftTrait {
+    /// [CowContract::name]
+    /// Name of the Cowchain Farm cow collection
+    fn name(env: Env) -> String;
+
+    /// [CowContract::symbol]
+    /// Symbol of the Cowchain Farm cow collection
+    fn symbol(env: Env) -> String;
+
+    /// [CowContract::set_base_uri]
+    /// Set the base URI for cow token URI
+    fn set_base_uri(env: Env, base_uri: String) -> Status;
+
+    /// [CowContract::token_uri]
+    /// Retrieve cow token URI
+    fn token_uri(env: Env, cow_id: String) -> String;
+
+    /// [CowContract::owner_of]
+    /// Retrieve the owner of a cow
+    fn owner_of(env: Env, cow_id: String) -> Option<Address>;
+
+    /// [CowContract::balance_of]
+    /// Retrieve the number of living cows owned
+    fn balance_of(env: Env, owner: Address) -> u32;
+
+    /// [CowContract::approve]
+    /// Approve another address to transfer a cow until the expiry ledger
+    fn approve(
+        env: Env,
+        owner: Address,
+        approved: Address,
+        cow_id: String,
+        expiry_ledger: u32,
+    ) -> Status;
+
+    /// [CowContract::get_approved]
+    /// Retrieve the address approved to transfer a cow
+    fn get_approved(env: Env, cow_id: String) -> Option<Address>;
+
+    /// [CowContract::set_approval_for_all]
+    /// Approve or revoke an operator for all owner's cows
+    fn set_approval_for_all(env: Env, owner: Address, operator: Address, approved: bool) -> Status;
+
+    /// [CowContract::is_approved_for_all]
+    /// Check if operator is approved for all owner's cows
+    fn is_approved_for_all(env: Env, owner: Address, operator: Address) -> bool;
+
+    /// [CowContract::transfer_from]
+    /// Transfer cow on behalf of the owner
+    fn transfer_from(
+        env: Env,
+        spender: Address,
+        from: Address,
+        to: Address,
+        cow_id: String,
+    ) -> CowStatus;
 }
@@ -1,19 +1,22 @@
 #![no_std]
 
 use soroban_sdk::{
-    contract, contractimpl, symbol_short, token, Address, BytesN, Env, String, Symbol, Vec,
+    contract, contractimpl, token, Address, Bytes, BytesN, Env, Map, String, Symbol, TryFromVal,
+    Val, Vec,
 };
 
 use crate::constants::*;
 use crate::enums::*;
+use crate::events::*;
 use crate::interface::*;
 use crate::types::*;
 
-mod constants;
-mod enums;
+pub mod constants;
+pub mod enums;
+mod events;
 mod interface;
 mod test;
-mod types;
+pub mod types;
 
 #[contract]
 pub struct CowContract;
@@ -25,7 +28,7 @@ impl CowContractTrait for CowContract {
         // you must set your own unique password other than "y3QKiJ5iq7y9JGAfN23vY8hwXa".
         // you can use the Deployer contract instead for this check.
         // the main purpose is to prevent other people from initializing your contract.
-        let internal_password = String::from_slice(&env, "y3QKiJ5iq7y9JGAfN23vY8hwXa");
+        let internal_password = String::from_str(&env, "y3QKiJ5iq7y9JGAfN23vY8hwXa");
         if message.ne(&internal_password) {
             return Status::TryAgain;
         }
@@ -48,7 +51,16 @@ impl CowContractTrait for CowContract {
         // bump storage instance lifetime to 1 month
         env.storage()
             .instance()
-            .bump(LEDGER_AMOUNT_IN_1_MONTH, LEDGER_AMOUNT_IN_1_MONTH);
+            .extend_ttl(LEDGER_AMOUNT_IN_1_MONTH, LEDGER_AMOUNT_IN_1_MONTH);
+
+        // publish Cowchain Farm INIT event
+        let new_init_event = InitEventDetails {
+            admin,
+            native_token,
+            initialized_ledger: env.ledger().sequence(),
+        };
+        InitEvent::new(new_init_event).publish(&env);
+
         Status::Ok
     }
 
@@ -65,7 +77,16 @@ impl CowContractTrait for CowContract {
         admin.require_auth();
 
         // update the Cowchain Farm contract.
-        env.deployer().update_current_contract_wasm(new_wasm_hash);
+        env.deployer()
+            .update_current_contract_wasm(new_wasm_hash.clone());
+
+        // publish Cowchain Farm UPGRADE event
+        let new_upgrade_event = UpgradeEventDetails {
+            admin,
+            new_wasm_hash,
+        };
+        UpgradeEvent::new(new_upgrade_event).publish(&env);
+
         Status::Upgraded
     }
 
@@ -82,7 +103,17 @@ impl CowContractTrait for CowContract {
         admin.require_auth();
 
         // bump instance storage
-        env.storage().instance().bump(ledger_amount, ledger_amount);
+        env.storage()
+            .instance()
+            .extend_ttl(ledger_amount, ledger_amount);
+
+        // publish Cowchain Farm BUMP INSTANCE event
+        let new_bump_event = BumpEventDetails {
+            admin,
+            ledger_amount,
+        };
+        BumpInstanceEvent::new(new_bump_event).publish(&env);
+
         Status::Bumped
     }
 
@@ -115,38 +146,44 @@ impl CowContractTrait for CowContract {
 
         // transfer native token from user to contract.
         let donation_amount = amount * 10_000_000;
-        native_token_client.transfer(&from, &env.current_contract_address(), &donation_amount);
+        native_token_client.transfer(&from, env.current_contract_address(), &donation_amount);
+
+        // publish Cowchain Farm DONATION event
+        let new_donation_event = DonationEventDetails {
+            from,
+            amount: donation_amount,
+        };
+        DonationEvent::new(new_donation_event).publish(&env);
 
         Status::Ok
     }
 
-    fn buy_cow(
+    fn order_cow(
         env: Env,
         user: Address,
         cow_name: Symbol,
-        cow_id: String,
+        external_ref: String,
         cow_breed: CowBreed,
-    ) -> BuyCowResult {
+        commitment: BytesN<32>,
+    ) -> CowOrderResult {
         // ensures that user has authorized invocation of this contract.
         user.require_auth();
 
-        // random Cow Gender
-        let mut cow_gender = CowGender::Male;
-        let value = env.prng().u64_in_range(1..=6);
-        if value % 2 == 0 {
-            cow_gender = CowGender::Female;
+        // check external reference length.
+        if external_ref.len() > MAX_EXTERNAL_REF_LENGTH {
+            return CowOrderResult::new(env, Status::Fail);
         }
 
         // if Native Token key not exist, contract has not been initialized.
         let is_native_token_exist = env.storage().instance().has(&DataKey::NativeToken);
         if !is_native_token_exist {
-            return BuyCowResult::new(env, Status::NotInitialized);
+            return CowOrderResult::new(env, Status::NotInitialized);
         }
 
-        // check for cow UNIQUE name, cancel buy if name already exists.
-        let is_name_exist = env.storage().temporary().has(&cow_name);
-        if is_name_exist {
-            return BuyCowResult::new(env, Status::NameAlreadyExist);
+        // check for cow UNIQUE name, cancel order if name already exists or restricted.
+        let name_status = get_cow_name_status(&env, &user, &cow_name);
+        if name_status.ne(&Status::Ok) {
+            return CowOrderResult::new(env, name_status);
         }
 
         // initiate native token client.
@@ -161,30 +198,121 @@ impl CowContractTrait for CowContract {
 
         // find out the approximate user balance after transaction.
         let user_balance_after_tx: i128 =
-            user_native_token_balance - MINIMUM_USER_BALANCE - cow_price_in_stroops.clone();
+            user_native_token_balance - MINIMUM_USER_BALANCE - cow_price_in_stroops;
 
         // cancel the transaction if user balance after transaction equal or less than zero.
         if user_balance_after_tx <= 0 {
-            return BuyCowResult::new(env, Status::InsufficientFund);
+            return CowOrderResult::new(env, Status::InsufficientFund);
         }
 
-        // transfer native token to supplier to complete the buying process.
-        native_token_client.transfer(
-            &user,
-            &env.current_contract_address(),
-            &cow_price_in_stroops,
-        );
+        // the cow needs a place on one of user's plots.
+        let mut plot_data = match find_free_plot(&env, &user) {
+            Some(plot_data) => plot_data,
+            None => return CowOrderResult::new(env, Status::NoCapacity),
+        };
+
+        // transfer native token to supplier to complete the ordering process.
+        native_token_client.transfer(&user, env.current_contract_address(), &cow_price_in_stroops);
+
+        // generate a unique cow ID, the cow will be delivered with this ID when hatched.
+        let cow_id = generate_id(&env, COW_ID_PREFIX, DataKey::CowCounter, is_cow_id_taken);
+
+        // new cow order.
+        let order = CowOrder {
+            cow_id: cow_id.clone(),
+            external_ref,
+            owner: user,
+            name: cow_name.clone(),
+            breed: cow_breed,
+            plot_id: plot_data.id.clone(),
+            commitment,
+            order_ledger: env.ledger().sequence(),
+            hatch_ledger: env.ledger().sequence() + HATCH_DELAY,
+        };
+
+        // save cow order & bump lifetime until the end of hatching window.
+        let order_key = DataKey::CowOrder(cow_id.clone());
+        env.storage().temporary().set(&order_key, &order);
+        env.storage()
+            .temporary()
+            .extend_ttl(&order_key, HATCH_WINDOW, HATCH_WINDOW);
+
+        // draw the hatch entropy now, so the cow is fixed once the order is paid for.
+        // it is kept apart from the order and never returned, so the caller cannot see it
+        // before the order is applied, and every hatch attempt draws the same cow.
+        let entropy_key = DataKey::HatchEntropy(cow_id.clone());
+        let hatch_entropy: BytesN<32> = env.prng().gen();
+        env.storage().temporary().set(&entropy_key, &hatch_entropy);
+        env.storage()
+            .temporary()
+            .extend_ttl(&entropy_key, HATCH_WINDOW, HATCH_WINDOW);
+
+        // reserve cow unique name in name registry, and its place on the plot.
+        set_cow_name(&env, &cow_name, &cow_id);
+        add_cow_to_plot(&env, &mut plot_data, &cow_id);
+
+        // publish Cowchain Farm ORDER event
+        OrderEvent::new(order.clone()).publish(&env);
+
+        // Result
+        let mut order_list: Vec<CowOrder> = Vec::new(&env);
+        order_list.push_back(order);
+        CowOrderResult {
+            status: Status::Ok,
+            order: order_list,
+        }
+    }
+
+    fn hatch_cow(env: Env, user: Address, cow_id: String, salt: BytesN<32>) -> BuyCowResult {
+        // ensures that user has authorized invocation of this contract.
+        user.require_auth();
+
+        // check if cow order exist, an order is forfeited when its hatching window is over.
+        let order_key = DataKey::CowOrder(cow_id.clone());
+        let is_order_exist = env.storage().temporary().has(&order_key);
+        if !is_order_exist {
+            return BuyCowResult::new(env, Status::NotFound);
+        }
+        let order: CowOrder = env.storage().temporary().get(&order_key).unwrap();
+
+        // only the user who ordered the cow can hatch it.
+        if order.owner.ne(&user) {
+            return BuyCowResult::new(env, Status::Unauthorized);
+        }
+
+        // the cow cannot be hatched in the same ledger it was ordered.
+        if env.ledger().sequence() < order.hatch_ledger {
+            return BuyCowResult::new(env, Status::NotReady);
+        }
+
+        // the salt must match the commitment made when ordering.
+        let salt_hash: BytesN<32> = env.crypto().sha256(&salt.clone().into()).into();
+        if salt_hash.ne(&order.commitment) {
+            return BuyCowResult::new(env, Status::Fail);
+        }
+
+        // every random cow trait is drawn from the hatch seed.
+        seed_hatch_prng(&env, &order, &salt);
+        let cow_gender = get_random_gender(&env);
+        let cow_genome: BytesN<16> = env.prng().gen();
 
         // new cow data.
         let new_cow_data = CowData {
             id: cow_id.clone(),
-            name: cow_name.clone(),
-            breed: cow_breed,
+            external_ref: order.external_ref,
+            name: order.name.clone(),
+            breed: order.breed,
             gender: cow_gender,
             born_ledger: env.ledger().sequence(),
             last_fed_ledger: env.ledger().sequence(),
             feeding_stats: CowFeedingStats::new(),
-            auction_id: String::from_slice(&env, ""),
+            condition: CowCondition::new(),
+            last_milked_ledger: env.ledger().sequence(),
+            life_stage: CowLifeStage::Calf,
+            last_renamed_ledger: 0,
+            auction_id: String::from_str(&env, ""),
+            genome: cow_genome,
+            plot_id: order.plot_id.clone(),
         };
         let mut cow_ownership_list: Vec<String> = Vec::new(&env);
 
@@ -199,34 +327,44 @@ impl CowContractTrait for CowContract {
         // save ownership data & bump lifetime to 1 week.
         cow_ownership_list.push_back(cow_id.clone());
         env.storage().persistent().set(&user, &cow_ownership_list);
-        env.storage()
-            .persistent()
-            .bump(&user, LEDGER_AMOUNT_IN_1_WEEK, LEDGER_AMOUNT_IN_1_WEEK);
+        bump_ownership(&env, &user);
 
         // save cow data & bump lifetime to 24 hours.
         env.storage().temporary().set(&cow_id, &new_cow_data);
-        env.storage().temporary().bump(
+        env.storage().temporary().extend_ttl(
             &cow_id,
             LEDGER_AMOUNT_IN_24_HOURS,
             LEDGER_AMOUNT_IN_24_HOURS,
         );
 
-        // save cow unique name & bump lifetime to 24 hours.
-        env.storage().temporary().set(&cow_name, &cow_id);
-        env.storage().temporary().bump(
-            &cow_name,
-            LEDGER_AMOUNT_IN_24_HOURS,
-            LEDGER_AMOUNT_IN_24_HOURS,
-        );
+        // save cow owner & bump lifetime to 24 hours.
+        set_cow_owner(&env, &cow_id, &user);
+
+        // the order is fulfilled, refresh the cow unique name in name registry.
+        env.storage().temporary().remove(&order_key);
+        env.storage()
+            .temporary()
+            .remove(&DataKey::HatchEntropy(cow_id.clone()));
+        set_cow_name(&env, &order.name, &cow_id);
 
         // publish Cowchain Farm BUY event
         let new_cow_event = CowEventDetails {
             id: new_cow_data.id.clone(),
             name: new_cow_data.name.clone(),
-            owner: user,
+            owner: user.clone(),
             last_fed_ledger: env.ledger().sequence(),
+            condition: new_cow_data.condition.clone(),
         };
-        env.events().publish((symbol_short!("buy"),), new_cow_event);
+        BuyEvent::new(new_cow_event).publish(&env);
+
+        // update farmer profile.
+        let mut profile = get_farmer_profile(&env, &user);
+        profile.cows_bought += 1;
+        unlock_achievement(&env, &user, &mut profile, Achievement::FirstCow);
+        set_farmer_profile(&env, &user, &profile);
+
+        // the new cow joins the herd.
+        update_herd_leaderboard(&env, &user);
 
         // Result
         let mut cow_data_list: Vec<CowData> = Vec::new(&env);
@@ -238,6 +376,23 @@ impl CowContractTrait for CowContract {
         }
     }
 
+    fn get_cow_order(env: Env, cow_id: String) -> CowOrderResult {
+        // check if cow order exist.
+        let order_key = DataKey::CowOrder(cow_id);
+        let is_order_exist = env.storage().temporary().has(&order_key);
+        if !is_order_exist {
+            return CowOrderResult::new(env, Status::NotFound);
+        }
+
+        let order: CowOrder = env.storage().temporary().get(&order_key).unwrap();
+        let mut result: Vec<CowOrder> = Vec::new(&env);
+        result.push_back(order);
+        CowOrderResult {
+            status: Status::Ok,
+            order: result,
+        }
+    }
+
     fn sell_cow(env: Env, user: Address, cow_id: String) -> SellCowResult {
         // ensures that user has authorized invocation of this contract.
         user.require_auth();
@@ -254,40 +409,43 @@ impl CowContractTrait for CowContract {
             return SellCowResult::new(env, Status::NotFound);
         }
 
-        // check if ownership data exist.
+        // check if ownership data exist and the cow is owned by user.
         let is_ownership_exist = env.storage().persistent().has(&user);
-        if !is_ownership_exist {
+        if !is_ownership_exist || !is_cow_owner(&env, &user, &cow_id) {
             return SellCowResult::new(env, Status::MissingOwnership);
         }
 
-        // get cow data.
-        let cow_data: CowData = env.storage().temporary().get(&cow_id).unwrap();
+        // get cow data, cow saved in the first cow data layout needs to be migrated first.
+        let Some(cow_data) = get_cow_data(&env, &cow_id) else {
+            return SellCowResult::new(env, Status::NotMigrated);
+        };
 
         // check for auction ID, cancel sell if exist.
-        if cow_data.auction_id.ne(&String::from_slice(&env, "")) {
+        if cow_data.auction_id.ne(&String::from_str(&env, "")) {
             return SellCowResult::new(env, Status::OnAuction);
         }
 
-        // here we check the age of the cow.
-        // a cow can only be sold after it has been alive for 3 days.
+        // here we check the life stage of the cow.
+        // a cow can only be sold after it has grown out of calf stage,
+        // and supplier won't buy a cow that has passed its lifespan.
         let current_ledger: u32 = env.ledger().sequence();
-        let cow_age: u32 = current_ledger - cow_data.born_ledger;
-        if cow_age < LEDGER_AMOUNT_IN_3_DAYS {
+        if is_cow_past_lifespan(&cow_data, current_ledger) {
+            return SellCowResult::new(env, Status::OldAge);
+        }
+        if get_cow_life_stage(&cow_data, current_ledger).eq(&CowLifeStage::Calf) {
             return SellCowResult::new(env, Status::Underage);
         }
 
         // calculate cow selling price.
         let cow_base_price: i128 = get_cow_base_price_in_stroops(&cow_data.breed);
-        let cow_selling_price = get_cow_appraisal_price(&cow_data, cow_base_price);
+        let cow_selling_price = get_cow_appraisal_price(&cow_data, cow_base_price, current_ledger);
 
         // initiate native token client.
         let native_token: Address = env.storage().instance().get(&DataKey::NativeToken).unwrap();
         let native_token_client = token::Client::new(&env, &native_token);
 
-        // get current contract balance.
-        let contract_native_token_balance: i128 =
-            native_token_client.balance(&env.current_contract_address());
-        if contract_native_token_balance < cow_selling_price {
+        // check if the treasury can afford the cow, bids and plan deposits belong to users.
+        if get_treasury_balance(&env, &native_token_client) < cow_selling_price {
             return SellCowResult::new(env, Status::InsufficientFund);
         }
 
@@ -305,23 +463,27 @@ impl CowContractTrait for CowContract {
 
         // save new ownership data & bump lifetime to 1 week.
         env.storage().persistent().set(&user, &cow_ownership_list);
-        env.storage()
-            .persistent()
-            .bump(&user, LEDGER_AMOUNT_IN_1_WEEK, LEDGER_AMOUNT_IN_1_WEEK);
+        bump_ownership(&env, &user);
 
-        // remove cow data & cow UNIQUE name from storage.
-        env.storage().temporary().remove(&cow_id);
-        env.storage().temporary().remove(&cow_data.name);
+        // remove cow data, cow owner, cow metadata & cow UNIQUE name from storage.
+        remove_cow_data(&env, &cow_data);
+
+        // update farmer profile.
+        let mut profile = get_farmer_profile(&env, &user);
+        profile.cows_sold += 1;
+        profile.earnings += cow_selling_price;
+        set_farmer_profile(&env, &user, &profile);
+        update_herd_leaderboard(&env, &user);
 
         // publish Cowchain Farm SELL event
         let new_cow_event = CowEventDetails {
             id: cow_data.id.clone(),
             name: cow_data.name.clone(),
             owner: user,
-            last_fed_ledger: cow_data.last_fed_ledger.clone(),
+            last_fed_ledger: cow_data.last_fed_ledger,
+            condition: cow_data.condition.clone(),
         };
-        env.events()
-            .publish((symbol_short!("sell"),), new_cow_event);
+        SellEvent::new(new_cow_event).publish(&env);
 
         SellCowResult {
             status: Status::Ok,
@@ -336,19 +498,26 @@ impl CowContractTrait for CowContract {
             return CowAppraisalResult::new(Status::NotFound);
         }
 
+        // cow saved in the first cow data layout needs to be migrated first.
+        let Some(cow_data) = get_cow_data(&env, &cow_id) else {
+            return CowAppraisalResult::new(Status::NotMigrated);
+        };
+
         // get cow price based on their breed (the price will be in XLM unit).
-        let cow_data: CowData = env.storage().temporary().get(&cow_id).unwrap();
         let cow_base_price: i128 = get_cow_base_price_in_stroops(&cow_data.breed);
 
-        // check if cow is underage.
+        // check if cow has passed its lifespan or still a calf.
         let current_ledger: u32 = env.ledger().sequence();
-        let cow_age: u32 = current_ledger - cow_data.born_ledger;
-        if cow_age < LEDGER_AMOUNT_IN_3_DAYS {
+        if is_cow_past_lifespan(&cow_data, current_ledger) {
+            return CowAppraisalResult::new(Status::OldAge);
+        }
+        if get_cow_life_stage(&cow_data, current_ledger).eq(&CowLifeStage::Calf) {
             return CowAppraisalResult::new(Status::Underage);
         }
 
         // get cow appraisal price.
-        let cow_price_appraisal = get_cow_appraisal_price(&cow_data, cow_base_price);
+        let cow_price_appraisal =
+            get_cow_appraisal_price(&cow_data, cow_base_price, current_ledger);
 
         CowAppraisalResult {
             status: Status::Ok,
@@ -357,552 +526,2417 @@ impl CowContractTrait for CowContract {
     }
 
     fn feed_the_cow(env: Env, user: Address, cow_id: String) -> CowStatus {
+        // ensures that user has authorized invocation of this contract.
+        user.require_auth();
+
         // check if cow still alive.
         let is_cow_alive = env.storage().temporary().has(&cow_id);
         if !is_cow_alive {
             return CowStatus::new(env, Status::NotFound);
         }
 
-        // check if ownership data exist.
+        // check if ownership data exist and the cow is owned by user.
         let is_ownership_exist = env.storage().persistent().has(&user);
-        if !is_ownership_exist {
+        if !is_ownership_exist || !is_cow_owner(&env, &user, &cow_id) {
             return CowStatus::new(env, Status::MissingOwnership);
         }
 
-        // get cow data from storage.
-        let mut cow_data: CowData = env.storage().temporary().get(&cow_id).unwrap();
-
-        // so in 24 hours there are approximately 17280 ledger.
-        // we have 4 feeding time zone, that is every 4320 ledger.
-        //
-        // the time zones are:
-        // 1st 4320 ledger -> FULL
-        // 2nd 4320 ledger -> ON TIME
-        // 3rd 4320 ledger -> LATE
-        // 4th 4320 ledger -> FORGET
-        //
-        // the basic rule in Cowchain Farm are:
-        // if feed distance are less than 4320 ledger, the cow won't eat, still full.
-        // if feed distance are more than 17280 ledger, the cow will die.
-
-        // find out feeding distance.
-        let current_ledger: u32 = env.ledger().sequence();
-        let last_fed_ledger: u32 = cow_data.last_fed_ledger;
-        let feed_distance: u32 = current_ledger - last_fed_ledger;
-
-        // when the cow is still full, no bump operation will be made to its data.
-        if feed_distance <= WELL_FED {
-            return CowStatus::new(env, Status::FullStomach);
-        }
-
-        // calculate feeding stats.
-        let mut on_time = cow_data.feeding_stats.on_time;
-        let mut late = cow_data.feeding_stats.late;
-        let mut forget = cow_data.feeding_stats.forget;
-
-        if feed_distance > WELL_FED && feed_distance <= ON_TIME_FEED {
-            on_time = on_time + 1;
+        // feed the cow.
+        let cow_value = get_herd_cow_value(&env, &cow_id);
+        let feed_status = feed_cow(&env, &user, &cow_id);
+        if feed_status.status.ne(&Status::Ok) {
+            return feed_status;
         }
-        if feed_distance > ON_TIME_FEED && feed_distance <= LATE_FEED {
-            late = late + 1;
-        }
-        if feed_distance > LATE_FEED {
-            forget = forget + 1;
-        }
-
-        // update cow data.
-        cow_data.last_fed_ledger = env.ledger().sequence();
-        cow_data.feeding_stats = CowFeedingStats {
-            on_time,
-            late,
-            forget,
-        };
 
-        // save updated cow data & bump lifetime to 24 hours.
-        env.storage().temporary().set(&cow_id, &cow_data);
-        env.storage().temporary().bump(
-            &cow_id,
-            LEDGER_AMOUNT_IN_24_HOURS,
-            LEDGER_AMOUNT_IN_24_HOURS,
-        );
+        // bump user lifetime to 1 week.
+        bump_ownership(&env, &user);
 
-        // bump cow unique name lifetime to 24 hours.
-        env.storage().temporary().bump(
-            &cow_data.name,
-            LEDGER_AMOUNT_IN_24_HOURS,
-            LEDGER_AMOUNT_IN_24_HOURS,
-        );
+        // feeding changes the cow appraisal price.
+        add_herd_value(&env, &user, get_herd_cow_value(&env, &cow_id) - cow_value);
 
-        // bump user lifetime to 1 week.
-        env.storage()
-            .persistent()
-            .bump(&user, LEDGER_AMOUNT_IN_1_WEEK, LEDGER_AMOUNT_IN_1_WEEK);
+        feed_status
+    }
 
-        // publish Cowchain Farm FEED event
-        let new_cow_event = CowEventDetails {
-            id: cow_data.id.clone(),
-            name: cow_data.name.clone(),
-            owner: user,
-            last_fed_ledger: cow_data.last_fed_ledger.clone(),
-        };
-        env.events()
-            .publish((symbol_short!("feed"),), new_cow_event);
+    fn feed_many(env: Env, user: Address, cow_ids: Vec<String>) -> FeedManyResult {
+        // ensures that user has authorized invocation of this contract.
+        user.require_auth();
 
-        CowStatus {
-            status: Status::Ok,
-            ledger: cow_data.last_fed_ledger,
+        // check if ownership data exist.
+        let is_ownership_exist = env.storage().persistent().has(&user);
+        if !is_ownership_exist {
+            return FeedManyResult::new(env, Status::MissingOwnership);
         }
+
+        feed_cow_batch(env, user, cow_ids, None)
     }
 
-    fn get_all_cow(env: Env, user: Address) -> GetAllCowResult {
+    fn feed_all(env: Env, user: Address, start: u32) -> FeedManyResult {
         // ensures that user has authorized invocation of this contract.
         user.require_auth();
 
         // check if ownership data exist.
         let is_ownership_exist = env.storage().persistent().has(&user);
         if !is_ownership_exist {
-            return GetAllCowResult {
-                status: Status::Fail,
-                data: Vec::new(&env),
-            };
+            return FeedManyResult::new(env, Status::MissingOwnership);
         }
 
-        // get ownership data.
+        // pick living cows listed in ownership from start, up to the batch limit.
+        // dead cows are skipped so they never take a place in the batch.
         let ownership_data: Vec<String> = env.storage().persistent().get(&user).unwrap();
-
-        // get all cow data.
-        let mut cow_data_list: Vec<CowData> = Vec::new(&env);
-        for cow_id in ownership_data {
-            let is_cow_alive = env.storage().temporary().has(&cow_id);
-            if !is_cow_alive {
+        let mut cow_ids: Vec<String> = Vec::new(&env);
+        let mut next: u32 = 0;
+        for index in start..ownership_data.len() {
+            let cow_id = ownership_data.get_unchecked(index);
+            if !env.storage().temporary().has(&cow_id) {
                 continue;
             }
-            let cow_data: CowData = env.storage().temporary().get(&cow_id).unwrap();
-            cow_data_list.push_back(cow_data);
+            if cow_ids.len() == MAX_COWS_PER_FEEDING {
+                next = index;
+                break;
+            }
+            cow_ids.push_back(cow_id);
         }
 
-        GetAllCowResult {
-            status: Status::Ok,
-            data: cow_data_list,
-        }
+        let mut result = feed_cow_batch(env, user, cow_ids, None);
+        result.next = next;
+        result
     }
 
-    fn register_auction(
+    fn approve_caretaker(
         env: Env,
-        user: Address,
-        cow_id: String,
-        auction_id: String,
-        price: u32,
-    ) -> AuctionResult {
-        // check Admin key in storage.
-        // if Admin key not exist, contract has not been initialized.
-        let is_admin_exist = env.storage().instance().has(&DataKey::Admin);
-        if !is_admin_exist {
-            return AuctionResult::new(env, Status::NotInitialized);
+        owner: Address,
+        caretaker: Address,
+        cow_ids: Vec<String>,
+        all_cows: bool,
+        expiry_ledger: u32,
+    ) -> Status {
+        // ensures that owner has authorized invocation of this contract.
+        owner.require_auth();
+
+        // owner cannot be their own caretaker.
+        if owner.eq(&caretaker) {
+            return Status::Fail;
         }
 
-        // ensures that user has authorized invocation of this contract.
-        user.require_auth();
-
-        // check if cow still alive.
-        let is_cow_alive = env.storage().temporary().has(&cow_id);
-        if !is_cow_alive {
-            return AuctionResult::new(env, Status::NotFound);
+        // the approval must end at a future ledger.
+        if expiry_ledger <= env.ledger().sequence() {
+            return Status::Expired;
         }
 
-        let mut cow_data: CowData = env.storage().temporary().get(&cow_id).unwrap();
-
-        // check for auction ID, cancel register if it is already being auctioned.
-        if cow_data.auction_id.ne(&String::from_slice(&env, "")) {
-            return AuctionResult::new(env, Status::OnAuction);
+        // check if ownership data exist.
+        let is_ownership_exist = env.storage().persistent().has(&owner);
+        if !is_ownership_exist {
+            return Status::MissingOwnership;
         }
 
-        // Set CowData's auction ID to indicate that this cow is being auctioned.
-        cow_data.auction_id = auction_id.clone();
-
-        let new_auction_data = AuctionData {
-            auction_id: auction_id.clone(),
-            cow_id: cow_id.clone(),
-            cow_name: cow_data.name.clone(),
-            cow_breed: cow_data.breed.clone(),
-            cow_gender: cow_data.gender.clone(),
-            cow_born_ledger: cow_data.born_ledger.clone(),
-            owner: user.clone(),
-            start_price: price.clone() as i128,
-            highest_bidder: Bidder {
-                user: user.clone(),
-                price: price as i128,
-            },
-            bid_history: Vec::new(&env),
-            auction_limit_ledger: env.ledger().sequence() + LEDGER_AMOUNT_IN_12_HOURS,
-        };
-
-        // Create and/or append auction list
-        let mut auction_list: Vec<String> = Vec::new(&env);
-        // if auction data exist, append the data to auction list.
-        let is_list_exist = env.storage().persistent().has(&DataKey::AuctionList);
-        if is_list_exist {
-            // get current auction list data.
-            let stored_auction_list: Vec<String> = env
-                .storage()
-                .persistent()
-                .get(&DataKey::AuctionList)
-                .unwrap();
-            auction_list.append(&stored_auction_list);
+        // every approved cow must be owned by owner.
+        for cow_id in cow_ids.iter() {
+            if !is_cow_owner(&env, &owner, &cow_id) {
+                return Status::MissingOwnership;
+            }
         }
 
-        // save auction list & bump lifetime to 1 month.
-        auction_list.push_back(auction_id.clone());
+        // save caretaker approval & bump lifetime to 1 week.
+        let caretaker_key = DataKey::Caretaker(owner.clone(), caretaker.clone());
+        let caretaker_data = CaretakerData {
+            owner,
+            caretaker,
+            cow_ids,
+            all_cows,
+            expiry_ledger,
+        };
         env.storage()
             .persistent()
-            .set(&DataKey::AuctionList, &auction_list);
-        env.storage().persistent().bump(
-            &DataKey::AuctionList,
-            LEDGER_AMOUNT_IN_1_MONTH,
-            LEDGER_AMOUNT_IN_1_MONTH,
+            .set(&caretaker_key, &caretaker_data);
+        env.storage().persistent().extend_ttl(
+            &caretaker_key,
+            LEDGER_AMOUNT_IN_1_WEEK,
+            LEDGER_AMOUNT_IN_1_WEEK,
         );
 
-        // save auction data & bump lifetime to 24 hours.
-        env.storage()
-            .temporary()
-            .set(&auction_id, &new_auction_data);
-        env.storage().temporary().bump(
-            &auction_id,
-            LEDGER_AMOUNT_IN_24_HOURS,
-            LEDGER_AMOUNT_IN_24_HOURS,
-        );
+        // publish Cowchain Farm CARETAKER event
+        CaretakerEvent::new(caretaker_data).publish(&env);
 
-        // save updated cow data.
-        env.storage().temporary().set(&cow_id, &cow_data);
+        Status::Saved
+    }
 
-        // bump user lifetime to 1 week.
-        env.storage()
-            .persistent()
-            .bump(&user, LEDGER_AMOUNT_IN_1_WEEK, LEDGER_AMOUNT_IN_1_WEEK);
+    fn revoke_caretaker(env: Env, owner: Address, caretaker: Address) -> Status {
+        // ensures that owner has authorized invocation of this contract.
+        owner.require_auth();
 
-        // publish Cowchain Farm AUCTION event
-        let new_auction_event = AuctionEventDetails {
-            auction_id: new_auction_data.auction_id.clone(),
-            cow_id: new_auction_data.cow_id.clone(),
-            name: new_auction_data.cow_name.clone(),
-            owner: new_auction_data.owner.clone(),
-            bidder: new_auction_data.highest_bidder.user.clone(),
-            price: new_auction_data.highest_bidder.price.clone(),
-            auction_limit_ledger: new_auction_data.auction_limit_ledger.clone(),
-        };
-        env.events()
-            .publish((symbol_short!("register"),), new_auction_event);
+        // check if caretaker approval exist.
+        let caretaker_key = DataKey::Caretaker(owner, caretaker);
+        let is_approval_exist = env.storage().persistent().has(&caretaker_key);
+        if !is_approval_exist {
+            return Status::NotFound;
+        }
 
-        // return result
-        let mut result: Vec<AuctionData> = Vec::new(&env);
-        result.push_back(new_auction_data);
-        AuctionResult {
-            status: Status::Ok,
-            auction_data: result,
+        // remove caretaker approval.
+        let caretaker_data: CaretakerData = env.storage().persistent().get(&caretaker_key).unwrap();
+        env.storage().persistent().remove(&caretaker_key);
+
+        // publish Cowchain Farm REVOKE event
+        RevokeEvent::new(caretaker_data).publish(&env);
+
+        Status::Ok
+    }
+
+    fn feed_as_caretaker(
+        env: Env,
+        caretaker: Address,
+        owner: Address,
+        cow_ids: Vec<String>,
+    ) -> FeedManyResult {
+        // ensures that caretaker has authorized invocation of this contract.
+        caretaker.require_auth();
+
+        // check if caretaker approval exist.
+        let caretaker_key = DataKey::Caretaker(owner.clone(), caretaker);
+        let is_approval_exist = env.storage().persistent().has(&caretaker_key);
+        if !is_approval_exist {
+            return FeedManyResult::new(env, Status::Unauthorized);
         }
+
+        // check if caretaker approval still valid, remove it if expired.
+        let caretaker_data: CaretakerData = env.storage().persistent().get(&caretaker_key).unwrap();
+        if caretaker_data.expiry_ledger <= env.ledger().sequence() {
+            env.storage().persistent().remove(&caretaker_key);
+            return FeedManyResult::new(env, Status::Expired);
+        }
+
+        // check if ownership data exist.
+        let is_ownership_exist = env.storage().persistent().has(&owner);
+        if !is_ownership_exist {
+            return FeedManyResult::new(env, Status::MissingOwnership);
+        }
+
+        // bump caretaker approval lifetime to 1 week.
+        env.storage().persistent().extend_ttl(
+            &caretaker_key,
+            LEDGER_AMOUNT_IN_1_WEEK,
+            LEDGER_AMOUNT_IN_1_WEEK,
+        );
+
+        feed_cow_batch(env, owner, cow_ids, Some(caretaker_data))
     }
 
-    fn bidding(env: Env, user: Address, auction_id: String, bid_price: u32) -> AuctionResult {
+    fn register_feeding_plan(
+        env: Env,
+        user: Address,
+        cow_ids: Vec<String>,
+        deposit: i128,
+    ) -> FeedingPlanResult {
         // ensures that user has authorized invocation of this contract.
         user.require_auth();
 
         // if Native Token key not exist, contract has not been initialized.
         let is_native_token_exist = env.storage().instance().has(&DataKey::NativeToken);
         if !is_native_token_exist {
-            return AuctionResult::new(env, Status::NotInitialized);
+            return FeedingPlanResult::new(env, Status::NotInitialized);
         }
 
-        // check if auction still on going.
-        let is_auction_alive = env.storage().temporary().has(&auction_id);
-        if !is_auction_alive {
-            return AuctionResult::new(env, Status::NotFound);
+        // deposit cannot be negative.
+        if deposit < 0 {
+            return FeedingPlanResult::new(env, Status::Fail);
         }
 
-        let mut auction_data: AuctionData = env.storage().temporary().get(&auction_id).unwrap();
-
-        // check if bidding is still open.
-        if auction_data.auction_limit_ledger < env.ledger().sequence() {
-            return AuctionResult::new(env, Status::BidIsClosed);
+        // limit the number of cows in a plan to stay under resource limits.
+        if cow_ids.len() > MAX_COWS_PER_FEEDING {
+            return FeedingPlanResult::new(env, Status::ExceedBatchLimit);
         }
 
-        // check for bidding price.
-        if (bid_price as i128) <= auction_data.highest_bidder.price {
-            return AuctionResult::new(env, Status::CannotBidLower);
+        // check if ownership data exist.
+        let is_ownership_exist = env.storage().persistent().has(&user);
+        if !is_ownership_exist {
+            return FeedingPlanResult::new(env, Status::MissingOwnership);
         }
 
-        // initiate native token client & check user balance.
-        let native_token: Address = env.storage().instance().get(&DataKey::NativeToken).unwrap();
-        let native_token_client = token::Client::new(&env, &native_token);
-        let user_native_token_balance: i128 = native_token_client.balance(&user);
-        let bid_amount = (bid_price as i128) * 10_000_000;
-        let user_balance_after_tx: i128 =
-            user_native_token_balance - MINIMUM_USER_BALANCE - bid_amount.clone();
-        if user_balance_after_tx <= 0 {
-            return AuctionResult::new(env, Status::InsufficientFund);
+        // every registered cow must be owned by user.
+        for cow_id in cow_ids.iter() {
+            if !is_cow_owner(&env, &user, &cow_id) {
+                return FeedingPlanResult::new(env, Status::MissingOwnership);
+            }
         }
 
-        // transfer native token to contract address to complete the bidding process.
-        native_token_client.transfer(&user, &env.current_contract_address(), &bid_amount);
+        // get current plan balance, if any.
+        let plan_key = DataKey::FeedingPlan(user.clone());
+        let mut plan_balance: i128 = 0;
+        let is_plan_exist = env.storage().persistent().has(&plan_key);
+        if is_plan_exist {
+            let stored_plan: FeedingPlanData = env.storage().persistent().get(&plan_key).unwrap();
+            plan_balance = stored_plan.balance;
+        }
 
-        // refund the previous highest bidder funds
-        if auction_data.owner.ne(&auction_data.highest_bidder.user) {
-            let refund_amount = auction_data.highest_bidder.price * 10_000_000;
-            native_token_client.transfer(
-                &env.current_contract_address(),
-                &auction_data.highest_bidder.user,
-                &refund_amount,
-            );
+        if deposit > 0 {
+            // initiate native token client.
+            let native_token: Address =
+                env.storage().instance().get(&DataKey::NativeToken).unwrap();
+            let native_token_client = token::Client::new(&env, &native_token);
+
+            // find out the approximate user balance after transaction.
+            let deposit_amount = deposit * 10_000_000;
+            let user_native_token_balance: i128 = native_token_client.balance(&user);
+            let user_balance_after_tx: i128 =
+                user_native_token_balance - MINIMUM_USER_BALANCE - deposit_amount;
+            if user_balance_after_tx <= 0 {
+                return FeedingPlanResult::new(env, Status::InsufficientFund);
+            }
 
-            // publish Cowchain Farm REFUND event
-            let new_auction_event = AuctionEventDetails {
-                auction_id: auction_data.auction_id.clone(),
-                cow_id: auction_data.cow_id.clone(),
-                name: auction_data.cow_name.clone(),
-                owner: auction_data.owner.clone(),
-                bidder: auction_data.highest_bidder.user.clone(),
-                price: auction_data.highest_bidder.price.clone(),
-                auction_limit_ledger: auction_data.auction_limit_ledger.clone(),
-            };
-            env.events()
-                .publish((symbol_short!("refund"),), new_auction_event);
+            // transfer native token from user to contract to prepay the plan.
+            native_token_client.transfer(&user, env.current_contract_address(), &deposit_amount);
+            plan_balance += deposit_amount;
+
+            // plan deposits belong to their owners, keep them apart from the treasury.
+            set_plan_deposits(&env, get_plan_deposits(&env) + deposit_amount);
         }
 
-        // update auction data.
-        auction_data
-            .bid_history
-            .push_back(auction_data.highest_bidder.clone());
-        auction_data.highest_bidder = Bidder {
-            user: user.clone(),
-            price: bid_price as i128,
+        // save feeding plan & bump lifetime to 1 month.
+        let plan_data = FeedingPlanData {
+            owner: user.clone(),
+            cow_ids,
+            balance: plan_balance,
         };
-
-        // save updated auction data.
-        env.storage().temporary().set(&auction_id, &auction_data);
+        env.storage().persistent().set(&plan_key, &plan_data);
+        env.storage().persistent().extend_ttl(
+            &plan_key,
+            LEDGER_AMOUNT_IN_1_MONTH,
+            LEDGER_AMOUNT_IN_1_MONTH,
+        );
 
         // bump user lifetime to 1 week.
-        env.storage()
-            .persistent()
-            .bump(&user, LEDGER_AMOUNT_IN_1_WEEK, LEDGER_AMOUNT_IN_1_WEEK);
+        bump_ownership(&env, &user);
 
-        // return result
-        let mut result: Vec<AuctionData> = Vec::new(&env);
-        result.push_back(auction_data);
-        AuctionResult {
+        // publish Cowchain Farm PLAN event
+        PlanEvent::new(plan_data.clone()).publish(&env);
+
+        let mut result: Vec<FeedingPlanData> = Vec::new(&env);
+        result.push_back(plan_data);
+        FeedingPlanResult {
+            status: Status::Saved,
+            data: result,
+        }
+    }
+
+    fn cancel_feeding_plan(env: Env, user: Address) -> FeedingPlanResult {
+        // ensures that user has authorized invocation of this contract.
+        user.require_auth();
+
+        // if Native Token key not exist, contract has not been initialized.
+        let is_native_token_exist = env.storage().instance().has(&DataKey::NativeToken);
+        if !is_native_token_exist {
+            return FeedingPlanResult::new(env, Status::NotInitialized);
+        }
+
+        // check if feeding plan exist.
+        let plan_key = DataKey::FeedingPlan(user.clone());
+        let is_plan_exist = env.storage().persistent().has(&plan_key);
+        if !is_plan_exist {
+            return FeedingPlanResult::new(env, Status::NotFound);
+        }
+
+        // refund the remaining plan balance to user.
+        let plan_data: FeedingPlanData = env.storage().persistent().get(&plan_key).unwrap();
+        if plan_data.balance > 0 {
+            let native_token: Address =
+                env.storage().instance().get(&DataKey::NativeToken).unwrap();
+            let native_token_client = token::Client::new(&env, &native_token);
+            native_token_client.transfer(
+                &env.current_contract_address(),
+                &user,
+                &plan_data.balance,
+            );
+            set_plan_deposits(&env, get_plan_deposits(&env) - plan_data.balance);
+        }
+
+        // remove feeding plan.
+        env.storage().persistent().remove(&plan_key);
+
+        // publish Cowchain Farm PLAN CANCEL event
+        PlanStopEvent::new(plan_data.clone()).publish(&env);
+
+        let mut result: Vec<FeedingPlanData> = Vec::new(&env);
+        result.push_back(plan_data);
+        FeedingPlanResult {
             status: Status::Ok,
-            auction_data: result,
+            data: result,
         }
     }
 
-    fn finalize_auction(env: Env, auction_id: String) -> AuctionResult {
-        // check if the auction is still not finalized.
-        let is_auction_alive = env.storage().temporary().has(&auction_id);
-        if !is_auction_alive {
-            return AuctionResult::new(env, Status::NotFound);
+    fn run_feeding_plan(env: Env, keeper: Address, user: Address) -> FeedManyResult {
+        // ensures that keeper has authorized invocation of this contract.
+        keeper.require_auth();
+
+        // if Native Token key not exist, contract has not been initialized.
+        let is_native_token_exist = env.storage().instance().has(&DataKey::NativeToken);
+        if !is_native_token_exist {
+            return FeedManyResult::new(env, Status::NotInitialized);
         }
 
-        let auction_data: AuctionData = env.storage().temporary().get(&auction_id).unwrap();
+        // check if feeding plan exist.
+        let plan_key = DataKey::FeedingPlan(user.clone());
+        let is_plan_exist = env.storage().persistent().has(&plan_key);
+        if !is_plan_exist {
+            return FeedManyResult::new(env, Status::NotFound);
+        }
 
-        // check if bidding is closed.
-        if auction_data.auction_limit_ledger >= env.ledger().sequence() {
-            return AuctionResult::new(env, Status::BidIsOpen);
+        // check if ownership data exist.
+        let is_ownership_exist = env.storage().persistent().has(&user);
+        if !is_ownership_exist {
+            return FeedManyResult::new(env, Status::MissingOwnership);
         }
 
-        // check if cow still alive.
-        let is_cow_alive = env.storage().temporary().has(&auction_data.cow_id);
+        let mut plan_data: FeedingPlanData = env.storage().persistent().get(&plan_key).unwrap();
+
+        // feed every cow in the plan that is in the ON TIME window,
+        // each fed cow costs one keeper fee paid from the plan balance.
+        // a cow past its ON TIME window is left for the owner, the plan never pays for late feeding.
+        let mut feeding_result: Vec<CowStatus> = Vec::new(&env);
+        let mut fed_cow_ids: Vec<String> = Vec::new(&env);
+        let mut keeper_fee: i128 = 0;
+        let mut herd_value_delta: i128 = 0;
+        for cow_id in plan_data.cow_ids.iter() {
+            // check if the cow is still owned by user.
+            if !is_cow_owner(&env, &user, &cow_id) {
+                feeding_result.push_back(CowStatus::new(env.clone(), Status::MissingOwnership));
+                continue;
+            }
 
-        // for zero bid.
-        if auction_data.owner.eq(&auction_data.highest_bidder.user) {
-            // when cow is not alive.
+            // check if cow still alive.
+            let is_cow_alive = env.storage().temporary().has(&cow_id);
             if !is_cow_alive {
-                // remove auction id.
-                env.storage().temporary().remove(&auction_id);
-                return AuctionResult::new(env, Status::Ok);
+                feeding_result.push_back(CowStatus::new(env.clone(), Status::NotFound));
+                continue;
             }
 
-            let mut cow_data: CowData =
-                env.storage().temporary().get(&auction_data.cow_id).unwrap();
-            cow_data.auction_id = String::from_slice(&env, "");
+            // stop paying keeper when the plan balance runs out.
+            if plan_data.balance - keeper_fee < FEEDING_PLAN_KEEPER_FEE {
+                feeding_result.push_back(CowStatus::new(env.clone(), Status::InsufficientFund));
+                continue;
+            }
 
-            // remove auction id.
-            env.storage().temporary().remove(&auction_id);
-            // save updated cow data.
-            env.storage()
-                .temporary()
-                .set(&auction_data.cow_id, &cow_data);
+            let Some(cow_data) = get_cow_data(&env, &cow_id) else {
+                feeding_result.push_back(CowStatus::new(env.clone(), Status::NotMigrated));
+                continue;
+            };
+            let current_ledger: u32 = env.ledger().sequence();
+            if current_ledger - cow_data.last_fed_ledger <= WELL_FED {
+                feeding_result.push_back(CowStatus::new(env.clone(), Status::FullStomach));
+                continue;
+            }
+            if get_feed_distance(&env, &cow_data, &user) > ON_TIME_FEED {
+                feeding_result.push_back(CowStatus::new(env.clone(), Status::Expired));
+                continue;
+            }
 
-            return AuctionResult::new(env, Status::Ok);
+            let cow_value = get_herd_cow_value(&env, &cow_id);
+            let feed_status = feed_cow(&env, &user, &cow_id);
+            if feed_status.status.eq(&Status::Ok) {
+                keeper_fee += FEEDING_PLAN_KEEPER_FEE;
+                herd_value_delta += get_herd_cow_value(&env, &cow_id) - cow_value;
+                fed_cow_ids.push_back(cow_id);
+            }
+            feeding_result.push_back(feed_status);
         }
 
-        // for existing bids.
-        // initiate native token client.
+        // nothing to do when no cow is hungry yet.
+        if fed_cow_ids.is_empty() {
+            return FeedManyResult {
+                status: Status::TryAgain,
+                data: feeding_result,
+                next: 0,
+            };
+        }
+
+        // pay keeper from the plan balance.
         let native_token: Address = env.storage().instance().get(&DataKey::NativeToken).unwrap();
         let native_token_client = token::Client::new(&env, &native_token);
-        let bid_amount = auction_data.highest_bidder.price * 10_000_000;
+        native_token_client.transfer(&env.current_contract_address(), &keeper, &keeper_fee);
+        set_plan_deposits(&env, get_plan_deposits(&env) - keeper_fee);
+
+        // save updated plan & bump lifetime to 1 month.
+        plan_data.balance -= keeper_fee;
+        env.storage().persistent().set(&plan_key, &plan_data);
+        env.storage().persistent().extend_ttl(
+            &plan_key,
+            LEDGER_AMOUNT_IN_1_MONTH,
+            LEDGER_AMOUNT_IN_1_MONTH,
+        );
 
-        // when cow is not alive.
-        if !is_cow_alive {
-            // refund bidder, cannot transfer died cow, lol.
-            native_token_client.transfer(
-                &env.current_contract_address(),
-                &auction_data.highest_bidder.user,
-                &bid_amount,
-            );
+        // bump user lifetime to 1 week.
+        bump_ownership(&env, &user);
 
-            // publish Cowchain Farm REFUND event
-            let new_auction_event = AuctionEventDetails {
-                auction_id: auction_data.auction_id.clone(),
-                cow_id: auction_data.cow_id.clone(),
-                name: auction_data.cow_name.clone(),
-                owner: auction_data.owner.clone(),
-                bidder: auction_data.highest_bidder.user.clone(),
-                price: auction_data.highest_bidder.price.clone(),
-                auction_limit_ledger: auction_data.auction_limit_ledger.clone(),
-            };
-            env.events()
-                .publish((symbol_short!("refund"),), new_auction_event);
+        // feeding changes the cow appraisal price.
+        add_herd_value(&env, &user, herd_value_delta);
 
-            // remove auction id.
-            env.storage().temporary().remove(&auction_id);
-            return AuctionResult::new(env, Status::Ok);
+        // publish Cowchain Farm PLAN RUN event
+        let new_plan_event = FeedingPlanEventDetails {
+            owner: user,
+            keeper,
+            cow_ids: fed_cow_ids,
+            keeper_fee,
+            remaining_balance: plan_data.balance,
+        };
+        PlanRunEvent::new(new_plan_event).publish(&env);
+
+        FeedManyResult {
+            status: Status::Ok,
+            data: feeding_result,
+            next: 0,
         }
+    }
 
-        // transfer fund to PREVIOUS owner.
-        native_token_client.transfer(
-            &env.current_contract_address(),
-            &auction_data.owner,
-            &bid_amount,
-        );
+    fn get_feeding_plan(env: Env, user: Address) -> FeedingPlanResult {
+        // check if feeding plan exist.
+        let plan_key = DataKey::FeedingPlan(user);
+        let is_plan_exist = env.storage().persistent().has(&plan_key);
+        if !is_plan_exist {
+            return FeedingPlanResult::new(env, Status::NotFound);
+        }
 
-        // update PREVIOUS ownership, save data & bump lifetime to 1 week.
-        let mut ownership: Vec<String> =
-            env.storage().persistent().get(&auction_data.owner).unwrap();
-        let index = ownership
-            .first_index_of(&auction_data.cow_id)
-            .unwrap_or(787737380);
-        if index.ne(&787737380) {
-            ownership.remove_unchecked(index);
+        let plan_data: FeedingPlanData = env.storage().persistent().get(&plan_key).unwrap();
+        let mut result: Vec<FeedingPlanData> = Vec::new(&env);
+        result.push_back(plan_data);
+        FeedingPlanResult {
+            status: Status::Found,
+            data: result,
         }
+    }
 
-        env.storage()
-            .persistent()
-            .set(&auction_data.owner, &ownership);
-        env.storage().persistent().bump(
-            &auction_data.owner,
-            LEDGER_AMOUNT_IN_1_WEEK,
-            LEDGER_AMOUNT_IN_1_WEEK,
-        );
+    fn harvest_milk(env: Env, user: Address, cow_ids: Vec<String>) -> MilkResult {
+        // ensures that user has authorized invocation of this contract.
+        user.require_auth();
 
-        // update NEW ownership, save data & bump lifetime to 1 week.
-        ownership = env
-            .storage()
-            .persistent()
-            .get(&auction_data.highest_bidder.user)
-            .unwrap();
-        ownership.push_back(auction_data.cow_id.clone());
+        // limit the number of cows milked in a single call to stay under resource limits.
+        if cow_ids.len() > MAX_COWS_PER_HARVEST {
+            return MilkResult::new(env, Status::ExceedBatchLimit);
+        }
 
-        env.storage()
-            .persistent()
-            .set(&auction_data.highest_bidder.user, &ownership);
-        env.storage().persistent().bump(
-            &auction_data.highest_bidder.user,
-            LEDGER_AMOUNT_IN_1_WEEK,
-            LEDGER_AMOUNT_IN_1_WEEK,
-        );
+        // check if ownership data exist.
+        let is_ownership_exist = env.storage().persistent().has(&user);
+        if !is_ownership_exist {
+            return MilkResult::new(env, Status::MissingOwnership);
+        }
 
-        // update auction list
-        let is_list_exist = env.storage().persistent().has(&DataKey::AuctionList);
-        if is_list_exist {
-            // get current auction list data.
-            let mut stored_auction_list: Vec<String> = env
-                .storage()
-                .persistent()
-                .get(&DataKey::AuctionList)
-                .unwrap();
-            let index = stored_auction_list
-                .first_index_of(&auction_id)
-                .unwrap_or(787737380);
-            if index.ne(&787737380) {
-                stored_auction_list.remove_unchecked(index);
+        // milk every cow, the result order follows the given cow ID list.
+        let current_ledger: u32 = env.ledger().sequence();
+        let mut harvest_result: Vec<CowStatus> = Vec::new(&env);
+        let mut milked_cow_ids: Vec<String> = Vec::new(&env);
+        let mut harvested_milk: i128 = 0;
+        for cow_id in cow_ids {
+            // check if the cow is owned by user.
+            if !is_cow_owner(&env, &user, &cow_id) {
+                harvest_result.push_back(CowStatus::new(env.clone(), Status::MissingOwnership));
+                continue;
             }
 
-            // save updated auction list & bump lifetime to 1 month.
-            env.storage()
-                .persistent()
-                .set(&DataKey::AuctionList, &stored_auction_list);
-            env.storage().persistent().bump(
-                &DataKey::AuctionList,
-                LEDGER_AMOUNT_IN_1_MONTH,
-                LEDGER_AMOUNT_IN_1_MONTH,
-            );
-        }
+            // check if cow still alive.
+            let is_cow_alive = env.storage().temporary().has(&cow_id);
+            if !is_cow_alive {
+                harvest_result.push_back(CowStatus::new(env.clone(), Status::NotFound));
+                continue;
+            }
 
-        let mut cow_data: CowData = env.storage().temporary().get(&auction_data.cow_id).unwrap();
-        cow_data.auction_id = String::from_slice(&env, "");
+            // only adult or senior cow can produce milk.
+            let Some(mut cow_data) = get_cow_data(&env, &cow_id) else {
+                harvest_result.push_back(CowStatus::new(env.clone(), Status::NotMigrated));
+                continue;
+            };
+            if is_cow_past_lifespan(&cow_data, current_ledger) {
+                harvest_result.push_back(CowStatus::new(env.clone(), Status::OldAge));
+                continue;
+            }
+            if get_cow_life_stage(&cow_data, current_ledger) < CowLifeStage::Adult {
+                harvest_result.push_back(CowStatus::new(env.clone(), Status::Underage));
+                continue;
+            }
 
-        // remove auction id.
-        env.storage().temporary().remove(&auction_id);
-        // save updated cow data.
-        env.storage()
-            .temporary()
-            .set(&auction_data.cow_id, &cow_data);
+            // only healthy female cow can produce milk.
+            let milk_amount = get_cow_milk_production(&cow_data, current_ledger);
+            if milk_amount == 0 {
+                harvest_result.push_back(CowStatus::new(env.clone(), Status::NoMilk));
+                continue;
+            }
 
-        // publish Cowchain Farm AUCTION event
-        let new_auction_event = AuctionEventDetails {
-            auction_id: auction_data.auction_id.clone(),
-            cow_id: auction_data.cow_id.clone(),
-            name: auction_data.cow_name.clone(),
-            owner: auction_data.owner.clone(),
-            bidder: auction_data.highest_bidder.user.clone(),
-            price: auction_data.highest_bidder.price.clone(),
-            auction_limit_ledger: auction_data.auction_limit_ledger.clone(),
-        };
-        env.events()
-            .publish((symbol_short!("auction"),), new_auction_event);
+            // save updated cow data.
+            cow_data.last_milked_ledger = current_ledger;
+            env.storage().temporary().set(&cow_id, &cow_data);
 
-        // return result
-        let mut result: Vec<AuctionData> = Vec::new(&env);
-        result.push_back(auction_data);
-        AuctionResult {
-            status: Status::Ok,
-            auction_data: result,
+            harvested_milk += milk_amount;
+            milked_cow_ids.push_back(cow_id);
+            harvest_result.push_back(CowStatus::new(env.clone(), Status::Ok));
         }
-    }
 
-    fn get_all_auction(env: Env) -> AuctionResult {
-        // check if auction list exist.
-        let is_list_exist = env.storage().persistent().has(&DataKey::AuctionList);
-        if !is_list_exist {
-            return AuctionResult::new(env, Status::NotFound);
-        }
+        // get user milk balance.
+        let milk_key = DataKey::MilkBalance(user.clone());
+        let mut milk_balance: i128 = env.storage().persistent().get(&milk_key).unwrap_or(0);
+
+        if harvested_milk > 0 {
+            // save user milk balance & bump lifetime to 1 week.
+            milk_balance += harvested_milk;
+            env.storage().persistent().set(&milk_key, &milk_balance);
+            env.storage().persistent().extend_ttl(
+                &milk_key,
+                LEDGER_AMOUNT_IN_1_WEEK,
+                LEDGER_AMOUNT_IN_1_WEEK,
+            );
 
-        // get auction list.
-        let stored_auction_list: Vec<String> = env
-            .storage()
-            .persistent()
-            .get(&DataKey::AuctionList)
-            .unwrap();
+            // bump user lifetime to 1 week.
+            bump_ownership(&env, &user);
 
-        // get all auction data.
-        let mut auction_data_list: Vec<AuctionData> = Vec::new(&env);
-        for auction_id in stored_auction_list {
-            // check if the auction is still not finalized.
-            let is_auction_alive = env.storage().temporary().has(&auction_id);
-            if !is_auction_alive {
-                continue;
-            }
-            let auction_data: AuctionData = env.storage().temporary().get(&auction_id).unwrap();
-            auction_data_list.push_back(auction_data);
+            // publish Cowchain Farm HARVEST event
+            let new_milk_event = MilkEventDetails {
+                owner: user,
+                cow_ids: milked_cow_ids,
+                amount: harvested_milk,
+                price: 0,
+                milk_balance,
+            };
+            HarvestEvent::new(new_milk_event).publish(&env);
         }
 
-        // return result
-        AuctionResult {
+        MilkResult {
             status: Status::Ok,
-            auction_data: auction_data_list,
+            amount: harvested_milk,
+            milk_balance,
+            data: harvest_result,
         }
     }
-}
 
-fn get_cow_base_price_in_stroops(breed: &CowBreed) -> i128 {
+    fn sell_milk(env: Env, user: Address, amount: i128) -> MilkResult {
+        // ensures that user has authorized invocation of this contract.
+        user.require_auth();
+
+        // if Native Token key not exist, contract has not been initialized.
+        let is_native_token_exist = env.storage().instance().has(&DataKey::NativeToken);
+        if !is_native_token_exist {
+            return MilkResult::new(env, Status::NotInitialized);
+        }
+
+        // milk amount must be positive.
+        if amount <= 0 {
+            return MilkResult::new(env, Status::Fail);
+        }
+
+        // check user milk balance.
+        let milk_key = DataKey::MilkBalance(user.clone());
+        let milk_balance: i128 = env.storage().persistent().get(&milk_key).unwrap_or(0);
+        if milk_balance < amount {
+            return MilkResult::new(env, Status::InsufficientFund);
+        }
+
+        // initiate native token client.
+        let native_token: Address = env.storage().instance().get(&DataKey::NativeToken).unwrap();
+        let native_token_client = token::Client::new(&env, &native_token);
+
+        // check if supplier can afford the milk.
+        let milk_selling_price: i128 = amount * MILK_PRICE_PER_MILLILITER;
+        if get_treasury_balance(&env, &native_token_client) < milk_selling_price {
+            return MilkResult::new(env, Status::InsufficientFund);
+        }
+
+        // transfer native token to user to complete the selling process.
+        native_token_client.transfer(&env.current_contract_address(), &user, &milk_selling_price);
+
+        // save user milk balance & bump lifetime to 1 week.
+        let remaining_milk_balance = milk_balance - amount;
+        env.storage()
+            .persistent()
+            .set(&milk_key, &remaining_milk_balance);
+        env.storage().persistent().extend_ttl(
+            &milk_key,
+            LEDGER_AMOUNT_IN_1_WEEK,
+            LEDGER_AMOUNT_IN_1_WEEK,
+        );
+
+        // publish Cowchain Farm SELL MILK event
+        let new_milk_event = MilkEventDetails {
+            owner: user,
+            cow_ids: Vec::new(&env),
+            amount,
+            price: milk_selling_price,
+            milk_balance: remaining_milk_balance,
+        };
+        SellMilkEvent::new(new_milk_event).publish(&env);
+
+        MilkResult {
+            status: Status::Ok,
+            amount,
+            milk_balance: remaining_milk_balance,
+            data: Vec::new(&env),
+        }
+    }
+
+    fn get_milk_balance(env: Env, user: Address) -> MilkResult {
+        let milk_key = DataKey::MilkBalance(user);
+        let milk_balance: i128 = env.storage().persistent().get(&milk_key).unwrap_or(0);
+
+        MilkResult {
+            status: Status::Ok,
+            amount: 0,
+            milk_balance,
+            data: Vec::new(&env),
+        }
+    }
+
+    fn get_cow(env: Env, cow_id: String) -> GetCowResult {
+        // check if cow still alive.
+        let is_cow_alive = env.storage().temporary().has(&cow_id);
+        if !is_cow_alive {
+            return GetCowResult::new(env, Status::NotFound);
+        }
+
+        // get cow data with its current life stage.
+        let Some(mut cow_data) = get_cow_data(&env, &cow_id) else {
+            return GetCowResult::new(env, Status::NotMigrated);
+        };
+        cow_data.life_stage = get_cow_life_stage(&cow_data, env.ledger().sequence());
+
+        let mut result: Vec<CowData> = Vec::new(&env);
+        result.push_back(cow_data);
+        GetCowResult {
+            status: Status::Ok,
+            data: result,
+        }
+    }
+
+    fn get_traits(env: Env, cow_id: String) -> CowTraitsResult {
+        // check if cow still alive.
+        let is_cow_alive = env.storage().temporary().has(&cow_id);
+        if !is_cow_alive {
+            return CowTraitsResult::new(env, Status::NotFound);
+        }
+
+        // decode cow traits from its genome, older cows get their genome when migrated.
+        let Some(cow_data) = get_cow_data(&env, &cow_id) else {
+            return CowTraitsResult::new(env, Status::NotMigrated);
+        };
+        let mut result: Vec<CowTraits> = Vec::new(&env);
+        result.push_back(get_cow_traits(&cow_data.genome));
+        CowTraitsResult {
+            status: Status::Ok,
+            data: result,
+        }
+    }
+
+    fn get_cow_by_name(env: Env, cow_name: Symbol) -> GetCowResult {
+        // get cow ID from cow unique name.
+        match get_cow_id_by_name(&env, &cow_name) {
+            Some(cow_id) => Self::get_cow(env, cow_id),
+            None => GetCowResult::new(env, Status::NotFound),
+        }
+    }
+
+    fn get_owner_of(env: Env, cow_id: String) -> OwnerResult {
+        // check if cow owner exist.
+        let owner_key = DataKey::CowOwner(cow_id);
+        let is_owner_exist = env.storage().temporary().has(&owner_key);
+        if !is_owner_exist {
+            return OwnerResult::new(env, Status::NotFound);
+        }
+
+        let owner: Address = env.storage().temporary().get(&owner_key).unwrap();
+        let mut result: Vec<Address> = Vec::new(&env);
+        result.push_back(owner);
+        OwnerResult {
+            status: Status::Found,
+            owner: result,
+        }
+    }
+
+    fn get_cows(env: Env, user: Address, start: u32, limit: u32) -> GetAllCowResult {
+        // check if ownership data exist.
+        let is_ownership_exist = env.storage().persistent().has(&user);
+        if !is_ownership_exist {
+            return GetAllCowResult {
+                status: Status::NotFound,
+                data: Vec::new(&env),
+            };
+        }
+
+        // get the requested page of ownership data.
+        let ownership_data: Vec<String> = env.storage().persistent().get(&user).unwrap();
+        let page_ownership = get_page(&ownership_data, start, limit);
+
+        // get cow data, dead cows and cows waiting to be migrated are skipped.
+        let mut cow_data_list: Vec<CowData> = Vec::new(&env);
+        for cow_id in page_ownership {
+            let Some(mut cow_data) = get_cow_data(&env, &cow_id) else {
+                continue;
+            };
+            cow_data.life_stage = get_cow_life_stage(&cow_data, env.ledger().sequence());
+            cow_data_list.push_back(cow_data);
+        }
+
+        GetAllCowResult {
+            status: Status::Ok,
+            data: cow_data_list,
+        }
+    }
+
+    fn check_ownership(env: Env, user: Address) -> OwnershipCheckResult {
+        // check if ownership data exist.
+        let is_ownership_exist = env.storage().persistent().has(&user);
+        if !is_ownership_exist {
+            return OwnershipCheckResult::new(env, Status::NotFound);
+        }
+
+        // compare every cow ID in ownership list against the cow owner record.
+        let ownership_data: Vec<String> = env.storage().persistent().get(&user).unwrap();
+        let mut dead_cow_ids: Vec<String> = Vec::new(&env);
+        let mut missing_owner_ids: Vec<String> = Vec::new(&env);
+        let mut wrong_owner_ids: Vec<String> = Vec::new(&env);
+        for cow_id in ownership_data {
+            // dead cows stay in ownership list until it is cleaned up.
+            let is_cow_alive = env.storage().temporary().has(&cow_id);
+            if !is_cow_alive {
+                dead_cow_ids.push_back(cow_id);
+                continue;
+            }
+
+            let owner_key = DataKey::CowOwner(cow_id.clone());
+            let is_owner_exist = env.storage().temporary().has(&owner_key);
+            if !is_owner_exist {
+                missing_owner_ids.push_back(cow_id);
+                continue;
+            }
+
+            let owner: Address = env.storage().temporary().get(&owner_key).unwrap();
+            if owner.ne(&user) {
+                wrong_owner_ids.push_back(cow_id);
+            }
+        }
+
+        let mut status = Status::Ok;
+        if !missing_owner_ids.is_empty() || !wrong_owner_ids.is_empty() {
+            status = Status::Fail;
+        }
+        OwnershipCheckResult {
+            status,
+            dead_cow_ids,
+            missing_owner_ids,
+            wrong_owner_ids,
+        }
+    }
+
+    fn clean_ownership(env: Env, user: Address) -> OwnershipCheckResult {
+        // ensures that user has authorized invocation of this contract.
+        user.require_auth();
+
+        // check if ownership data exist.
+        let is_ownership_exist = env.storage().persistent().has(&user);
+        if !is_ownership_exist {
+            return OwnershipCheckResult::new(env, Status::NotFound);
+        }
+
+        // keep alive cows only, dead cow data has already expired from storage.
+        let ownership_data: Vec<String> = env.storage().persistent().get(&user).unwrap();
+        let mut cow_ownership_list: Vec<String> = Vec::new(&env);
+        let mut dead_cow_ids: Vec<String> = Vec::new(&env);
+        for cow_id in ownership_data {
+            let is_cow_alive = env.storage().temporary().has(&cow_id);
+            if is_cow_alive {
+                cow_ownership_list.push_back(cow_id);
+            } else {
+                dead_cow_ids.push_back(cow_id);
+            }
+        }
+
+        // save new ownership data & bump lifetime to 1 week.
+        // dead cows are counted on the farmer profile once, when they leave the ownership list.
+        if !dead_cow_ids.is_empty() {
+            env.storage().persistent().set(&user, &cow_ownership_list);
+            bump_ownership(&env, &user);
+
+            let mut profile = get_farmer_profile(&env, &user);
+            profile.deaths += dead_cow_ids.len();
+            set_farmer_profile(&env, &user, &profile);
+        }
+
+        OwnershipCheckResult {
+            status: Status::Ok,
+            dead_cow_ids,
+            missing_owner_ids: Vec::new(&env),
+            wrong_owner_ids: Vec::new(&env),
+        }
+    }
+
+    fn migrate_cow(env: Env, user: Address, cow_id: String) -> GetCowResult {
+        // ensures that user has authorized invocation of this contract.
+        user.require_auth();
+
+        // check if cow still alive, cow data is read as a plain map since its layout is unknown.
+        let stored_cow_data: Option<Map<Symbol, Val>> = env.storage().temporary().get(&cow_id);
+        let Some(stored_cow_data) = stored_cow_data else {
+            return GetCowResult::new(env, Status::NotFound);
+        };
+
+        // cow data already saved in the current layout needs no migration.
+        if is_current_cow_layout(&env, &stored_cow_data) {
+            return GetCowResult::new(env, Status::Duplicate);
+        }
+        let Ok(legacy_cow_data) = LegacyCowData::try_from_val(&env, &stored_cow_data.to_val())
+        else {
+            return GetCowResult::new(env, Status::Fail);
+        };
+
+        // older cows are only listed in their owner's ownership list.
+        let ownership_data: Vec<String> = env
+            .storage()
+            .persistent()
+            .get(&user)
+            .unwrap_or(Vec::new(&env));
+        if !ownership_data.contains(&cow_id) {
+            return GetCowResult::new(env, Status::MissingOwnership);
+        }
+
+        // cow cannot be migrated while its older auction is open, finalize_auction closes it.
+        let is_auction_alive = !legacy_cow_data.auction_id.is_empty()
+            && env.storage().temporary().has(&legacy_cow_data.auction_id);
+        if is_auction_alive {
+            return GetCowResult::new(env, Status::OnAuction);
+        }
+
+        // the cow needs a place on one of user's plots.
+        let Some(mut plot_data) = find_free_plot(&env, &user) else {
+            return GetCowResult::new(env, Status::NoCapacity);
+        };
+
+        // fields added after the first version start from their hatching values,
+        // the genome is drawn from the cow ID so it cannot be steered.
+        let current_ledger = env.ledger().sequence();
+        let cow_id_hash: BytesN<32> = env.crypto().sha256(&cow_id.to_bytes()).into();
+        let mut genes = [0u8; 16];
+        genes.copy_from_slice(&cow_id_hash.to_array()[..16]);
+        let mut cow_data = CowData {
+            id: cow_id.clone(),
+            external_ref: String::from_str(&env, ""),
+            name: legacy_cow_data.name,
+            breed: legacy_cow_data.breed,
+            gender: legacy_cow_data.gender,
+            born_ledger: legacy_cow_data.born_ledger,
+            last_fed_ledger: legacy_cow_data.last_fed_ledger,
+            feeding_stats: CowFeedingStats {
+                on_time: legacy_cow_data.feeding_stats.on_time,
+                late: legacy_cow_data.feeding_stats.late,
+                forget: legacy_cow_data.feeding_stats.forget,
+                ..CowFeedingStats::new()
+            },
+            condition: CowCondition::new(),
+            last_milked_ledger: current_ledger,
+            life_stage: CowLifeStage::Calf,
+            last_renamed_ledger: 0,
+            auction_id: String::from_str(&env, ""),
+            genome: BytesN::from_array(&env, &genes),
+            plot_id: plot_data.id.clone(),
+        };
+        cow_data.life_stage = get_cow_life_stage(&cow_data, current_ledger);
+        add_cow_to_plot(&env, &mut plot_data, &cow_id);
+
+        // save cow data & cow owner, their lifetime stays until the cow feeding deadline.
+        env.storage().temporary().set(&cow_id, &cow_data);
+        set_cow_owner(&env, &cow_id, &user);
+
+        // move the cow unique name from the older name key to the name registry.
+        if get_cow_id_by_name(&env, &cow_data.name).eq(&Some(cow_id.clone())) {
+            env.storage().temporary().remove(&cow_data.name);
+            set_cow_name(&env, &cow_data.name, &cow_id);
+        }
+        bump_cow_entries(&env, &cow_data);
+
+        // publish Cowchain Farm MIGRATE event
+        let new_cow_event = CowEventDetails {
+            id: cow_id,
+            name: cow_data.name.clone(),
+            owner: user,
+            last_fed_ledger: cow_data.last_fed_ledger,
+            condition: cow_data.condition.clone(),
+        };
+        MigrateEvent::new(new_cow_event).publish(&env);
+
+        let mut result: Vec<CowData> = Vec::new(&env);
+        result.push_back(cow_data);
+        GetCowResult {
+            status: Status::Ok,
+            data: result,
+        }
+    }
+
+    fn get_all_cow(env: Env, user: Address) -> GetAllCowResult {
+        // ensures that user has authorized invocation of this contract.
+        user.require_auth();
+
+        // check if ownership data exist.
+        let is_ownership_exist = env.storage().persistent().has(&user);
+        if !is_ownership_exist {
+            return GetAllCowResult {
+                status: Status::Fail,
+                data: Vec::new(&env),
+            };
+        }
+
+        // get ownership data.
+        let ownership_data: Vec<String> = env.storage().persistent().get(&user).unwrap();
+
+        // get all cow data, cows waiting to be migrated are skipped.
+        let mut cow_data_list: Vec<CowData> = Vec::new(&env);
+        for cow_id in ownership_data {
+            let Some(mut cow_data) = get_cow_data(&env, &cow_id) else {
+                continue;
+            };
+            cow_data.life_stage = get_cow_life_stage(&cow_data, env.ledger().sequence());
+            cow_data_list.push_back(cow_data);
+        }
+
+        GetAllCowResult {
+            status: Status::Ok,
+            data: cow_data_list,
+        }
+    }
+
+    fn transfer_cow(env: Env, from: Address, to: Address, cow_id: String) -> CowStatus {
+        // ensures that sender has authorized invocation of this contract.
+        from.require_auth();
+
+        // check if the cow can be transferred.
+        let transfer_status = get_cow_transfer_status(&env, &from, &to, &cow_id);
+        if transfer_status.ne(&Status::Ok) {
+            return CowStatus::new(env, transfer_status);
+        }
+
+        transfer_cow_to(&env, &from, &to, &cow_id);
+        CowStatus::new(env, Status::Ok)
+    }
+
+    fn offer_gift(env: Env, from: Address, to: Address, cow_id: String) -> CowStatus {
+        // ensures that sender has authorized invocation of this contract.
+        from.require_auth();
+
+        // check if the cow can be transferred.
+        let transfer_status = get_cow_transfer_status(&env, &from, &to, &cow_id);
+        if transfer_status.ne(&Status::Ok) {
+            return CowStatus::new(env, transfer_status);
+        }
+
+        // save gift offer & bump lifetime to 24 hours.
+        // a gift that is not accepted within 24 hours is cancelled.
+        let gift_key = DataKey::Gift(cow_id.clone());
+        let gift_data = GiftData {
+            cow_id,
+            from,
+            to,
+            offered_ledger: env.ledger().sequence(),
+        };
+        env.storage().temporary().set(&gift_key, &gift_data);
+        env.storage().temporary().extend_ttl(
+            &gift_key,
+            LEDGER_AMOUNT_IN_24_HOURS,
+            LEDGER_AMOUNT_IN_24_HOURS,
+        );
+
+        // publish Cowchain Farm GIFT event
+        GiftEvent::new(gift_data).publish(&env);
+
+        CowStatus::new(env, Status::Saved)
+    }
+
+    fn accept_gift(env: Env, to: Address, cow_id: String) -> CowStatus {
+        // ensures that recipient has authorized invocation of this contract.
+        to.require_auth();
+
+        // check if gift offer exist and addressed to recipient.
+        let gift_key = DataKey::Gift(cow_id.clone());
+        let is_gift_exist = env.storage().temporary().has(&gift_key);
+        if !is_gift_exist {
+            return CowStatus::new(env, Status::NotFound);
+        }
+        let gift_data: GiftData = env.storage().temporary().get(&gift_key).unwrap();
+        if gift_data.to.ne(&to) {
+            return CowStatus::new(env, Status::Unauthorized);
+        }
+
+        // check if the cow can still be transferred.
+        let transfer_status = get_cow_transfer_status(&env, &gift_data.from, &to, &cow_id);
+        if transfer_status.ne(&Status::Ok) {
+            env.storage().temporary().remove(&gift_key);
+            return CowStatus::new(env, transfer_status);
+        }
+
+        transfer_cow_to(&env, &gift_data.from, &to, &cow_id);
+        CowStatus::new(env, Status::Ok)
+    }
+
+    fn decline_gift(env: Env, to: Address, cow_id: String) -> CowStatus {
+        // ensures that recipient has authorized invocation of this contract.
+        to.require_auth();
+
+        // check if gift offer exist and addressed to recipient.
+        let gift_key = DataKey::Gift(cow_id.clone());
+        let is_gift_exist = env.storage().temporary().has(&gift_key);
+        if !is_gift_exist {
+            return CowStatus::new(env, Status::NotFound);
+        }
+        let gift_data: GiftData = env.storage().temporary().get(&gift_key).unwrap();
+        if gift_data.to.ne(&to) {
+            return CowStatus::new(env, Status::Unauthorized);
+        }
+
+        // remove gift offer.
+        env.storage().temporary().remove(&gift_key);
+
+        // publish Cowchain Farm GIFT DECLINE event
+        GiftDenyEvent::new(gift_data).publish(&env);
+
+        CowStatus::new(env, Status::Ok)
+    }
+
+    fn rename_cow(env: Env, user: Address, cow_id: String, new_name: Symbol) -> GetCowResult {
+        // ensures that user has authorized invocation of this contract.
+        user.require_auth();
+
+        // if Native Token key not exist, contract has not been initialized.
+        let is_native_token_exist = env.storage().instance().has(&DataKey::NativeToken);
+        if !is_native_token_exist {
+            return GetCowResult::new(env, Status::NotInitialized);
+        }
+
+        // check if cow still alive.
+        let is_cow_alive = env.storage().temporary().has(&cow_id);
+        if !is_cow_alive {
+            return GetCowResult::new(env, Status::NotFound);
+        }
+
+        // check if the cow is owned by user.
+        if !is_cow_owner(&env, &user, &cow_id) {
+            return GetCowResult::new(env, Status::MissingOwnership);
+        }
+
+        // get cow data, cow saved in the first cow data layout needs to be migrated first.
+        let Some(mut cow_data) = get_cow_data(&env, &cow_id) else {
+            return GetCowResult::new(env, Status::NotMigrated);
+        };
+
+        // auction data keeps the cow name, so a cow on auction cannot be renamed.
+        if cow_data.auction_id.ne(&String::from_str(&env, "")) {
+            return GetCowResult::new(env, Status::OnAuction);
+        }
+
+        // a cow can only be renamed once in every cooldown period.
+        let current_ledger: u32 = env.ledger().sequence();
+        if cow_data.last_renamed_ledger != 0
+            && current_ledger - cow_data.last_renamed_ledger < RENAME_COOLDOWN
+        {
+            return GetCowResult::new(env, Status::TryAgain);
+        }
+
+        // check for cow UNIQUE name, cancel rename if name already exists or restricted.
+        if cow_data.name.eq(&new_name) {
+            return GetCowResult::new(env, Status::Duplicate);
+        }
+        let name_status = get_cow_name_status(&env, &user, &new_name);
+        if name_status.ne(&Status::Ok) {
+            return GetCowResult::new(env, name_status);
+        }
+
+        // initiate native token client.
+        let native_token: Address = env.storage().instance().get(&DataKey::NativeToken).unwrap();
+        let native_token_client = token::Client::new(&env, &native_token);
+
+        // cancel the transaction if user balance after paying the fee equal or less than zero.
+        let user_native_token_balance: i128 = native_token_client.balance(&user);
+        if user_native_token_balance - MINIMUM_USER_BALANCE - RENAME_FEE <= 0 {
+            return GetCowResult::new(env, Status::InsufficientFund);
+        }
+
+        // transfer rename fee to supplier.
+        native_token_client.transfer(&user, env.current_contract_address(), &RENAME_FEE);
+
+        // release old name and save the new one in name registry.
+        let old_name = cow_data.name.clone();
+        remove_cow_name(&env, &old_name);
+        set_cow_name(&env, &new_name, &cow_id);
+
+        // save updated cow data & bump cow entries until its feeding deadline.
+        cow_data.name = new_name.clone();
+        cow_data.last_renamed_ledger = current_ledger;
+        env.storage().temporary().set(&cow_id, &cow_data);
+        bump_cow_entries(&env, &cow_data);
+
+        // publish Cowchain Farm RENAME event
+        let new_rename_event = RenameEventDetails {
+            cow_id,
+            old_name,
+            new_name,
+            owner: user,
+        };
+        RenameEvent::new(new_rename_event).publish(&env);
+
+        let mut result: Vec<CowData> = Vec::new(&env);
+        result.push_back(cow_data);
+        GetCowResult {
+            status: Status::Ok,
+            data: result,
+        }
+    }
+
+    fn set_cow_metadata(env: Env, user: Address, cow_id: String, metadata: CowMetadata) -> Status {
+        // ensures that user has authorized invocation of this contract.
+        user.require_auth();
+
+        // check if cow still alive.
+        let is_cow_alive = env.storage().temporary().has(&cow_id);
+        if !is_cow_alive {
+            return Status::NotFound;
+        }
+
+        // check if the cow is owned by user.
+        if !is_cow_owner(&env, &user, &cow_id) {
+            return Status::MissingOwnership;
+        }
+
+        // check metadata length.
+        if metadata.description.len() > MAX_COW_DESCRIPTION_LENGTH
+            || metadata.color.len() > MAX_COW_TRAIT_LENGTH
+            || metadata.pattern.len() > MAX_COW_TRAIT_LENGTH
+        {
+            return Status::Fail;
+        }
+
+        // cow saved in the first cow data layout needs to be migrated first.
+        let Some(cow_data) = get_cow_data(&env, &cow_id) else {
+            return Status::NotMigrated;
+        };
+
+        // save cow metadata & bump its lifetime together with the cow data.
+        let metadata_key = DataKey::CowMetadata(cow_id.clone());
+        env.storage().temporary().set(&metadata_key, &metadata);
+        bump_cow_entries(&env, &cow_data);
+
+        Status::Saved
+    }
+
+    fn get_cow_metadata(env: Env, cow_id: String) -> CowMetadataResult {
+        // check if cow metadata exist.
+        let metadata_key = DataKey::CowMetadata(cow_id);
+        let is_metadata_exist = env.storage().temporary().has(&metadata_key);
+        if !is_metadata_exist {
+            return CowMetadataResult::new(env, Status::NotFound);
+        }
+
+        let metadata: CowMetadata = env.storage().temporary().get(&metadata_key).unwrap();
+        let mut result: Vec<CowMetadata> = Vec::new(&env);
+        result.push_back(metadata);
+        CowMetadataResult {
+            status: Status::Ok,
+            data: result,
+        }
+    }
+
+    fn set_moderator(env: Env, moderator: Address) -> Status {
+        // check Admin key in storage.
+        // if Admin key not exist, contract has not been initialized.
+        let is_admin_exist = env.storage().instance().has(&DataKey::Admin);
+        if !is_admin_exist {
+            return Status::NotInitialized;
+        }
+
+        // load the Admin address and get its authorization.
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&DataKey::Moderator, &moderator);
+        Status::Saved
+    }
+
+    fn set_name_restriction(
+        env: Env,
+        moderator: Address,
+        cow_name: Symbol,
+        restriction: NameRestriction,
+    ) -> Status {
+        // check Moderator key in storage.
+        let is_moderator_exist = env.storage().instance().has(&DataKey::Moderator);
+        if !is_moderator_exist {
+            return Status::NotFound;
+        }
+
+        // only the current Moderator can maintain the name list.
+        let current_moderator: Address = env.storage().instance().get(&DataKey::Moderator).unwrap();
+        if current_moderator.ne(&moderator) {
+            return Status::Unauthorized;
+        }
+        moderator.require_auth();
+
+        // save name restriction & bump lifetime to 1 month, or remove it when name is free.
+        let restriction_key = DataKey::NameRestriction(cow_name.clone());
+        if restriction.eq(&NameRestriction::Free) {
+            env.storage().persistent().remove(&restriction_key);
+        } else {
+            env.storage()
+                .persistent()
+                .set(&restriction_key, &restriction);
+            env.storage().persistent().extend_ttl(
+                &restriction_key,
+                LEDGER_AMOUNT_IN_1_MONTH,
+                LEDGER_AMOUNT_IN_1_MONTH,
+            );
+        }
+
+        // publish Cowchain Farm NAME RESTRICTION event
+        let new_name_rule_event = NameRuleEventDetails {
+            moderator,
+            cow_name,
+            restriction,
+        };
+        NameRuleEvent::new(new_name_rule_event).publish(&env);
+
+        Status::Saved
+    }
+
+    fn get_name_restriction(env: Env, cow_name: Symbol) -> NameRestriction {
+        env.storage()
+            .persistent()
+            .get(&DataKey::NameRestriction(cow_name))
+            .unwrap_or(NameRestriction::Free)
+    }
+
+    fn extend_my_farm(env: Env, user: Address) -> EntryTtlResult {
+        // ensures that user has authorized invocation of this contract.
+        user.require_auth();
+
+        // check if ownership data exist.
+        let is_ownership_exist = env.storage().persistent().has(&user);
+        if !is_ownership_exist {
+            return EntryTtlResult::new(env, Status::NotFound);
+        }
+
+        // bump ownership, cow, cow name and auction entries together.
+        let entries = get_farm_entries(&env, &user);
+        let mut result: Vec<EntryTtl> = Vec::new(&env);
+        for entry in entries {
+            extend_entry_ttl(&env, &entry, get_entry_ledger_amount(&entry));
+            result.push_back(get_entry_ttl(&env, &entry));
+        }
+
+        EntryTtlResult {
+            status: Status::Bumped,
+            data: result,
+        }
+    }
+
+    fn extend_entries(env: Env, entries: Vec<FarmEntry>) -> EntryTtlResult {
+        // check Admin key in storage.
+        // if Admin key not exist, contract has not been initialized.
+        let is_admin_exist = env.storage().instance().has(&DataKey::Admin);
+        if !is_admin_exist {
+            return EntryTtlResult::new(env, Status::NotInitialized);
+        }
+
+        // load the Admin address and get its authorization.
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        // limit the number of entries in a single call.
+        if entries.len() > MAX_ENTRIES_PER_EXTEND {
+            return EntryTtlResult::new(env, Status::ExceedBatchLimit);
+        }
+
+        // bump every entry that still exists.
+        let mut result: Vec<EntryTtl> = Vec::new(&env);
+        for entry in entries {
+            if is_entry_exist(&env, &entry) {
+                extend_entry_ttl(&env, &entry, get_entry_ledger_amount(&entry));
+            }
+            result.push_back(get_entry_ttl(&env, &entry));
+        }
+
+        EntryTtlResult {
+            status: Status::Bumped,
+            data: result,
+        }
+    }
+
+    fn get_entries_ttl(env: Env, entries: Vec<FarmEntry>) -> EntryTtlResult {
+        // limit the number of entries in a single call.
+        if entries.len() > MAX_ENTRIES_PER_EXTEND {
+            return EntryTtlResult::new(env, Status::ExceedBatchLimit);
+        }
+
+        let mut result: Vec<EntryTtl> = Vec::new(&env);
+        for entry in entries {
+            result.push_back(get_entry_ttl(&env, &entry));
+        }
+        EntryTtlResult {
+            status: Status::Ok,
+            data: result,
+        }
+    }
+
+    fn get_farm_ttl(env: Env, user: Address) -> EntryTtlResult {
+        // check if ownership data exist.
+        let is_ownership_exist = env.storage().persistent().has(&user);
+        if !is_ownership_exist {
+            return EntryTtlResult::new(env, Status::NotFound);
+        }
+
+        let entries = get_farm_entries(&env, &user);
+        let mut result: Vec<EntryTtl> = Vec::new(&env);
+        for entry in entries {
+            result.push_back(get_entry_ttl(&env, &entry));
+        }
+        EntryTtlResult {
+            status: Status::Ok,
+            data: result,
+        }
+    }
+
+    fn register_auction(
+        env: Env,
+        user: Address,
+        cow_id: String,
+        external_ref: String,
+        price: u32,
+    ) -> AuctionResult {
+        // check Admin key in storage.
+        // if Admin key not exist, contract has not been initialized.
+        let is_admin_exist = env.storage().instance().has(&DataKey::Admin);
+        if !is_admin_exist {
+            return AuctionResult::new(env, Status::NotInitialized);
+        }
+
+        // ensures that user has authorized invocation of this contract.
+        user.require_auth();
+
+        // check if cow still alive.
+        let is_cow_alive = env.storage().temporary().has(&cow_id);
+        if !is_cow_alive {
+            return AuctionResult::new(env, Status::NotFound);
+        }
+
+        // check if ownership data exist and the cow is owned by user.
+        let is_ownership_exist = env.storage().persistent().has(&user);
+        if !is_ownership_exist || !is_cow_owner(&env, &user, &cow_id) {
+            return AuctionResult::new(env, Status::MissingOwnership);
+        }
+
+        // cow saved in the first cow data layout needs to be migrated first.
+        let Some(mut cow_data) = get_cow_data(&env, &cow_id) else {
+            return AuctionResult::new(env, Status::NotMigrated);
+        };
+
+        // check for auction ID, cancel register if it is already being auctioned.
+        if cow_data.auction_id.ne(&String::from_str(&env, "")) {
+            return AuctionResult::new(env, Status::OnAuction);
+        }
+
+        // calf cannot be auctioned, and neither can a cow that has passed its lifespan.
+        let current_ledger: u32 = env.ledger().sequence();
+        if is_cow_past_lifespan(&cow_data, current_ledger) {
+            return AuctionResult::new(env, Status::OldAge);
+        }
+        if get_cow_life_stage(&cow_data, current_ledger).eq(&CowLifeStage::Calf) {
+            return AuctionResult::new(env, Status::Underage);
+        }
+
+        // check external reference length.
+        if external_ref.len() > MAX_EXTERNAL_REF_LENGTH {
+            return AuctionResult::new(env, Status::Fail);
+        }
+
+        // generate a unique auction ID.
+        let auction_id = generate_id(
+            &env,
+            AUCTION_ID_PREFIX,
+            DataKey::AuctionCounter,
+            is_auction_id_taken,
+        );
+
+        // Set CowData's auction ID to indicate that this cow is being auctioned.
+        cow_data.auction_id = auction_id.clone();
+
+        let new_auction_data = AuctionData {
+            auction_id: auction_id.clone(),
+            external_ref,
+            cow_id: cow_id.clone(),
+            cow_name: cow_data.name.clone(),
+            cow_breed: cow_data.breed,
+            cow_gender: cow_data.gender,
+            cow_born_ledger: cow_data.born_ledger,
+            owner: user.clone(),
+            start_price: price as i128,
+            highest_bidder: Bidder {
+                user: user.clone(),
+                price: price as i128,
+            },
+            bid_history: Vec::new(&env),
+            auction_limit_ledger: env.ledger().sequence() + LEDGER_AMOUNT_IN_12_HOURS,
+        };
+
+        // Create and/or append auction list
+        let mut auction_list: Vec<String> = Vec::new(&env);
+        // if auction data exist, append the data to auction list.
+        let is_list_exist = env.storage().persistent().has(&DataKey::AuctionList);
+        if is_list_exist {
+            // get current auction list data.
+            let stored_auction_list: Vec<String> = env
+                .storage()
+                .persistent()
+                .get(&DataKey::AuctionList)
+                .unwrap();
+            auction_list.append(&stored_auction_list);
+        }
+
+        // save auction list & bump lifetime to 1 month.
+        auction_list.push_back(auction_id.clone());
+        env.storage()
+            .persistent()
+            .set(&DataKey::AuctionList, &auction_list);
+        bump_entry_ttl(&env, &FarmEntry::AuctionList, LEDGER_AMOUNT_IN_1_MONTH);
+
+        // save auction data & bump lifetime to 1 month.
+        // auction data outlives the bidding window, so the highest bid can always be settled.
+        env.storage()
+            .persistent()
+            .set(&auction_id, &new_auction_data);
+        extend_entry_ttl(
+            &env,
+            &FarmEntry::Auction(auction_id.clone()),
+            LEDGER_AMOUNT_IN_1_MONTH,
+        );
+
+        // save updated cow data.
+        env.storage().temporary().set(&cow_id, &cow_data);
+
+        // bump user lifetime to 1 week.
+        bump_ownership(&env, &user);
+
+        // publish Cowchain Farm AUCTION event
+        let new_auction_event = AuctionEventDetails {
+            auction_id: new_auction_data.auction_id.clone(),
+            cow_id: new_auction_data.cow_id.clone(),
+            name: new_auction_data.cow_name.clone(),
+            owner: new_auction_data.owner.clone(),
+            bidder: new_auction_data.highest_bidder.user.clone(),
+            price: new_auction_data.highest_bidder.price,
+            auction_limit_ledger: new_auction_data.auction_limit_ledger,
+        };
+        RegisterEvent::new(new_auction_event).publish(&env);
+
+        // return result
+        let mut result: Vec<AuctionData> = Vec::new(&env);
+        result.push_back(new_auction_data);
+        AuctionResult {
+            status: Status::Ok,
+            auction_data: result,
+        }
+    }
+
+    fn bidding(env: Env, user: Address, auction_id: String, bid_price: u32) -> AuctionResult {
+        // ensures that user has authorized invocation of this contract.
+        user.require_auth();
+
+        // if Native Token key not exist, contract has not been initialized.
+        let is_native_token_exist = env.storage().instance().has(&DataKey::NativeToken);
+        if !is_native_token_exist {
+            return AuctionResult::new(env, Status::NotInitialized);
+        }
+
+        // check if auction still on going.
+        let is_auction_alive = env.storage().persistent().has(&auction_id);
+        if !is_auction_alive {
+            // auctions opened before the move to persistent storage take no more bids.
+            if env.storage().temporary().has(&auction_id) {
+                return AuctionResult::new(env, Status::BidIsClosed);
+            }
+            return AuctionResult::new(env, Status::NotFound);
+        }
+
+        let mut auction_data: AuctionData = env.storage().persistent().get(&auction_id).unwrap();
+
+        // check if bidding is still open.
+        if auction_data.auction_limit_ledger < env.ledger().sequence() {
+            return AuctionResult::new(env, Status::BidIsClosed);
+        }
+
+        // owner cannot bid on their own cow, finalization would keep the bid in the contract.
+        if auction_data.owner.eq(&user) {
+            return AuctionResult::new(env, Status::Unauthorized);
+        }
+
+        // check for bidding price.
+        if (bid_price as i128) <= auction_data.highest_bidder.price {
+            return AuctionResult::new(env, Status::CannotBidLower);
+        }
+
+        // the cow needs a place on one of bidder's plots, held until the auction is finalized.
+        // a bidder who already holds a place from an earlier bid gets it back.
+        let mut free_plot: Option<PlotData> = None;
+        if find_bid_plot(&env, &user, &auction_data.cow_id).is_none() {
+            free_plot = find_free_plot(&env, &user);
+            if free_plot.is_none() {
+                return AuctionResult::new(env, Status::NoCapacity);
+            }
+        }
+
+        // initiate native token client & check user balance.
+        let native_token: Address = env.storage().instance().get(&DataKey::NativeToken).unwrap();
+        let native_token_client = token::Client::new(&env, &native_token);
+        let user_native_token_balance: i128 = native_token_client.balance(&user);
+        let bid_amount = (bid_price as i128) * 10_000_000;
+        let user_balance_after_tx: i128 =
+            user_native_token_balance - MINIMUM_USER_BALANCE - bid_amount;
+        if user_balance_after_tx <= 0 {
+            return AuctionResult::new(env, Status::InsufficientFund);
+        }
+
+        // transfer native token to contract address to complete the bidding process.
+        native_token_client.transfer(&user, env.current_contract_address(), &bid_amount);
+        set_auction_escrow(&env, get_auction_escrow(&env) + bid_amount);
+
+        // hold the place for the cow, it is freed once the bidder is outbid.
+        if let Some(mut plot_data) = free_plot {
+            add_cow_to_plot(&env, &mut plot_data, &auction_data.cow_id);
+        }
+
+        // refund the previous highest bidder funds
+        if auction_data.owner.ne(&auction_data.highest_bidder.user) {
+            let refund_amount = auction_data.highest_bidder.price * 10_000_000;
+            native_token_client.transfer(
+                &env.current_contract_address(),
+                &auction_data.highest_bidder.user,
+                &refund_amount,
+            );
+            set_auction_escrow(&env, get_auction_escrow(&env) - refund_amount);
+
+            // publish Cowchain Farm REFUND event
+            let new_auction_event = AuctionEventDetails {
+                auction_id: auction_data.auction_id.clone(),
+                cow_id: auction_data.cow_id.clone(),
+                name: auction_data.cow_name.clone(),
+                owner: auction_data.owner.clone(),
+                bidder: auction_data.highest_bidder.user.clone(),
+                price: auction_data.highest_bidder.price,
+                auction_limit_ledger: auction_data.auction_limit_ledger,
+            };
+            RefundEvent::new(new_auction_event).publish(&env);
+        }
+
+        // update auction data.
+        auction_data
+            .bid_history
+            .push_back(auction_data.highest_bidder.clone());
+        auction_data.highest_bidder = Bidder {
+            user: user.clone(),
+            price: bid_price as i128,
+        };
+
+        // save updated auction data.
+        env.storage().persistent().set(&auction_id, &auction_data);
+
+        // bump user lifetime to 1 week, bidder might not own any cow yet.
+        let is_ownership_exist = env.storage().persistent().has(&user);
+        if is_ownership_exist {
+            bump_ownership(&env, &user);
+        }
+
+        // return result
+        let mut result: Vec<AuctionData> = Vec::new(&env);
+        result.push_back(auction_data);
+        AuctionResult {
+            status: Status::Ok,
+            auction_data: result,
+        }
+    }
+
+    fn finalize_auction(env: Env, auction_id: String) -> AuctionResult {
+        // check if the auction is still not finalized.
+        let is_auction_alive = env.storage().persistent().has(&auction_id);
+        if !is_auction_alive {
+            // auctions opened before the move to persistent storage are closed with a refund.
+            if env.storage().temporary().has(&auction_id) {
+                close_legacy_auction(&env, &auction_id);
+                return AuctionResult::new(env, Status::Ok);
+            }
+            return AuctionResult::new(env, Status::NotFound);
+        }
+
+        let auction_data: AuctionData = env.storage().persistent().get(&auction_id).unwrap();
+
+        // check if bidding is closed.
+        if auction_data.auction_limit_ledger >= env.ledger().sequence() {
+            return AuctionResult::new(env, Status::BidIsOpen);
+        }
+
+        // check if cow still alive.
+        let is_cow_alive = env.storage().temporary().has(&auction_data.cow_id);
+
+        // auction event details shared by every finalization branch.
+        let new_auction_event = AuctionEventDetails {
+            auction_id: auction_data.auction_id.clone(),
+            cow_id: auction_data.cow_id.clone(),
+            name: auction_data.cow_name.clone(),
+            owner: auction_data.owner.clone(),
+            bidder: auction_data.highest_bidder.user.clone(),
+            price: auction_data.highest_bidder.price,
+            auction_limit_ledger: auction_data.auction_limit_ledger,
+        };
+
+        // for zero bid.
+        if auction_data.owner.eq(&auction_data.highest_bidder.user) {
+            // when cow is not alive.
+            if !is_cow_alive {
+                // publish Cowchain Farm DEAD COW event
+                DeadCowEvent::new(new_auction_event).publish(&env);
+
+                // remove auction id.
+                remove_auction_data(&env, &auction_id);
+                return AuctionResult::new(env, Status::Ok);
+            }
+
+            let mut cow_data = get_cow_data(&env, &auction_data.cow_id).unwrap();
+            cow_data.auction_id = String::from_str(&env, "");
+
+            // remove auction id.
+            remove_auction_data(&env, &auction_id);
+            // save updated cow data.
+            env.storage()
+                .temporary()
+                .set(&auction_data.cow_id, &cow_data);
+
+            // publish Cowchain Farm NO BID event
+            NoBidEvent::new(new_auction_event).publish(&env);
+
+            return AuctionResult::new(env, Status::Ok);
+        }
+
+        // for existing bids.
+        // initiate native token client.
+        let native_token: Address = env.storage().instance().get(&DataKey::NativeToken).unwrap();
+        let native_token_client = token::Client::new(&env, &native_token);
+        let bid_amount = auction_data.highest_bidder.price * 10_000_000;
+
+        // the highest bid leaves the escrow in every branch below.
+        set_auction_escrow(&env, get_auction_escrow(&env) - bid_amount);
+
+        // when cow is not alive.
+        if !is_cow_alive {
+            // refund bidder, cannot transfer died cow, lol.
+            native_token_client.transfer(
+                &env.current_contract_address(),
+                &auction_data.highest_bidder.user,
+                &bid_amount,
+            );
+
+            // publish Cowchain Farm DEAD COW & REFUND event
+            DeadCowEvent::new(new_auction_event.clone()).publish(&env);
+            RefundEvent::new(new_auction_event).publish(&env);
+
+            // remove auction id.
+            remove_auction_data(&env, &auction_id);
+            return AuctionResult::new(env, Status::Ok);
+        }
+
+        // the cow moves to the place held for the winner since bidding.
+        // bids placed before places were held fall back to a free plot, or leave the cow unassigned.
+        let winner_plot = find_bid_plot(
+            &env,
+            &auction_data.highest_bidder.user,
+            &auction_data.cow_id,
+        )
+        .or_else(|| {
+            let mut plot_data = find_free_plot(&env, &auction_data.highest_bidder.user)?;
+            add_cow_to_plot(&env, &mut plot_data, &auction_data.cow_id);
+            Some(plot_data)
+        });
+
+        // transfer fund to PREVIOUS owner.
+        native_token_client.transfer(
+            &env.current_contract_address(),
+            &auction_data.owner,
+            &bid_amount,
+        );
+
+        // move cow ownership from PREVIOUS owner to NEW owner.
+        move_cow_ownership(
+            &env,
+            &auction_data.owner,
+            &auction_data.highest_bidder.user,
+            &auction_data.cow_id,
+        );
+
+        // the cow moves to the winner's plot.
+        let mut cow_data = get_cow_data(&env, &auction_data.cow_id).unwrap();
+        cow_data.auction_id = String::from_str(&env, "");
+        cow_data.plot_id = match winner_plot {
+            Some(plot_data) => plot_data.id,
+            None => String::from_str(&env, ""),
+        };
+
+        // remove auction id.
+        remove_auction_data(&env, &auction_id);
+        // save updated cow data.
+        env.storage()
+            .temporary()
+            .set(&auction_data.cow_id, &cow_data);
+
+        // publish Cowchain Farm AUCTION event
+        AuctionEvent::new(new_auction_event).publish(&env);
+
+        // update PREVIOUS owner & NEW owner farmer profiles.
+        let mut seller_profile = get_farmer_profile(&env, &auction_data.owner);
+        seller_profile.cows_sold += 1;
+        seller_profile.earnings += bid_amount;
+        set_farmer_profile(&env, &auction_data.owner, &seller_profile);
+        let mut winner_profile = get_farmer_profile(&env, &auction_data.highest_bidder.user);
+        winner_profile.auctions_won += 1;
+        unlock_achievement(
+            &env,
+            &auction_data.highest_bidder.user,
+            &mut winner_profile,
+            Achievement::FirstCow,
+        );
+        if winner_profile.auctions_won >= AUCTIONS_WON_ACHIEVEMENT {
+            unlock_achievement(
+                &env,
+                &auction_data.highest_bidder.user,
+                &mut winner_profile,
+                Achievement::TenAuctionsWon,
+            );
+        }
+        set_farmer_profile(&env, &auction_data.highest_bidder.user, &winner_profile);
+
+        // update leaderboards, the cow now counts to the NEW owner herd.
+        update_auction_leaderboard(&env, &auction_data.highest_bidder.user);
+        update_herd_leaderboard(&env, &auction_data.owner);
+        update_herd_leaderboard(&env, &auction_data.highest_bidder.user);
+
+        // return result
+        let mut result: Vec<AuctionData> = Vec::new(&env);
+        result.push_back(auction_data);
+        AuctionResult {
+            status: Status::Ok,
+            auction_data: result,
+        }
+    }
+
+    fn get_auction(env: Env, auction_id: String) -> AuctionResult {
+        // check if the auction is still not finalized.
+        let is_auction_alive = env.storage().persistent().has(&auction_id);
+        if !is_auction_alive {
+            return AuctionResult::new(env, Status::NotFound);
+        }
+
+        let auction_data: AuctionData = env.storage().persistent().get(&auction_id).unwrap();
+        let mut result: Vec<AuctionData> = Vec::new(&env);
+        result.push_back(auction_data);
+        AuctionResult {
+            status: Status::Ok,
+            auction_data: result,
+        }
+    }
+
+    fn get_auctions(env: Env, start: u32, limit: u32, filter: AuctionFilter) -> AuctionResult {
+        // check if auction list exist.
+        let is_list_exist = env.storage().persistent().has(&DataKey::AuctionList);
+        if !is_list_exist {
+            return AuctionResult::new(env, Status::NotFound);
+        }
+
+        // get the requested page of auction list.
+        let stored_auction_list: Vec<String> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::AuctionList)
+            .unwrap();
+        let page_auction_list = get_page(&stored_auction_list, start, limit);
+
+        // get auction data that match the filter.
+        let current_ledger: u32 = env.ledger().sequence();
+        let mut auction_data_list: Vec<AuctionData> = Vec::new(&env);
+        for auction_id in page_auction_list {
+            // check if the auction is still not finalized.
+            let is_auction_alive = env.storage().persistent().has(&auction_id);
+            if !is_auction_alive {
+                continue;
+            }
+            let auction_data: AuctionData = env.storage().persistent().get(&auction_id).unwrap();
+            let is_bid_open = auction_data.auction_limit_ledger >= current_ledger;
+            let is_match = match filter {
+                AuctionFilter::All => true,
+                AuctionFilter::Open => is_bid_open,
+                AuctionFilter::Closed => !is_bid_open,
+            };
+            if is_match {
+                auction_data_list.push_back(auction_data);
+            }
+        }
+
+        // return result
+        AuctionResult {
+            status: Status::Ok,
+            auction_data: auction_data_list,
+        }
+    }
+
+    fn get_all_auction(env: Env) -> AuctionResult {
+        // check if auction list exist.
+        let is_list_exist = env.storage().persistent().has(&DataKey::AuctionList);
+        if !is_list_exist {
+            return AuctionResult::new(env, Status::NotFound);
+        }
+
+        // get auction list.
+        let stored_auction_list: Vec<String> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::AuctionList)
+            .unwrap();
+
+        // get all auction data.
+        let mut auction_data_list: Vec<AuctionData> = Vec::new(&env);
+        for auction_id in stored_auction_list {
+            // check if the auction is still not finalized.
+            let is_auction_alive = env.storage().persistent().has(&auction_id);
+            if !is_auction_alive {
+                continue;
+            }
+            let auction_data: AuctionData = env.storage().persistent().get(&auction_id).unwrap();
+            auction_data_list.push_back(auction_data);
+        }
+
+        // return result
+        AuctionResult {
+            status: Status::Ok,
+            auction_data: auction_data_list,
+        }
+    }
+
+    fn buy_plot(env: Env, user: Address, tier: PlotTier) -> PlotResult {
+        // ensures that user has authorized invocation of this contract.
+        user.require_auth();
+        new_plot(env, user, tier, false)
+    }
+
+    fn rent_plot(env: Env, user: Address, tier: PlotTier) -> PlotResult {
+        // ensures that user has authorized invocation of this contract.
+        user.require_auth();
+        new_plot(env, user, tier, true)
+    }
+
+    fn renew_plot(env: Env, user: Address, plot_id: String) -> PlotResult {
+        // ensures that user has authorized invocation of this contract.
+        user.require_auth();
+
+        // check if plot exist and owned by user.
+        let mut plot_data = match get_plot_data(&env, &plot_id) {
+            Some(plot_data) => plot_data,
+            None => return PlotResult::new(env, Status::NotFound),
+        };
+        if plot_data.owner.ne(&user) {
+            return PlotResult::new(env, Status::Unauthorized);
+        }
+
+        // only rented plot can be renewed.
+        if plot_data.rent_until_ledger == 0 {
+            return PlotResult::new(env, Status::Fail);
+        }
+
+        // pay weekly rent to supplier.
+        let rent_price = get_plot_rent_price_in_stroops(&plot_data.tier);
+        let payment_status = pay_supplier(&env, &user, rent_price);
+        if payment_status.ne(&Status::Ok) {
+            return PlotResult::new(env, payment_status);
+        }
+
+        // the rent continues from its end, or starts again from now if it is already over.
+        let rent_start_ledger = plot_data.rent_until_ledger.max(env.ledger().sequence());
+        plot_data.rent_until_ledger = rent_start_ledger + PLOT_RENT_DURATION;
+        set_plot_data(&env, &plot_data);
+
+        // publish Cowchain Farm PLOT event
+        PlotEvent::new(plot_data.clone()).publish(&env);
+
+        let mut result: Vec<PlotData> = Vec::new(&env);
+        result.push_back(plot_data);
+        PlotResult {
+            status: Status::Ok,
+            data: result,
+        }
+    }
+
+    fn upgrade_plot(env: Env, user: Address, plot_id: String, upgrade: PlotUpgrade) -> PlotResult {
+        // ensures that user has authorized invocation of this contract.
+        user.require_auth();
+
+        // check if plot exist and owned by user.
+        let mut plot_data = match get_plot_data(&env, &plot_id) {
+            Some(plot_data) => plot_data,
+            None => return PlotResult::new(env, Status::NotFound),
+        };
+        if plot_data.owner.ne(&user) {
+            return PlotResult::new(env, Status::Unauthorized);
+        }
+
+        // rented plot must be renewed before upgrading.
+        if !is_plot_active(&env, &plot_data) {
+            return PlotResult::new(env, Status::Expired);
+        }
+
+        // every upgrade can only be built once.
+        let is_built = match upgrade {
+            PlotUpgrade::Barn => plot_data.barn,
+            PlotUpgrade::Pasture => plot_data.pasture,
+        };
+        if is_built {
+            return PlotResult::new(env, Status::Duplicate);
+        }
+
+        // pay upgrade price to supplier.
+        let upgrade_price = match upgrade {
+            PlotUpgrade::Barn => BARN_PRICE,
+            PlotUpgrade::Pasture => PASTURE_PRICE,
+        };
+        let payment_status = pay_supplier(&env, &user, upgrade_price * 10_000_000);
+        if payment_status.ne(&Status::Ok) {
+            return PlotResult::new(env, payment_status);
+        }
+
+        match upgrade {
+            PlotUpgrade::Barn => plot_data.barn = true,
+            PlotUpgrade::Pasture => plot_data.pasture = true,
+        }
+        set_plot_data(&env, &plot_data);
+
+        // publish Cowchain Farm PLOT event
+        PlotEvent::new(plot_data.clone()).publish(&env);
+
+        let mut result: Vec<PlotData> = Vec::new(&env);
+        result.push_back(plot_data);
+        PlotResult {
+            status: Status::Ok,
+            data: result,
+        }
+    }
+
+    fn assign_cow(env: Env, user: Address, cow_id: String, plot_id: String) -> PlotResult {
+        // ensures that user has authorized invocation of this contract.
+        user.require_auth();
+
+        // check if cow still alive and owned by user.
+        let is_cow_alive = env.storage().temporary().has(&cow_id);
+        if !is_cow_alive {
+            return PlotResult::new(env, Status::NotFound);
+        }
+        if !is_cow_owner(&env, &user, &cow_id) {
+            return PlotResult::new(env, Status::MissingOwnership);
+        }
+
+        // check if plot exist and owned by user.
+        let mut plot_data = match get_plot_data(&env, &plot_id) {
+            Some(plot_data) => plot_data,
+            None => return PlotResult::new(env, Status::NotFound),
+        };
+        if plot_data.owner.ne(&user) {
+            return PlotResult::new(env, Status::Unauthorized);
+        }
+        if !is_plot_active(&env, &plot_data) {
+            return PlotResult::new(env, Status::Expired);
+        }
+
+        // cow saved in the first cow data layout is assigned a plot when migrated.
+        let Some(mut cow_data) = get_cow_data(&env, &cow_id) else {
+            return PlotResult::new(env, Status::NotMigrated);
+        };
+
+        // check if the cow is already on this plot.
+        if is_cow_on_plot(&env, &plot_data, &cow_id) {
+            return PlotResult::new(env, Status::Duplicate);
+        }
+
+        // check plot capacity.
+        if !has_free_capacity(&env, &mut plot_data) {
+            return PlotResult::new(env, Status::NoCapacity);
+        }
+
+        // move the cow to the plot, its previous plot frees the place by itself.
+        add_cow_to_plot(&env, &mut plot_data, &cow_id);
+        cow_data.plot_id = plot_id;
+        env.storage().temporary().set(&cow_id, &cow_data);
+
+        // publish Cowchain Farm PLOT event
+        PlotEvent::new(plot_data.clone()).publish(&env);
+
+        let mut result: Vec<PlotData> = Vec::new(&env);
+        result.push_back(plot_data);
+        PlotResult {
+            status: Status::Ok,
+            data: result,
+        }
+    }
+
+    fn get_plot(env: Env, plot_id: String) -> PlotResult {
+        // check if plot exist.
+        let plot_data = match get_plot_data(&env, &plot_id) {
+            Some(plot_data) => plot_data,
+            None => return PlotResult::new(env, Status::NotFound),
+        };
+
+        let mut result: Vec<PlotData> = Vec::new(&env);
+        result.push_back(plot_data);
+        PlotResult {
+            status: Status::Ok,
+            data: result,
+        }
+    }
+
+    fn get_plots(env: Env, user: Address) -> PlotResult {
+        // check if user plot list exist.
+        let plot_list_key = DataKey::PlotList(user);
+        let is_list_exist = env.storage().persistent().has(&plot_list_key);
+        if !is_list_exist {
+            return PlotResult::new(env, Status::NotFound);
+        }
+
+        // get all plot data.
+        let plot_list: Vec<String> = env.storage().persistent().get(&plot_list_key).unwrap();
+        let mut result: Vec<PlotData> = Vec::new(&env);
+        for plot_id in plot_list {
+            if let Some(plot_data) = get_plot_data(&env, &plot_id) {
+                result.push_back(plot_data);
+            }
+        }
+        PlotResult {
+            status: Status::Ok,
+            data: result,
+        }
+    }
+
+    fn set_display_name(env: Env, user: Address, display_name: String) -> Status {
+        // ensures that user has authorized invocation of this contract.
+        user.require_auth();
+
+        // check display name length.
+        if display_name.len() > MAX_DISPLAY_NAME_LENGTH {
+            return Status::Fail;
+        }
+
+        // save updated farmer profile.
+        let mut profile = get_farmer_profile(&env, &user);
+        profile.display_name = display_name;
+        set_farmer_profile(&env, &user, &profile);
+
+        Status::Saved
+    }
+
+    fn get_profile(env: Env, user: Address) -> ProfileResult {
+        // check if farmer profile exist.
+        let profile_key = DataKey::Profile(user);
+        let is_profile_exist = env.storage().persistent().has(&profile_key);
+        if !is_profile_exist {
+            return ProfileResult::new(env, Status::NotFound);
+        }
+
+        let profile: FarmerProfile = env.storage().persistent().get(&profile_key).unwrap();
+        let mut result: Vec<FarmerProfile> = Vec::new(&env);
+        result.push_back(profile);
+        ProfileResult {
+            status: Status::Ok,
+            data: result,
+        }
+    }
+
+    fn get_leaderboard(env: Env, kind: LeaderboardKind) -> LeaderboardResult {
+        // check if leaderboard exist, every leaderboard is cleared at the start of a season.
+        let season = get_leaderboard_season(&env);
+        let is_leaderboard_exist = env.storage().persistent().has(&DataKey::Leaderboard(kind));
+        if !is_leaderboard_exist {
+            let mut result = LeaderboardResult::new(env, Status::NotFound);
+            result.season = season;
+            return result;
+        }
+
+        LeaderboardResult {
+            status: Status::Ok,
+            season,
+            entries: get_leaderboard_entries(&env, &kind),
+        }
+    }
+
+    fn reset_leaderboards(env: Env) -> Status {
+        // check Admin key in storage.
+        // if Admin key not exist, contract has not been initialized.
+        let is_admin_exist = env.storage().instance().has(&DataKey::Admin);
+        if !is_admin_exist {
+            return Status::NotInitialized;
+        }
+
+        // load the Admin address and get its authorization.
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        // clear every leaderboard and start a new season.
+        for kind in [
+            LeaderboardKind::BestFedCow,
+            LeaderboardKind::RichestHerd,
+            LeaderboardKind::MostAuctionsWon,
+        ] {
+            env.storage()
+                .persistent()
+                .remove(&DataKey::Leaderboard(kind));
+        }
+        let season = get_leaderboard_season(&env) + 1;
+        env.storage()
+            .instance()
+            .set(&DataKey::LeaderboardSeason, &season);
+
+        // publish Cowchain Farm SEASON event
+        let new_season_event = SeasonEventDetails { admin, season };
+        SeasonEvent::new(new_season_event).publish(&env);
+
+        Status::Ok
+    }
+
+    fn set_achievement_reward(env: Env, achievement: Achievement, reward: i128) -> Status {
+        // check Admin key in storage.
+        // if Admin key not exist, contract has not been initialized.
+        let is_admin_exist = env.storage().instance().has(&DataKey::Admin);
+        if !is_admin_exist {
+            return Status::NotInitialized;
+        }
+
+        // load the Admin address and get its authorization.
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        // achievement rewards are kept small, zero turns the reward off.
+        if !(0..=MAX_ACHIEVEMENT_REWARD).contains(&reward) {
+            return Status::Fail;
+        }
+
+        env.storage()
+            .instance()
+            .set(&DataKey::AchievementReward(achievement), &reward);
+        Status::Saved
+    }
+
+    fn get_achievement_reward(env: Env, achievement: Achievement) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey::AchievementReward(achievement))
+            .unwrap_or(0)
+    }
+}
+
+#[contractimpl]
+impl CowNftTrait for CowContract {
+    fn name(env: Env) -> String {
+        String::from_str(&env, COW_NFT_NAME)
+    }
+
+    fn symbol(env: Env) -> String {
+        String::from_str(&env, COW_NFT_SYMBOL)
+    }
+
+    fn set_base_uri(env: Env, base_uri: String) -> Status {
+        // check Admin key in storage.
+        // if Admin key not exist, contract has not been initialized.
+        let is_admin_exist = env.storage().instance().has(&DataKey::Admin);
+        if !is_admin_exist {
+            return Status::NotInitialized;
+        }
+
+        // load the Admin address and get its authorization.
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        // base URI and cow ID must fit in the token URI buffer.
+        if base_uri.len() > MAX_BASE_URI_LENGTH {
+            return Status::Fail;
+        }
+
+        env.storage().instance().set(&DataKey::BaseUri, &base_uri);
+        Status::Saved
+    }
+
+    fn token_uri(env: Env, cow_id: String) -> String {
+        // token URI is the base URI followed by the cow ID.
+        let base_uri: String = env
+            .storage()
+            .instance()
+            .get(&DataKey::BaseUri)
+            .unwrap_or(String::from_str(&env, ""));
+        join_string(&env, &base_uri, &cow_id)
+    }
+
+    fn owner_of(env: Env, cow_id: String) -> Option<Address> {
+        // check if cow still alive.
+        let is_cow_alive = env.storage().temporary().has(&cow_id);
+        if !is_cow_alive {
+            return None;
+        }
+        env.storage().temporary().get(&DataKey::CowOwner(cow_id))
+    }
+
+    fn balance_of(env: Env, owner: Address) -> u32 {
+        // check if ownership data exist.
+        let is_ownership_exist = env.storage().persistent().has(&owner);
+        if !is_ownership_exist {
+            return 0;
+        }
+
+        // count the cows that are still alive.
+        let ownership_data: Vec<String> = env.storage().persistent().get(&owner).unwrap();
+        let mut balance: u32 = 0;
+        for cow_id in ownership_data {
+            let is_cow_alive = env.storage().temporary().has(&cow_id);
+            if is_cow_alive {
+                balance += 1;
+            }
+        }
+        balance
+    }
+
+    fn approve(
+        env: Env,
+        owner: Address,
+        approved: Address,
+        cow_id: String,
+        expiry_ledger: u32,
+    ) -> Status {
+        // ensures that owner has authorized invocation of this contract.
+        owner.require_auth();
+
+        // the approval must end at a future ledger.
+        if expiry_ledger <= env.ledger().sequence() {
+            return Status::Expired;
+        }
+
+        // check if cow still alive.
+        let is_cow_alive = env.storage().temporary().has(&cow_id);
+        if !is_cow_alive {
+            return Status::NotFound;
+        }
+
+        // check if the cow is owned by owner.
+        if !is_cow_owner(&env, &owner, &cow_id) {
+            return Status::MissingOwnership;
+        }
+
+        // save cow approval & bump lifetime to 24 hours, same as the cow data.
+        let approval_key = DataKey::Approval(cow_id.clone());
+        let approval_data = ApprovalData {
+            owner: owner.clone(),
+            approved: approved.clone(),
+            expiry_ledger,
+        };
+        env.storage().temporary().set(&approval_key, &approval_data);
+        env.storage().temporary().extend_ttl(
+            &approval_key,
+            LEDGER_AMOUNT_IN_24_HOURS,
+            LEDGER_AMOUNT_IN_24_HOURS,
+        );
+
+        // publish standard APPROVE event
+        ApproveEvent {
+            owner,
+            cow_id,
+            approved,
+            expiry_ledger,
+        }
+        .publish(&env);
+
+        Status::Saved
+    }
+
+    fn get_approved(env: Env, cow_id: String) -> Option<Address> {
+        // check if cow approval exist.
+        let approval_key = DataKey::Approval(cow_id.clone());
+        let is_approval_exist = env.storage().temporary().has(&approval_key);
+        if !is_approval_exist {
+            return None;
+        }
+
+        // approval is only valid until its expiry ledger, and only for the owner who gave it.
+        let approval_data: ApprovalData = env.storage().temporary().get(&approval_key).unwrap();
+        if approval_data.expiry_ledger <= env.ledger().sequence()
+            || !is_cow_owner(&env, &approval_data.owner, &cow_id)
+        {
+            return None;
+        }
+        Some(approval_data.approved)
+    }
+
+    fn set_approval_for_all(env: Env, owner: Address, operator: Address, approved: bool) -> Status {
+        // ensures that owner has authorized invocation of this contract.
+        owner.require_auth();
+
+        // owner cannot be their own operator.
+        if owner.eq(&operator) {
+            return Status::Fail;
+        }
+
+        // save or remove operator approval.
+        let operator_key = DataKey::Operator(owner.clone(), operator.clone());
+        if approved {
+            env.storage().persistent().set(&operator_key, &true);
+            env.storage().persistent().extend_ttl(
+                &operator_key,
+                LEDGER_AMOUNT_IN_1_WEEK,
+                LEDGER_AMOUNT_IN_1_WEEK,
+            );
+        } else {
+            env.storage().persistent().remove(&operator_key);
+        }
+
+        // publish standard APPROVE FOR ALL event
+        ApproveForAllEvent {
+            owner,
+            operator,
+            approved,
+        }
+        .publish(&env);
+
+        Status::Saved
+    }
+
+    fn is_approved_for_all(env: Env, owner: Address, operator: Address) -> bool {
+        env.storage()
+            .persistent()
+            .has(&DataKey::Operator(owner, operator))
+    }
+
+    fn transfer_from(
+        env: Env,
+        spender: Address,
+        from: Address,
+        to: Address,
+        cow_id: String,
+    ) -> CowStatus {
+        // ensures that spender has authorized invocation of this contract.
+        spender.require_auth();
+
+        // spender must be the owner, the approved address, or an approved operator.
+        let is_owner = spender.eq(&from);
+        let is_approved =
+            Self::get_approved(env.clone(), cow_id.clone()).eq(&Some(spender.clone()));
+        let is_operator = Self::is_approved_for_all(env.clone(), from.clone(), spender.clone());
+        if !is_owner && !is_approved && !is_operator {
+            return CowStatus::new(env, Status::Unauthorized);
+        }
+
+        // check if the cow can be transferred.
+        let transfer_status = get_cow_transfer_status(&env, &from, &to, &cow_id);
+        if transfer_status.ne(&Status::Ok) {
+            return CowStatus::new(env, transfer_status);
+        }
+
+        transfer_cow_to(&env, &from, &to, &cow_id);
+        CowStatus::new(env, Status::Ok)
+    }
+}
+
+fn get_cow_base_price_in_stroops(breed: &CowBreed) -> i128 {
     // get cow price based on their breed (the price will be in XLM unit).
     let cow_price_in_native_token = match breed {
         CowBreed::Jersey => JERSEY_PRICE,
@@ -913,25 +2947,1512 @@ fn get_cow_base_price_in_stroops(breed: &CowBreed) -> i128 {
         CowBreed::Simmental => SIMMENTAL_PRICE,
     };
 
-    // we need to convert the price from XLM to stroops unit.
-    // this is because Soroban uses the smallest unit for its operation.
-    // convert price to stroops unit by multiplying XLM unit with 10 million.
-    cow_price_in_native_token * 10_000_000
+    // we need to convert the price from XLM to stroops unit.
+    // this is because Soroban uses the smallest unit for its operation.
+    // convert price to stroops unit by multiplying XLM unit with 10 million.
+    cow_price_in_native_token * 10_000_000
+}
+
+fn get_cow_appraisal_price(cow_data: &CowData, cow_base_price: i128, current_ledger: u32) -> i128 {
+    // calculate appraisal multiplier.
+    let on_time_rewards: i128 = (cow_data.feeding_stats.on_time as i128) * ON_TIME_REWARD;
+    let late_rewards: i128 = (cow_data.feeding_stats.late as i128) * LATE_REWARD;
+    let forget_fines: i128 = (cow_data.feeding_stats.forget as i128) * FORGET_FINE;
+
+    // healthier and heavier cows are worth more, sick and skinny cows are worth less.
+    let health_rewards: i128 =
+        (cow_data.condition.health as i128 - INITIAL_HEALTH as i128) * HEALTH_POINT_REWARD;
+    let weight_rewards: i128 =
+        (cow_data.condition.weight as i128 - INITIAL_WEIGHT as i128) * WEIGHT_POINT_REWARD;
+
+    // senior cows depreciate for every day after they become senior.
+    let senior_ledger: u32 = cow_data.born_ledger + get_cow_life_cycle(&cow_data.breed)[2];
+    let mut senior_fines: i128 = 0;
+    if current_ledger > senior_ledger {
+        let senior_days = (current_ledger - senior_ledger) / LEDGER_AMOUNT_IN_24_HOURS;
+        senior_fines = (senior_days as i128) * SENIOR_DAILY_FINE;
+    }
+
+    // genetic traits and rarity add their own appraisal bonus.
+    let traits_rewards: i128 = get_cow_traits(&cow_data.genome).appraisal_bonus;
+
+    let mut rewards_fines_multiplier: i128 =
+        on_time_rewards + late_rewards - forget_fines + health_rewards + weight_rewards
+            - senior_fines
+            + traits_rewards;
+    if rewards_fines_multiplier < -PRECISION_100_PERCENT {
+        // rewards_or_fines cannot less than 0.
+        rewards_fines_multiplier = -PRECISION_100_PERCENT;
+    }
+    let rewards_or_fines: i128 =
+        (cow_base_price * rewards_fines_multiplier) / PRECISION_100_PERCENT;
+
+    // calculate cow appraisal price.
+    cow_base_price + rewards_or_fines
+}
+
+fn feed_cow(env: &Env, user: &Address, cow_id: &String) -> CowStatus {
+    // get cow data from storage, cow saved in the first cow data layout needs to be migrated first.
+    let Some(mut cow_data) = get_cow_data(env, cow_id) else {
+        return CowStatus::new(env.clone(), Status::NotMigrated);
+    };
+
+    // a cow that has passed its lifespan dies of old age, remove its data, owner & unique name.
+    if is_cow_past_lifespan(&cow_data, env.ledger().sequence()) {
+        remove_cow_data(env, &cow_data);
+        return CowStatus::new(env.clone(), Status::OldAge);
+    }
+
+    // so in 24 hours there are approximately 17280 ledger.
+    // we have 4 feeding time zone, that is every 4320 ledger.
+    //
+    // the time zones are:
+    // 1st 4320 ledger -> FULL
+    // 2nd 4320 ledger -> ON TIME
+    // 3rd 4320 ledger -> LATE
+    // 4th 4320 ledger -> FORGET
+    //
+    // the basic rule in Cowchain Farm are:
+    // if feed distance are less than 4320 ledger, the cow won't eat, still full.
+    // if feed distance are more than 17280 ledger, the cow will die.
+
+    // find out feeding distance.
+    let current_ledger: u32 = env.ledger().sequence();
+    let last_fed_ledger: u32 = cow_data.last_fed_ledger;
+
+    // when the cow is still full, no bump operation will be made to its data.
+    if current_ledger - last_fed_ledger <= WELL_FED {
+        return CowStatus::new(env.clone(), Status::FullStomach);
+    }
+    let feed_distance: u32 = get_feed_distance(env, &cow_data, user);
+
+    // calculate feeding stats.
+    let mut on_time = cow_data.feeding_stats.on_time;
+    let mut late = cow_data.feeding_stats.late;
+    let mut forget = cow_data.feeding_stats.forget;
+    let mut current_streak = cow_data.feeding_stats.current_streak;
+    let mut best_streak = cow_data.feeding_stats.best_streak;
+    let mut streak_start_ledger = cow_data.feeding_stats.streak_start_ledger;
+
+    // every ON TIME feeding extends the streak, any other feeding breaks it.
+    let is_on_time = feed_distance > WELL_FED && feed_distance <= ON_TIME_FEED;
+    let is_streak_broken = !is_on_time && current_streak > 0;
+    if is_on_time {
+        // a new streak starts from the feeding right before its first ON TIME feeding.
+        if current_streak == 0 {
+            streak_start_ledger = last_fed_ledger;
+        }
+        on_time += 1;
+        current_streak += 1;
+        best_streak = best_streak.max(current_streak);
+    } else {
+        current_streak = 0;
+    }
+    if feed_distance > ON_TIME_FEED && feed_distance <= LATE_FEED {
+        late += 1;
+    }
+    if feed_distance > LATE_FEED {
+        forget += 1;
+    }
+
+    // update cow data.
+    cow_data.last_fed_ledger = env.ledger().sequence();
+    cow_data.feeding_stats = CowFeedingStats {
+        on_time,
+        late,
+        forget,
+        current_streak,
+        best_streak,
+        streak_start_ledger,
+    };
+    cow_data.condition = get_cow_condition_after_feeding(&cow_data.condition, feed_distance);
+    let life_stage = get_cow_life_stage(&cow_data, current_ledger);
+    let is_calf_raised =
+        cow_data.life_stage.eq(&CowLifeStage::Calf) && life_stage.ne(&CowLifeStage::Calf);
+    cow_data.life_stage = life_stage;
+
+    // save updated cow data & bump cow entries lifetime to 24 hours.
+    env.storage().temporary().set(cow_id, &cow_data);
+    bump_cow_entries(env, &cow_data);
+
+    // publish Cowchain Farm FEED event
+    let new_cow_event = CowEventDetails {
+        id: cow_data.id.clone(),
+        name: cow_data.name.clone(),
+        owner: user.clone(),
+        last_fed_ledger: cow_data.last_fed_ledger,
+        condition: cow_data.condition.clone(),
+    };
+    FeedEvent::new(new_cow_event).publish(env);
+
+    // update farmer profile & achievements.
+    if is_on_time || is_calf_raised {
+        let mut profile = get_farmer_profile(env, user);
+        if is_on_time {
+            profile.on_time_feeds += 1;
+        }
+        if current_streak > 0 && current_ledger - streak_start_ledger >= STREAK_ACHIEVEMENT_DURATION
+        {
+            unlock_achievement(env, user, &mut profile, Achievement::ThirtyDayStreak);
+        }
+        if is_calf_raised {
+            unlock_achievement(env, user, &mut profile, Achievement::RaisedCalf);
+        }
+        set_farmer_profile(env, user, &profile);
+    }
+
+    // update best fed cow leaderboard, a broken streak leaves the leaderboard.
+    if is_on_time || is_streak_broken {
+        let new_entry = LeaderboardEntry {
+            owner: user.clone(),
+            cow_id: cow_id.clone(),
+            score: current_streak as i128,
+        };
+        update_leaderboard(env, LeaderboardKind::BestFedCow, new_entry);
+    }
+
+    CowStatus {
+        status: Status::Ok,
+        ledger: cow_data.last_fed_ledger,
+    }
+}
+
+fn get_feed_distance(env: &Env, cow_data: &CowData, user: &Address) -> u32 {
+    // feeding tolerance gene and plot upgrades extend the ON TIME and LATE feeding windows.
+    let feed_distance: u32 = env.ledger().sequence() - cow_data.last_fed_ledger;
+    let feeding_tolerance: u32 = get_cow_traits(&cow_data.genome).feeding_tolerance
+        + get_plot_feeding_tolerance(env, cow_data, user);
+    feed_distance
+        .saturating_sub(feeding_tolerance)
+        .max(WELL_FED + 1)
+}
+
+fn feed_cow_batch(
+    env: Env,
+    user: Address,
+    cow_ids: Vec<String>,
+    caretaker: Option<CaretakerData>,
+) -> FeedManyResult {
+    // limit the number of cows fed in a single call to stay under resource limits.
+    if cow_ids.len() > MAX_COWS_PER_FEEDING {
+        return FeedManyResult::new(env, Status::ExceedBatchLimit);
+    }
+
+    // feed every cow, the result order follows the given cow ID list.
+    let mut feeding_result: Vec<CowStatus> = Vec::new(&env);
+    let mut fed_cow_ids: Vec<String> = Vec::new(&env);
+    let mut herd_value_delta: i128 = 0;
+    for cow_id in cow_ids {
+        // check if the cow is owned by user.
+        if !is_cow_owner(&env, &user, &cow_id) {
+            feeding_result.push_back(CowStatus::new(env.clone(), Status::MissingOwnership));
+            continue;
+        }
+
+        // check if the cow is covered by caretaker approval.
+        if let Some(caretaker_data) = &caretaker {
+            if !caretaker_data.all_cows && !caretaker_data.cow_ids.contains(&cow_id) {
+                feeding_result.push_back(CowStatus::new(env.clone(), Status::Unauthorized));
+                continue;
+            }
+        }
+
+        // check if cow still alive.
+        let is_cow_alive = env.storage().temporary().has(&cow_id);
+        if !is_cow_alive {
+            feeding_result.push_back(CowStatus::new(env.clone(), Status::NotFound));
+            continue;
+        }
+
+        let cow_value = get_herd_cow_value(&env, &cow_id);
+        let feed_status = feed_cow(&env, &user, &cow_id);
+        if feed_status.status.eq(&Status::Ok) {
+            herd_value_delta += get_herd_cow_value(&env, &cow_id) - cow_value;
+            fed_cow_ids.push_back(cow_id);
+        }
+        feeding_result.push_back(feed_status);
+    }
+
+    if !fed_cow_ids.is_empty() {
+        // bump user lifetime to 1 week.
+        bump_ownership(&env, &user);
+
+        // feeding changes the cow appraisal price.
+        add_herd_value(&env, &user, herd_value_delta);
+
+        // publish Cowchain Farm FEED MANY event
+        let new_feed_event = FeedManyEventDetails {
+            owner: user,
+            cow_ids: fed_cow_ids,
+            last_fed_ledger: env.ledger().sequence(),
+        };
+        FeedManyEvent::new(new_feed_event).publish(&env);
+    }
+
+    FeedManyResult {
+        status: Status::Ok,
+        data: feeding_result,
+        next: 0,
+    }
+}
+
+fn get_cow_condition_after_feeding(condition: &CowCondition, feed_distance: u32) -> CowCondition {
+    let mut weight = condition.weight;
+    let mut health = condition.health;
+    let mut mood = condition.mood;
+
+    // ON TIME feeding makes the cow grow, healthy and happy.
+    if feed_distance > WELL_FED && feed_distance <= ON_TIME_FEED {
+        weight += ON_TIME_WEIGHT_GAIN;
+        health = (health + ON_TIME_HEALTH_GAIN).min(MAX_HEALTH);
+        mood = (mood + ON_TIME_MOOD_GAIN).min(MAX_MOOD);
+    }
+    // LATE feeding still makes the cow grow, but the cow gets grumpy.
+    if feed_distance > ON_TIME_FEED && feed_distance <= LATE_FEED {
+        weight += LATE_WEIGHT_GAIN;
+        mood = mood.saturating_sub(LATE_MOOD_LOSS);
+    }
+    // FORGET feeding makes the cow lose weight, get sick and sad.
+    if feed_distance > LATE_FEED {
+        weight = weight.saturating_sub(FORGET_WEIGHT_LOSS).max(MIN_WEIGHT);
+        health = health.saturating_sub(FORGET_HEALTH_LOSS);
+        mood = mood.saturating_sub(FORGET_MOOD_LOSS);
+    }
+
+    CowCondition {
+        weight,
+        health,
+        mood,
+    }
+}
+
+fn get_cow_milk_production(cow_data: &CowData, current_ledger: u32) -> i128 {
+    // only female cow in good health can produce milk.
+    if cow_data.gender.ne(&CowGender::Female) || cow_data.condition.health < MILKING_MIN_HEALTH {
+        return 0;
+    }
+
+    // milk accrues from the later of the last harvest or the day the cow become adult.
+    let adult_ledger: u32 = cow_data.born_ledger + get_cow_life_cycle(&cow_data.breed)[1];
+    let start_ledger: u32 = cow_data.last_milked_ledger.max(adult_ledger);
+    if current_ledger <= start_ledger {
+        return 0;
+    }
+
+    // unharvested milk will spoil, so it only accrues for up to 24 hours.
+    let milking_ledger: u32 = (current_ledger - start_ledger).min(LEDGER_AMOUNT_IN_24_HOURS);
+
+    // get daily milk production based on breed (the amount will be in milliliter unit).
+    let daily_milk_production: i128 = match cow_data.breed {
+        CowBreed::Jersey => JERSEY_DAILY_MILK,
+        CowBreed::Limousin => LIMOUSIN_DAILY_MILK,
+        CowBreed::Hallikar => HALLIKAR_DAILY_MILK,
+        CowBreed::Hereford => HEREFORD_DAILY_MILK,
+        CowBreed::Holstein => HOLSTEIN_DAILY_MILK,
+        CowBreed::Simmental => SIMMENTAL_DAILY_MILK,
+    };
+
+    // milk yield gene increases daily milk production.
+    let milk_yield_bonus: i128 = get_cow_traits(&cow_data.genome).milk_yield_bonus;
+    let daily_milk_production: i128 =
+        daily_milk_production * (PRECISION_100_PERCENT + milk_yield_bonus) / PRECISION_100_PERCENT;
+
+    // healthier cow produces more milk.
+    daily_milk_production * (milking_ledger as i128) * (cow_data.condition.health as i128)
+        / (LEDGER_AMOUNT_IN_24_HOURS as i128)
+        / (MAX_HEALTH as i128)
+}
+
+fn get_cow_traits(genome: &BytesN<16>) -> CowTraits {
+    let genes: [u8; COW_GENOME_LENGTH] = genome.to_array();
+
+    // visible traits, rarer variants give more rarity points.
+    let coat_index = get_gene_variant(genes[COAT_GENE], &COAT_THRESHOLDS);
+    let horns_index = get_gene_variant(genes[HORNS_GENE], &HORNS_THRESHOLDS);
+    let size_index = get_gene_variant(genes[SIZE_GENE], &SIZE_THRESHOLDS);
+    let coat = [
+        CowCoat::Brown,
+        CowCoat::Black,
+        CowCoat::White,
+        CowCoat::Red,
+        CowCoat::Spotted,
+        CowCoat::Golden,
+    ][coat_index];
+    let horns = [
+        CowHorns::Polled,
+        CowHorns::Short,
+        CowHorns::Long,
+        CowHorns::Curved,
+    ][horns_index];
+    let size = [
+        CowSize::Small,
+        CowSize::Medium,
+        CowSize::Large,
+        CowSize::Giant,
+    ][size_index];
+
+    // rarity tier from total rarity points.
+    let rarity_points: u32 = COAT_RARITY_POINTS[coat_index]
+        + HORNS_RARITY_POINTS[horns_index]
+        + SIZE_RARITY_POINTS[size_index];
+    let rarity_index = RARITY_THRESHOLDS
+        .iter()
+        .filter(|threshold| rarity_points >= **threshold)
+        .count();
+    let rarity = [
+        CowRarity::Common,
+        CowRarity::Uncommon,
+        CowRarity::Rare,
+        CowRarity::Epic,
+        CowRarity::Legendary,
+    ][rarity_index];
+
+    // hidden stat modifiers, scaled by their gene.
+    let feeding_tolerance: u32 =
+        genes[FEEDING_TOLERANCE_GENE] as u32 * MAX_FEEDING_TOLERANCE / u8::MAX as u32;
+    let milk_yield_bonus: i128 =
+        genes[MILK_YIELD_GENE] as i128 * MAX_MILK_YIELD_BONUS / u8::MAX as i128;
+    let appraisal_bonus: i128 = genes[APPRAISAL_GENE] as i128 * MAX_APPRAISAL_BONUS
+        / u8::MAX as i128
+        + RARITY_APPRAISAL_BONUS[rarity_index];
+
+    CowTraits {
+        genome: genome.clone(),
+        coat,
+        horns,
+        size,
+        rarity,
+        feeding_tolerance,
+        milk_yield_bonus,
+        appraisal_bonus,
+    }
+}
+
+fn get_gene_variant(gene: u8, thresholds: &[u32]) -> usize {
+    // pick the first variant whose threshold is above the gene value.
+    thresholds
+        .iter()
+        .position(|threshold| (gene as u32) < *threshold)
+        .unwrap_or(thresholds.len() - 1)
+}
+
+fn get_cow_life_cycle(breed: &CowBreed) -> [u32; 4] {
+    // get cow life cycle based on their breed (the age will be in ledger unit).
+    match breed {
+        CowBreed::Jersey => JERSEY_LIFE_CYCLE,
+        CowBreed::Limousin => LIMOUSIN_LIFE_CYCLE,
+        CowBreed::Hallikar => HALLIKAR_LIFE_CYCLE,
+        CowBreed::Hereford => HEREFORD_LIFE_CYCLE,
+        CowBreed::Holstein => HOLSTEIN_LIFE_CYCLE,
+        CowBreed::Simmental => SIMMENTAL_LIFE_CYCLE,
+    }
+}
+
+fn get_cow_life_stage(cow_data: &CowData, current_ledger: u32) -> CowLifeStage {
+    let cow_age: u32 = current_ledger - cow_data.born_ledger;
+    let life_cycle = get_cow_life_cycle(&cow_data.breed);
+
+    if cow_age < life_cycle[0] {
+        return CowLifeStage::Calf;
+    }
+    if cow_age < life_cycle[1] {
+        return CowLifeStage::Young;
+    }
+    if cow_age < life_cycle[2] {
+        return CowLifeStage::Adult;
+    }
+    CowLifeStage::Senior
+}
+
+fn is_cow_past_lifespan(cow_data: &CowData, current_ledger: u32) -> bool {
+    let cow_age: u32 = current_ledger - cow_data.born_ledger;
+    cow_age >= get_cow_life_cycle(&cow_data.breed)[3]
+}
+
+fn set_cow_owner(env: &Env, cow_id: &String, owner: &Address) {
+    // save cow owner & bump lifetime to 24 hours, same as the cow data.
+    let owner_key = DataKey::CowOwner(cow_id.clone());
+    env.storage().temporary().set(&owner_key, owner);
+    env.storage().temporary().extend_ttl(
+        &owner_key,
+        LEDGER_AMOUNT_IN_24_HOURS,
+        LEDGER_AMOUNT_IN_24_HOURS,
+    );
+}
+
+fn get_page(list: &Vec<String>, start: u32, limit: u32) -> Vec<String> {
+    // limit page size to stay under resource limits.
+    let page_size = limit.min(MAX_PAGE_SIZE);
+    if start >= list.len() || page_size == 0 {
+        return Vec::new(list.env());
+    }
+    let end = (start + page_size).min(list.len());
+    list.slice(start..end)
+}
+
+fn is_cow_owner(env: &Env, user: &Address, cow_id: &String) -> bool {
+    // cows saved by the first contract version have no cow owner record until migrate_cow,
+    // so they fail this check without any write from read-only callers.
+    let owner_key = DataKey::CowOwner(cow_id.clone());
+    let is_owner_exist = env.storage().temporary().has(&owner_key);
+    if !is_owner_exist {
+        return false;
+    }
+    let owner: Address = env.storage().temporary().get(&owner_key).unwrap();
+    owner.eq(user)
+}
+
+fn move_cow_ownership(env: &Env, from: &Address, to: &Address, cow_id: &String) {
+    // update PREVIOUS ownership, save data & bump lifetime to 1 week.
+    let mut ownership: Vec<String> = env.storage().persistent().get(from).unwrap();
+    let index = ownership.first_index_of(cow_id).unwrap_or(787737380);
+    if index.ne(&787737380) {
+        ownership.remove_unchecked(index);
+    }
+    env.storage().persistent().set(from, &ownership);
+    bump_ownership(env, from);
+
+    // update NEW ownership, save data & bump lifetime to 1 week.
+    // the new owner might not own any cow yet.
+    ownership = env.storage().persistent().get(to).unwrap_or(Vec::new(env));
+    ownership.push_back(cow_id.clone());
+    env.storage().persistent().set(to, &ownership);
+    bump_ownership(env, to);
+
+    // save NEW cow owner & bump lifetime to 24 hours.
+    set_cow_owner(env, cow_id, to);
+
+    // any pending gift offer or approval for this cow is no longer valid.
+    env.storage()
+        .temporary()
+        .remove(&DataKey::Gift(cow_id.clone()));
+    env.storage()
+        .temporary()
+        .remove(&DataKey::Approval(cow_id.clone()));
+
+    // publish standard TRANSFER event
+    NftTransferEvent {
+        from: from.clone(),
+        to: to.clone(),
+        cow_id: cow_id.clone(),
+    }
+    .publish(env);
+}
+
+fn get_cow_transfer_status(env: &Env, from: &Address, to: &Address, cow_id: &String) -> Status {
+    // cannot transfer cow to yourself.
+    if from.eq(to) {
+        return Status::Fail;
+    }
+
+    // check if cow still alive.
+    let is_cow_alive = env.storage().temporary().has(cow_id);
+    if !is_cow_alive {
+        return Status::NotFound;
+    }
+
+    // check if ownership data exist and the cow is owned by sender.
+    let is_ownership_exist = env.storage().persistent().has(from);
+    if !is_ownership_exist || !is_cow_owner(env, from, cow_id) {
+        return Status::MissingOwnership;
+    }
+
+    // cow saved in the first cow data layout needs to be migrated first.
+    let Some(cow_data) = get_cow_data(env, cow_id) else {
+        return Status::NotMigrated;
+    };
+
+    // check for auction ID, cancel transfer if exist.
+    if cow_data.auction_id.ne(&String::from_str(env, "")) {
+        return Status::OnAuction;
+    }
+
+    // the cow needs a place on one of recipient's plots.
+    if find_free_plot(env, to).is_none() {
+        return Status::NoCapacity;
+    }
+
+    Status::Ok
+}
+
+fn transfer_cow_to(env: &Env, from: &Address, to: &Address, cow_id: &String) {
+    // move cow ownership from sender to recipient.
+    move_cow_ownership(env, from, to, cow_id);
+
+    // the cow moves to the recipient's plot, checked by the transfer status beforehand.
+    let mut cow_data = get_cow_data(env, cow_id).unwrap();
+    let mut plot_data = find_free_plot(env, to).unwrap();
+    cow_data.plot_id = plot_data.id.clone();
+    add_cow_to_plot(env, &mut plot_data, cow_id);
+    env.storage().temporary().set(cow_id, &cow_data);
+
+    // bump cow entries until its feeding deadline, a transfer does not feed the cow.
+    bump_cow_entries(env, &cow_data);
+
+    // the cow leaves the sender herd and joins the recipient herd.
+    update_herd_leaderboard(env, from);
+    update_herd_leaderboard(env, to);
+
+    // publish Cowchain Farm TRANSFER event
+    let new_transfer_event = TransferEventDetails {
+        cow_id: cow_data.id,
+        name: cow_data.name,
+        from: from.clone(),
+        to: to.clone(),
+    };
+    TransferEvent::new(new_transfer_event).publish(env);
+}
+
+fn join_string(env: &Env, left: &String, right: &String) -> String {
+    // soroban String has no concatenation, so copy both into a buffer.
+    let left_length = left.len() as usize;
+    let right_length = right.len() as usize;
+    let mut buffer = [0u8; MAX_TOKEN_URI_LENGTH];
+    if left_length + right_length > MAX_TOKEN_URI_LENGTH {
+        return right.clone();
+    }
+    left.copy_into_slice(&mut buffer[..left_length]);
+    right.copy_into_slice(&mut buffer[left_length..left_length + right_length]);
+
+    let joined = core::str::from_utf8(&buffer[..left_length + right_length]).unwrap_or("");
+    String::from_str(env, joined)
+}
+
+fn get_cow_id_by_name(env: &Env, cow_name: &Symbol) -> Option<String> {
+    // look up the name registry first, then the unique name key used by older cows.
+    let name_key = DataKey::CowName(cow_name.clone());
+    let mut cow_id: Option<String> = env.storage().persistent().get(&name_key);
+    if cow_id.is_none() {
+        cow_id = env.storage().temporary().get(cow_name);
+    }
+
+    // a name only belongs to a cow while the cow is still alive or waiting to be hatched.
+    match cow_id {
+        Some(id)
+            if env.storage().temporary().has(&id)
+                || env
+                    .storage()
+                    .temporary()
+                    .has(&DataKey::CowOrder(id.clone())) =>
+        {
+            Some(id)
+        }
+        _ => None,
+    }
+}
+
+fn get_cow_name_status(env: &Env, user: &Address, cow_name: &Symbol) -> Status {
+    // name must not be used by another living cow.
+    if get_cow_id_by_name(env, cow_name).is_some() {
+        return Status::NameAlreadyExist;
+    }
+
+    // banned names cannot be used, and reserved names can only be used by Moderator.
+    let restriction: NameRestriction = env
+        .storage()
+        .persistent()
+        .get(&DataKey::NameRestriction(cow_name.clone()))
+        .unwrap_or(NameRestriction::Free);
+    let moderator: Option<Address> = env.storage().instance().get(&DataKey::Moderator);
+    match restriction {
+        NameRestriction::Free => Status::Ok,
+        NameRestriction::Reserved if moderator.eq(&Some(user.clone())) => Status::Ok,
+        _ => Status::NameRestricted,
+    }
+}
+
+fn set_cow_name(env: &Env, cow_name: &Symbol, cow_id: &String) {
+    // save cow unique name & bump lifetime to 1 week.
+    env.storage()
+        .persistent()
+        .set(&DataKey::CowName(cow_name.clone()), cow_id);
+    bump_cow_name(env, cow_name);
+}
+
+fn bump_cow_name(env: &Env, cow_name: &Symbol) {
+    let name_key = DataKey::CowName(cow_name.clone());
+    let is_name_exist = env.storage().persistent().has(&name_key);
+    if is_name_exist {
+        env.storage().persistent().extend_ttl(
+            &name_key,
+            LEDGER_AMOUNT_IN_1_WEEK,
+            LEDGER_AMOUNT_IN_1_WEEK,
+        );
+    }
+}
+
+fn remove_cow_name(env: &Env, cow_name: &Symbol) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::CowName(cow_name.clone()));
+    env.storage()
+        .persistent()
+        .remove(&DataKey::LiveUntil(FarmEntry::CowName(cow_name.clone())));
+    env.storage().temporary().remove(cow_name);
+}
+
+fn bump_cow_entries(env: &Env, cow_data: &CowData) {
+    // cow entries live until the cow feeding deadline, bumping them never feeds the cow.
+    let live_until_ledger = get_cow_live_until_ledger(cow_data);
+    let current_ledger = env.ledger().sequence();
+    if live_until_ledger > current_ledger {
+        let ledger_amount = live_until_ledger - current_ledger;
+        let cow_keys = [
+            DataKey::CowOwner(cow_data.id.clone()),
+            DataKey::CowMetadata(cow_data.id.clone()),
+            DataKey::Approval(cow_data.id.clone()),
+        ];
+        env.storage()
+            .temporary()
+            .extend_ttl(&cow_data.id, ledger_amount, ledger_amount);
+        for cow_key in cow_keys {
+            let is_key_exist = env.storage().temporary().has(&cow_key);
+            if is_key_exist {
+                env.storage()
+                    .temporary()
+                    .extend_ttl(&cow_key, ledger_amount, ledger_amount);
+            }
+        }
+    }
+
+    // cow unique name lives in persistent storage and outlives the cow.
+    bump_cow_name(env, &cow_data.name);
+}
+
+fn get_cow_live_until_ledger(cow_data: &CowData) -> u32 {
+    cow_data.last_fed_ledger + LEDGER_AMOUNT_IN_24_HOURS
+}
+
+fn get_cow_name_live_until_ledger(env: &Env, cow_name: &Symbol) -> u32 {
+    // every feeding bumps the name of a living cow to 1 week, so the name lives at least
+    // 1 week after the last feeding, without writing a lifetime record on the feeding path.
+    let Some(cow_id) = get_cow_id_by_name(env, cow_name) else {
+        return 0;
+    };
+    let Some(cow_data) = get_cow_data(env, &cow_id) else {
+        return 0;
+    };
+    cow_data.last_fed_ledger + LEDGER_AMOUNT_IN_1_WEEK
+}
+
+fn bump_ownership(env: &Env, user: &Address) {
+    // bump user ownership lifetime to 1 week.
+    bump_entry_ttl(
+        env,
+        &FarmEntry::Ownership(user.clone()),
+        LEDGER_AMOUNT_IN_1_WEEK,
+    );
 }
 
-fn get_cow_appraisal_price(cow_data: &CowData, cow_base_price: i128) -> i128 {
-    // calculate appraisal multiplier.
-    let on_time_rewards: i128 = (cow_data.feeding_stats.on_time as i128) * ON_TIME_REWARD;
-    let late_rewards: i128 = (cow_data.feeding_stats.late as i128) * LATE_REWARD;
-    let forget_fines: i128 = (cow_data.feeding_stats.forget as i128) * FORGET_FINE;
-    let mut rewards_fines_multiplier: i128 = on_time_rewards + late_rewards - forget_fines;
-    if rewards_fines_multiplier < -PRECISION_100_PERCENT {
-        // rewards_or_fines cannot less than 0.
-        rewards_fines_multiplier = -PRECISION_100_PERCENT;
+fn bump_entry_ttl(env: &Env, entry: &FarmEntry, ledger_amount: u32) {
+    // farm actions skip entries already bumped in the last 24 hours,
+    // so the lifetime record is rewritten at most once a day.
+    let live_until_ledger = get_entry_ttl(env, entry).live_until_ledger;
+    if live_until_ledger + LEDGER_AMOUNT_IN_24_HOURS >= env.ledger().sequence() + ledger_amount {
+        return;
     }
-    let rewards_or_fines: i128 =
-        (cow_base_price * rewards_fines_multiplier) / PRECISION_100_PERCENT;
+    extend_entry_ttl(env, entry, ledger_amount);
+}
 
-    // calculate cow appraisal price.
-    cow_base_price + rewards_or_fines
+fn extend_entry_ttl(env: &Env, entry: &FarmEntry, ledger_amount: u32) {
+    // soroban cannot read entry lifetime, so the expected live until ledger is kept in a record
+    // stored next to the entry, with the same lifetime.
+    let record_key = DataKey::LiveUntil(entry.clone());
+    let live_until_ledger = env.ledger().sequence() + ledger_amount;
+    match entry {
+        FarmEntry::Ownership(user) => {
+            env.storage()
+                .persistent()
+                .extend_ttl(user, ledger_amount, ledger_amount);
+            env.storage()
+                .persistent()
+                .set(&record_key, &live_until_ledger);
+            env.storage()
+                .persistent()
+                .extend_ttl(&record_key, ledger_amount, ledger_amount);
+        }
+        FarmEntry::AuctionList => {
+            env.storage().persistent().extend_ttl(
+                &DataKey::AuctionList,
+                ledger_amount,
+                ledger_amount,
+            );
+            env.storage()
+                .persistent()
+                .set(&record_key, &live_until_ledger);
+            env.storage()
+                .persistent()
+                .extend_ttl(&record_key, ledger_amount, ledger_amount);
+        }
+        FarmEntry::Auction(auction_id) => {
+            env.storage()
+                .persistent()
+                .extend_ttl(auction_id, ledger_amount, ledger_amount);
+            env.storage()
+                .persistent()
+                .set(&record_key, &live_until_ledger);
+            env.storage()
+                .persistent()
+                .extend_ttl(&record_key, ledger_amount, ledger_amount);
+        }
+        FarmEntry::Cow(cow_id) => {
+            // cow saved in the first cow data layout is bumped when migrated.
+            if let Some(cow_data) = get_cow_data(env, cow_id) {
+                bump_cow_entries(env, &cow_data);
+            }
+        }
+        FarmEntry::CowName(cow_name) => {
+            // feeding bumps the name without a record, only explicit extension records it.
+            bump_cow_name(env, cow_name);
+            env.storage()
+                .persistent()
+                .set(&record_key, &live_until_ledger);
+            env.storage()
+                .persistent()
+                .extend_ttl(&record_key, ledger_amount, ledger_amount);
+        }
+    }
+}
+
+fn is_entry_exist(env: &Env, entry: &FarmEntry) -> bool {
+    match entry {
+        FarmEntry::Ownership(user) => env.storage().persistent().has(user),
+        FarmEntry::AuctionList => env.storage().persistent().has(&DataKey::AuctionList),
+        FarmEntry::Auction(auction_id) => env.storage().persistent().has(auction_id),
+        FarmEntry::Cow(cow_id) => env.storage().temporary().has(cow_id),
+        FarmEntry::CowName(cow_name) => env
+            .storage()
+            .persistent()
+            .has(&DataKey::CowName(cow_name.clone())),
+    }
+}
+
+fn get_entry_ttl(env: &Env, entry: &FarmEntry) -> EntryTtl {
+    // entry that does not exist, or whose lifetime was never recorded, reports zero.
+    let mut live_until_ledger: u32 = 0;
+    if is_entry_exist(env, entry) {
+        let record_key = DataKey::LiveUntil(entry.clone());
+        live_until_ledger = match entry {
+            FarmEntry::Ownership(_) | FarmEntry::AuctionList | FarmEntry::Auction(_) => {
+                env.storage().persistent().get(&record_key).unwrap_or(0)
+            }
+            FarmEntry::Cow(cow_id) => match get_cow_data(env, cow_id) {
+                Some(cow_data) => get_cow_live_until_ledger(&cow_data),
+                None => 0,
+            },
+            FarmEntry::CowName(cow_name) => {
+                let recorded_ledger: u32 = env.storage().persistent().get(&record_key).unwrap_or(0);
+                recorded_ledger.max(get_cow_name_live_until_ledger(env, cow_name))
+            }
+        };
+    }
+
+    let current_ledger = env.ledger().sequence();
+    let mut remaining_ledger: u32 = 0;
+    if live_until_ledger > current_ledger {
+        remaining_ledger = live_until_ledger - current_ledger;
+    }
+    EntryTtl {
+        entry: entry.clone(),
+        live_until_ledger,
+        remaining_ledger,
+    }
+}
+
+fn get_farm_entries(env: &Env, user: &Address) -> Vec<FarmEntry> {
+    // user farm consists of the ownership list, every living cow with its name, and their auctions,
+    // cows saved in the first cow data layout are left out until they are migrated.
+    let mut entries: Vec<FarmEntry> = Vec::new(env);
+    entries.push_back(FarmEntry::Ownership(user.clone()));
+
+    let ownership_data: Vec<String> = env
+        .storage()
+        .persistent()
+        .get(user)
+        .unwrap_or(Vec::new(env));
+    for cow_id in ownership_data {
+        let Some(cow_data) = get_cow_data(env, &cow_id) else {
+            continue;
+        };
+        entries.push_back(FarmEntry::Cow(cow_id));
+        entries.push_back(FarmEntry::CowName(cow_data.name));
+
+        let is_auction_exist = env.storage().persistent().has(&cow_data.auction_id);
+        if !cow_data.auction_id.is_empty() && is_auction_exist {
+            entries.push_back(FarmEntry::Auction(cow_data.auction_id));
+        }
+    }
+    entries
+}
+
+fn get_entry_ledger_amount(entry: &FarmEntry) -> u32 {
+    match entry {
+        FarmEntry::Ownership(_) | FarmEntry::CowName(_) => LEDGER_AMOUNT_IN_1_WEEK,
+        FarmEntry::AuctionList | FarmEntry::Auction(_) => LEDGER_AMOUNT_IN_1_MONTH,
+        FarmEntry::Cow(_) => LEDGER_AMOUNT_IN_24_HOURS,
+    }
+}
+
+fn close_legacy_auction(env: &Env, auction_id: &String) {
+    // the cow of an older auction has no place on a plot until it is migrated, so the auction
+    // is closed without a sale. its highest bid was never counted in the auction escrow.
+    let auction_data: LegacyAuctionData = env.storage().temporary().get(auction_id).unwrap();
+    let new_auction_event = AuctionEventDetails {
+        auction_id: auction_data.auction_id.clone(),
+        cow_id: auction_data.cow_id.clone(),
+        name: auction_data.cow_name.clone(),
+        owner: auction_data.owner.clone(),
+        bidder: auction_data.highest_bidder.user.clone(),
+        price: auction_data.highest_bidder.price,
+        auction_limit_ledger: auction_data.auction_limit_ledger,
+    };
+
+    // refund the highest bidder, or close the auction without any bid.
+    if auction_data.owner.ne(&auction_data.highest_bidder.user) {
+        let native_token: Address = env.storage().instance().get(&DataKey::NativeToken).unwrap();
+        let native_token_client = token::Client::new(env, &native_token);
+        let refund_amount = auction_data.highest_bidder.price * 10_000_000;
+        native_token_client.transfer(
+            &env.current_contract_address(),
+            &auction_data.highest_bidder.user,
+            &refund_amount,
+        );
+
+        // publish Cowchain Farm REFUND event
+        RefundEvent::new(new_auction_event.clone()).publish(env);
+    } else {
+        // publish Cowchain Farm NO BID event
+        NoBidEvent::new(new_auction_event.clone()).publish(env);
+    }
+
+    // the cow leaves the auction, in whichever layout it is saved.
+    let empty_auction_id = String::from_str(env, "");
+    let is_cow_alive = env.storage().temporary().has(&auction_data.cow_id);
+    if let Some(mut cow_data) = get_cow_data(env, &auction_data.cow_id) {
+        if cow_data.auction_id.eq(auction_id) {
+            cow_data.auction_id = empty_auction_id;
+            env.storage()
+                .temporary()
+                .set(&auction_data.cow_id, &cow_data);
+        }
+    } else if is_cow_alive {
+        let mut cow_data: LegacyCowData =
+            env.storage().temporary().get(&auction_data.cow_id).unwrap();
+        if cow_data.auction_id.eq(auction_id) {
+            cow_data.auction_id = empty_auction_id;
+            env.storage()
+                .temporary()
+                .set(&auction_data.cow_id, &cow_data);
+        }
+    } else {
+        // publish Cowchain Farm DEAD COW event
+        DeadCowEvent::new(new_auction_event).publish(env);
+    }
+
+    // remove auction id.
+    env.storage().temporary().remove(auction_id);
+    remove_auction_data(env, auction_id);
+}
+
+fn remove_auction_data(env: &Env, auction_id: &String) {
+    // remove auction data & its lifetime record.
+    env.storage().persistent().remove(auction_id);
+    env.storage()
+        .persistent()
+        .remove(&DataKey::LiveUntil(FarmEntry::Auction(auction_id.clone())));
+
+    // remove auction id from auction list.
+    let is_list_exist = env.storage().persistent().has(&DataKey::AuctionList);
+    if is_list_exist {
+        // get current auction list data.
+        let mut stored_auction_list: Vec<String> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::AuctionList)
+            .unwrap();
+        let index = stored_auction_list
+            .first_index_of(auction_id)
+            .unwrap_or(787737380);
+        if index.ne(&787737380) {
+            stored_auction_list.remove_unchecked(index);
+        }
+
+        // save updated auction list & bump lifetime to 1 month.
+        env.storage()
+            .persistent()
+            .set(&DataKey::AuctionList, &stored_auction_list);
+        bump_entry_ttl(env, &FarmEntry::AuctionList, LEDGER_AMOUNT_IN_1_MONTH);
+    }
+}
+
+fn remove_cow_data(env: &Env, cow_data: &CowData) {
+    // remove cow data, cow owner, cow metadata & cow unique name.
+    env.storage().temporary().remove(&cow_data.id);
+    env.storage()
+        .temporary()
+        .remove(&DataKey::CowOwner(cow_data.id.clone()));
+    env.storage()
+        .temporary()
+        .remove(&DataKey::CowMetadata(cow_data.id.clone()));
+    remove_cow_name(env, &cow_data.name);
+}
+
+fn seed_hatch_prng(env: &Env, order: &CowOrder, salt: &BytesN<32>) {
+    // hatch seed is the hash of the revealed salt, the hatch entropy and the cow ID.
+    // the entropy was drawn when the order was applied and nothing returns it, so the order
+    // reveals nothing about the cow, while a rolled back hatch gets the same cow on retry.
+    // the salt was committed before the entropy was drawn, so it cannot steer the cow either.
+    let hatch_entropy: BytesN<32> = env
+        .storage()
+        .temporary()
+        .get(&DataKey::HatchEntropy(order.cow_id.clone()))
+        .unwrap();
+    let mut seed = Bytes::from_array(env, &salt.to_array());
+    seed.extend_from_array(&hatch_entropy.to_array());
+    seed.append(&order.cow_id.to_bytes());
+    let seed_hash: BytesN<32> = env.crypto().sha256(&seed).into();
+    env.prng().seed(seed_hash.into());
+}
+
+fn get_random_gender(env: &Env) -> CowGender {
+    // random Cow Gender
+    let value = env.prng().gen_range::<u64>(1..=6);
+    if value.is_multiple_of(2) {
+        return CowGender::Female;
+    }
+    CowGender::Male
+}
+
+fn generate_id(
+    env: &Env,
+    prefix: &str,
+    counter_key: DataKey,
+    is_id_taken: fn(&Env, &String) -> bool,
+) -> String {
+    // IDs come from a monotonic counter, skipping any ID already taken by older client-supplied IDs.
+    let mut counter: u64 = env.storage().instance().get(&counter_key).unwrap_or(0);
+    loop {
+        counter += 1;
+        let id = format_id(env, prefix, counter);
+        if !is_id_taken(env, &id) {
+            env.storage().instance().set(&counter_key, &counter);
+            return id;
+        }
+    }
+}
+
+fn is_cow_id_taken(env: &Env, id: &String) -> bool {
+    env.storage().temporary().has(id)
+}
+
+fn is_auction_id_taken(env: &Env, id: &String) -> bool {
+    // auctions opened before the move to persistent storage still live in temporary storage.
+    env.storage().persistent().has(id) || env.storage().temporary().has(id)
+}
+
+fn is_plot_id_taken(env: &Env, id: &String) -> bool {
+    env.storage().persistent().has(&DataKey::Plot(id.clone()))
+}
+
+fn format_id(env: &Env, prefix: &str, number: u64) -> String {
+    // write the prefix followed by the decimal digits of the number.
+    let mut buffer = [0u8; 32];
+    let prefix_length = prefix.len();
+    buffer[..prefix_length].copy_from_slice(prefix.as_bytes());
+
+    let mut digits = [0u8; 20];
+    let mut digit_count = 0;
+    let mut remaining = number;
+    loop {
+        digits[digit_count] = b'0' + (remaining % 10) as u8;
+        digit_count += 1;
+        remaining /= 10;
+        if remaining == 0 {
+            break;
+        }
+    }
+    for index in 0..digit_count {
+        buffer[prefix_length + index] = digits[digit_count - 1 - index];
+    }
+
+    let id = core::str::from_utf8(&buffer[..prefix_length + digit_count]).unwrap_or("");
+    String::from_str(env, id)
+}
+
+fn new_plot(env: Env, user: Address, tier: PlotTier, rented: bool) -> PlotResult {
+    // limit the number of plots, every plot is read when looking for free capacity.
+    let plot_list_key = DataKey::PlotList(user.clone());
+    let mut plot_list: Vec<String> = env
+        .storage()
+        .persistent()
+        .get(&plot_list_key)
+        .unwrap_or(Vec::new(&env));
+    if plot_list.len() >= MAX_PLOTS_PER_USER {
+        return PlotResult::new(env, Status::Fail);
+    }
+
+    // pay plot price or the first weekly rent to supplier.
+    let plot_price = match rented {
+        true => get_plot_rent_price_in_stroops(&tier),
+        false => get_plot_price_in_stroops(&tier),
+    };
+    let payment_status = pay_supplier(&env, &user, plot_price);
+    if payment_status.ne(&Status::Ok) {
+        return PlotResult::new(env, payment_status);
+    }
+
+    // new plot data, an owned plot has no rent limit.
+    let rent_until_ledger = match rented {
+        true => env.ledger().sequence() + PLOT_RENT_DURATION,
+        false => 0,
+    };
+    let plot_data = PlotData {
+        id: generate_id(&env, PLOT_ID_PREFIX, DataKey::PlotCounter, is_plot_id_taken),
+        owner: user,
+        tier,
+        capacity: PLOT_CAPACITY[tier as usize - 1],
+        cow_ids: Vec::new(&env),
+        rent_until_ledger,
+        barn: false,
+        pasture: false,
+    };
+    set_plot_data(&env, &plot_data);
+
+    // save user plot list & bump lifetime to 1 month.
+    plot_list.push_back(plot_data.id.clone());
+    env.storage().persistent().set(&plot_list_key, &plot_list);
+    env.storage().persistent().extend_ttl(
+        &plot_list_key,
+        LEDGER_AMOUNT_IN_1_MONTH,
+        LEDGER_AMOUNT_IN_1_MONTH,
+    );
+
+    // publish Cowchain Farm PLOT event
+    PlotEvent::new(plot_data.clone()).publish(&env);
+
+    let mut result: Vec<PlotData> = Vec::new(&env);
+    result.push_back(plot_data);
+    PlotResult {
+        status: Status::Ok,
+        data: result,
+    }
+}
+
+fn pay_supplier(env: &Env, user: &Address, amount: i128) -> Status {
+    // if Native Token key not exist, contract has not been initialized.
+    let is_native_token_exist = env.storage().instance().has(&DataKey::NativeToken);
+    if !is_native_token_exist {
+        return Status::NotInitialized;
+    }
+
+    // cancel the payment if user balance after paying equal or less than zero.
+    let native_token: Address = env.storage().instance().get(&DataKey::NativeToken).unwrap();
+    let native_token_client = token::Client::new(env, &native_token);
+    let user_native_token_balance: i128 = native_token_client.balance(user);
+    if user_native_token_balance - MINIMUM_USER_BALANCE - amount <= 0 {
+        return Status::InsufficientFund;
+    }
+
+    native_token_client.transfer(user, env.current_contract_address(), &amount);
+    Status::Ok
+}
+
+fn get_plot_price_in_stroops(tier: &PlotTier) -> i128 {
+    PLOT_PRICE[*tier as usize - 1] * 10_000_000
+}
+
+fn get_plot_rent_price_in_stroops(tier: &PlotTier) -> i128 {
+    PLOT_RENT_PRICE[*tier as usize - 1] * 10_000_000
+}
+
+fn get_cow_data(env: &Env, cow_id: &String) -> Option<CowData> {
+    // cow data is read as a plain map first, cows saved in the first cow data layout
+    // are left out until their owner migrates them.
+    let stored_cow_data: Map<Symbol, Val> = env.storage().temporary().get(cow_id)?;
+    if !is_current_cow_layout(env, &stored_cow_data) {
+        return None;
+    }
+    CowData::try_from_val(env, &stored_cow_data.to_val()).ok()
+}
+
+fn is_current_cow_layout(env: &Env, stored_cow_data: &Map<Symbol, Val>) -> bool {
+    // plot ID was added together with the rest of the current cow data layout.
+    stored_cow_data.contains_key(Symbol::new(env, "plot_id"))
+}
+
+fn get_plot_data(env: &Env, plot_id: &String) -> Option<PlotData> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Plot(plot_id.clone()))
+}
+
+fn set_plot_data(env: &Env, plot_data: &PlotData) {
+    // save plot data & bump lifetime to 1 month.
+    let plot_key = DataKey::Plot(plot_data.id.clone());
+    env.storage().persistent().set(&plot_key, plot_data);
+    env.storage().persistent().extend_ttl(
+        &plot_key,
+        LEDGER_AMOUNT_IN_1_MONTH,
+        LEDGER_AMOUNT_IN_1_MONTH,
+    );
+}
+
+fn is_plot_active(env: &Env, plot_data: &PlotData) -> bool {
+    // owned plot is always active, rented plot only until its rent is over.
+    plot_data.rent_until_ledger == 0 || plot_data.rent_until_ledger >= env.ledger().sequence()
+}
+
+fn is_cow_on_plot(env: &Env, plot_data: &PlotData, cow_id: &String) -> bool {
+    // an order waiting to be hatched keeps its place on the plot.
+    let order: Option<CowOrder> = env
+        .storage()
+        .temporary()
+        .get(&DataKey::CowOrder(cow_id.clone()));
+    if let Some(order) = order {
+        return order.plot_id.eq(&plot_data.id);
+    }
+
+    // a cow only stays on the plot while it is alive, assigned to it, and owned by plot owner.
+    let cow_data = get_cow_data(env, cow_id);
+    match cow_data {
+        Some(cow_data) if cow_data.plot_id.eq(&plot_data.id) => {
+            let owner: Option<Address> = env
+                .storage()
+                .temporary()
+                .get(&DataKey::CowOwner(cow_id.clone()));
+            owner.eq(&Some(plot_data.owner.clone()))
+        }
+        // a cow on auction also holds a place on a plot of its highest bidder.
+        Some(cow_data) if !cow_data.auction_id.is_empty() => {
+            let auction_data: Option<AuctionData> =
+                env.storage().persistent().get(&cow_data.auction_id);
+            match auction_data {
+                Some(auction_data) => {
+                    auction_data.owner.ne(&plot_data.owner)
+                        && auction_data.highest_bidder.user.eq(&plot_data.owner)
+                }
+                None => false,
+            }
+        }
+        _ => false,
+    }
+}
+
+fn has_free_capacity(env: &Env, plot_data: &mut PlotData) -> bool {
+    if !is_plot_active(env, plot_data) {
+        return false;
+    }
+
+    // cows that left the plot are only dropped from the list once the plot looks full.
+    if plot_data.cow_ids.len() >= plot_data.capacity {
+        let mut cow_ids: Vec<String> = Vec::new(env);
+        for cow_id in plot_data.cow_ids.iter() {
+            if is_cow_on_plot(env, plot_data, &cow_id) {
+                cow_ids.push_back(cow_id);
+            }
+        }
+        plot_data.cow_ids = cow_ids;
+    }
+    plot_data.cow_ids.len() < plot_data.capacity
+}
+
+fn find_free_plot(env: &Env, user: &Address) -> Option<PlotData> {
+    // pick the first user plot with free capacity.
+    let plot_list: Vec<String> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::PlotList(user.clone()))
+        .unwrap_or(Vec::new(env));
+    for plot_id in plot_list {
+        if let Some(mut plot_data) = get_plot_data(env, &plot_id) {
+            if has_free_capacity(env, &mut plot_data) {
+                return Some(plot_data);
+            }
+        }
+    }
+    None
+}
+
+fn find_bid_plot(env: &Env, user: &Address, cow_id: &String) -> Option<PlotData> {
+    // the user plot holding a place for a cow on auction.
+    let plot_list: Vec<String> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::PlotList(user.clone()))
+        .unwrap_or(Vec::new(env));
+    for plot_id in plot_list {
+        if let Some(plot_data) = get_plot_data(env, &plot_id) {
+            if plot_data.cow_ids.contains(cow_id) {
+                return Some(plot_data);
+            }
+        }
+    }
+    None
+}
+
+fn add_cow_to_plot(env: &Env, plot_data: &mut PlotData, cow_id: &String) {
+    plot_data.cow_ids.push_back(cow_id.clone());
+    set_plot_data(env, plot_data);
+}
+
+fn get_plot_feeding_tolerance(env: &Env, cow_data: &CowData, owner: &Address) -> u32 {
+    // plot upgrades only help cows on an active plot of their owner.
+    if cow_data.plot_id.is_empty() {
+        return 0;
+    }
+    let plot_data = match get_plot_data(env, &cow_data.plot_id) {
+        Some(plot_data) => plot_data,
+        None => return 0,
+    };
+    if plot_data.owner.ne(owner) || !is_plot_active(env, &plot_data) {
+        return 0;
+    }
+
+    let mut feeding_tolerance: u32 = 0;
+    if plot_data.barn {
+        feeding_tolerance += BARN_FEEDING_TOLERANCE;
+    }
+    if plot_data.pasture {
+        feeding_tolerance += PASTURE_FEEDING_TOLERANCE;
+    }
+    feeding_tolerance
+}
+
+fn get_farmer_profile(env: &Env, user: &Address) -> FarmerProfile {
+    // a farmer joins the farm the first time their profile is saved.
+    env.storage()
+        .persistent()
+        .get(&DataKey::Profile(user.clone()))
+        .unwrap_or(FarmerProfile::new(env))
+}
+
+fn set_farmer_profile(env: &Env, user: &Address, profile: &FarmerProfile) {
+    // save farmer profile & bump lifetime to 1 month.
+    let profile_key = DataKey::Profile(user.clone());
+    env.storage().persistent().set(&profile_key, profile);
+    env.storage().persistent().extend_ttl(
+        &profile_key,
+        LEDGER_AMOUNT_IN_1_MONTH,
+        LEDGER_AMOUNT_IN_1_MONTH,
+    );
+}
+
+fn get_treasury_balance(env: &Env, native_token_client: &token::Client) -> i128 {
+    // contract balance also holds the highest bids and feeding plan deposits, which belong to users.
+    native_token_client.balance(&env.current_contract_address())
+        - get_auction_escrow(env)
+        - get_plan_deposits(env)
+}
+
+fn get_auction_escrow(env: &Env) -> i128 {
+    // total of the highest bid of every auction, held by the contract for bidders.
+    env.storage()
+        .instance()
+        .get(&DataKey::AuctionEscrow)
+        .unwrap_or(0)
+}
+
+fn set_auction_escrow(env: &Env, amount: i128) {
+    env.storage()
+        .instance()
+        .set(&DataKey::AuctionEscrow, &amount);
+}
+
+fn get_plan_deposits(env: &Env) -> i128 {
+    // total balance of every feeding plan, held by the contract for plan owners.
+    env.storage()
+        .instance()
+        .get(&DataKey::PlanDeposits)
+        .unwrap_or(0)
+}
+
+fn set_plan_deposits(env: &Env, amount: i128) {
+    env.storage()
+        .instance()
+        .set(&DataKey::PlanDeposits, &amount);
+}
+
+fn get_leaderboard_season(env: &Env) -> u32 {
+    // the first season starts when the contract is deployed.
+    env.storage()
+        .instance()
+        .get(&DataKey::LeaderboardSeason)
+        .unwrap_or(1)
+}
+
+fn get_leaderboard_entries(env: &Env, kind: &LeaderboardKind) -> Vec<LeaderboardEntry> {
+    let entries: Vec<LeaderboardEntry> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::Leaderboard(*kind))
+        .unwrap_or(Vec::new(env));
+    if kind.ne(&LeaderboardKind::BestFedCow) {
+        return entries;
+    }
+
+    // a cow that died, was sold, or moved to another owner leaves the leaderboard.
+    let mut alive_entries: Vec<LeaderboardEntry> = Vec::new(env);
+    for entry in entries {
+        let owner: Option<Address> = env
+            .storage()
+            .temporary()
+            .get(&DataKey::CowOwner(entry.cow_id.clone()));
+        if owner.eq(&Some(entry.owner.clone())) {
+            alive_entries.push_back(entry);
+        }
+    }
+    alive_entries
+}
+
+fn update_leaderboard(env: &Env, kind: LeaderboardKind, new_entry: LeaderboardEntry) {
+    // cow leaderboard ranks cows, the others rank farmers.
+    let is_same_entry = |entry: &LeaderboardEntry| match kind {
+        LeaderboardKind::BestFedCow => entry.cow_id.eq(&new_entry.cow_id),
+        _ => entry.owner.eq(&new_entry.owner),
+    };
+
+    // drop the previous score, then insert the new one after every entry with the same or higher score.
+    let mut entries: Vec<LeaderboardEntry> = Vec::new(env);
+    let mut is_inserted = new_entry.score <= 0;
+    for entry in get_leaderboard_entries(env, &kind) {
+        if is_same_entry(&entry) {
+            continue;
+        }
+        if !is_inserted && entry.score < new_entry.score {
+            entries.push_back(new_entry.clone());
+            is_inserted = true;
+        }
+        entries.push_back(entry);
+    }
+    if !is_inserted {
+        entries.push_back(new_entry);
+    }
+
+    // keep the top entries only.
+    while entries.len() > LEADERBOARD_SIZE {
+        entries.pop_back();
+    }
+
+    // save leaderboard & bump lifetime to 1 month.
+    let leaderboard_key = DataKey::Leaderboard(kind);
+    env.storage().persistent().set(&leaderboard_key, &entries);
+    env.storage().persistent().extend_ttl(
+        &leaderboard_key,
+        LEDGER_AMOUNT_IN_1_MONTH,
+        LEDGER_AMOUNT_IN_1_MONTH,
+    );
+}
+
+fn update_herd_leaderboard(env: &Env, user: &Address) {
+    // herd value is the sum of the appraisal price of every living cow in the herd,
+    // the whole herd is only scanned when cows join or leave it.
+    let ownership_data: Vec<String> = env
+        .storage()
+        .persistent()
+        .get(user)
+        .unwrap_or(Vec::new(env));
+    let mut herd_value: i128 = 0;
+    for cow_id in ownership_data {
+        herd_value += get_herd_cow_value(env, &cow_id);
+    }
+    set_herd_value(env, user, herd_value);
+}
+
+fn add_herd_value(env: &Env, user: &Address, herd_value_delta: i128) {
+    // feeding only changes the value of the fed cows, add their difference to the saved herd value.
+    let herd_value: Option<i128> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::HerdValue(user.clone()));
+    match herd_value {
+        Some(herd_value) => set_herd_value(env, user, herd_value + herd_value_delta),
+        None => update_herd_leaderboard(env, user),
+    }
+}
+
+fn set_herd_value(env: &Env, user: &Address, herd_value: i128) {
+    // save herd value & bump lifetime to 1 month.
+    let herd_key = DataKey::HerdValue(user.clone());
+    env.storage().persistent().set(&herd_key, &herd_value);
+    env.storage().persistent().extend_ttl(
+        &herd_key,
+        LEDGER_AMOUNT_IN_1_MONTH,
+        LEDGER_AMOUNT_IN_1_MONTH,
+    );
+
+    let new_entry = LeaderboardEntry {
+        owner: user.clone(),
+        cow_id: String::from_str(env, ""),
+        score: herd_value,
+    };
+    update_leaderboard(env, LeaderboardKind::RichestHerd, new_entry);
+}
+
+fn get_herd_cow_value(env: &Env, cow_id: &String) -> i128 {
+    // dead cows, cows past their lifespan, and cows waiting to be migrated are worth nothing.
+    let cow_data = get_cow_data(env, cow_id);
+    let current_ledger: u32 = env.ledger().sequence();
+    match cow_data {
+        Some(cow_data) if !is_cow_past_lifespan(&cow_data, current_ledger) => {
+            let cow_base_price: i128 = get_cow_base_price_in_stroops(&cow_data.breed);
+            get_cow_appraisal_price(&cow_data, cow_base_price, current_ledger)
+        }
+        _ => 0,
+    }
+}
+
+fn update_auction_leaderboard(env: &Env, winner: &Address) {
+    // auctions won are counted per season, save the count & bump lifetime to 1 month.
+    let wins_key = DataKey::SeasonAuctionsWon(get_leaderboard_season(env), winner.clone());
+    let auctions_won: u32 = env.storage().persistent().get(&wins_key).unwrap_or(0) + 1;
+    env.storage().persistent().set(&wins_key, &auctions_won);
+    env.storage().persistent().extend_ttl(
+        &wins_key,
+        LEDGER_AMOUNT_IN_1_MONTH,
+        LEDGER_AMOUNT_IN_1_MONTH,
+    );
+
+    let new_entry = LeaderboardEntry {
+        owner: winner.clone(),
+        cow_id: String::from_str(env, ""),
+        score: auctions_won as i128,
+    };
+    update_leaderboard(env, LeaderboardKind::MostAuctionsWon, new_entry);
+}
+
+fn unlock_achievement(
+    env: &Env,
+    user: &Address,
+    profile: &mut FarmerProfile,
+    achievement: Achievement,
+) {
+    // every achievement is unlocked once.
+    if profile.achievements.contains(achievement) {
+        return;
+    }
+    profile.achievements.push_back(achievement);
+
+    // pay the reward when it is set and the treasury can afford it.
+    let reward: i128 = env
+        .storage()
+        .instance()
+        .get(&DataKey::AchievementReward(achievement))
+        .unwrap_or(0);
+    let mut reward_paid: i128 = 0;
+    if reward > 0 {
+        let native_token: Address = env.storage().instance().get(&DataKey::NativeToken).unwrap();
+        let native_token_client = token::Client::new(env, &native_token);
+        if get_treasury_balance(env, &native_token_client) >= reward {
+            native_token_client.transfer(&env.current_contract_address(), user, &reward);
+            reward_paid = reward;
+        }
+    }
+
+    // publish Cowchain Farm ACHIEVEMENT UNLOCKED event
+    let new_achievement_event = AchievementEventDetails {
+        owner: user.clone(),
+        achievement,
+        reward: reward_paid,
+    };
+    AchievementEvent::new(new_achievement_event).publish(env);
 }
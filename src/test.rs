@@ -1,22 +1,2836 @@
 #![cfg(test)]
+extern crate std;
 
 use super::*;
-use soroban_sdk::{arbitrary::std, Env};
+use soroban_sdk::testutils::storage::{Persistent as _, Temporary as _};
+use soroban_sdk::testutils::{Address as _, Events, Ledger};
+use soroban_sdk::{contract, contractimpl, symbol_short, token, vec, IntoVal};
+
+mod economy;
+mod snapshot;
+
+const INIT_MESSAGE: &str = "y3QKiJ5iq7y9JGAfN23vY8hwXa";
+const XLM: i128 = 10_000_000;
+
+struct Farm<'a> {
+    env: Env,
+    contract_id: Address,
+    admin: Address,
+    client: CowContractClient<'a>,
+    token: token::Client<'a>,
+    token_admin: token::StellarAssetClient<'a>,
+}
+
+fn setup<'a>() -> Farm<'a> {
+    setup_with_treasury(Env::default(), 1_000_000 * XLM)
+}
+
+fn setup_with_treasury<'a>(env: Env, treasury: i128) -> Farm<'a> {
+    setup_at_ledger(env, treasury, 100)
+}
+
+fn setup_at_ledger<'a>(env: Env, treasury: i128, start_ledger: u32) -> Farm<'a> {
+    env.mock_all_auths();
+    env.cost_estimate().budget().reset_unlimited();
+    env.ledger().with_mut(|ledger| {
+        ledger.sequence_number = start_ledger;
+        ledger.min_temp_entry_ttl = 16;
+        ledger.min_persistent_entry_ttl = 16;
+        ledger.max_entry_ttl = 3 * LEDGER_AMOUNT_IN_1_MONTH;
+    });
+
+    let contract_id = env.register(CowContract, ());
+    let client = CowContractClient::new(&env, &contract_id);
+
+    // native token is a registered Stellar Asset Contract.
+    let admin = Address::generate(&env);
+    let native_token = env.register_stellar_asset_contract_v2(admin.clone());
+    let token = token::Client::new(&env, &native_token.address());
+    let token_admin = token::StellarAssetClient::new(&env, &native_token.address());
+    if treasury > 0 {
+        token_admin.mint(&contract_id, &treasury);
+    }
+
+    let message = String::from_str(&env, INIT_MESSAGE);
+    client.init(&admin, &native_token.address(), &message);
+
+    Farm {
+        env,
+        contract_id,
+        admin,
+        client,
+        token,
+        token_admin,
+    }
+}
+
+// new user with 100k XLM and a large plot, already paid for.
+fn new_user(farm: &Farm) -> Address {
+    let user = Address::generate(&farm.env);
+    farm.token_admin
+        .mint(&user, &(100_000 * XLM + PLOT_PRICE[2] * XLM));
+    let result = farm.client.buy_plot(&user, &PlotTier::Large);
+    assert_eq!(result.status, Status::Ok);
+    user
+}
+
+// new user with 100k XLM and a rented small plot.
+fn new_tenant(farm: &Farm) -> Address {
+    let user = Address::generate(&farm.env);
+    farm.token_admin.mint(&user, &(100_000 * XLM));
+    let result = farm.client.rent_plot(&user, &PlotTier::Small);
+    assert_eq!(result.status, Status::Ok);
+    user
+}
+
+fn salt(env: &Env) -> BytesN<32> {
+    BytesN::from_array(env, &[7; 32])
+}
+
+fn commitment(env: &Env, salt: &BytesN<32>) -> BytesN<32> {
+    env.crypto().sha256(&salt.clone().into()).into()
+}
+
+fn order_cow(farm: &Farm, user: &Address, name: Symbol, breed: CowBreed) -> CowOrder {
+    let external_ref = String::from_str(&farm.env, "");
+    let commitment = commitment(&farm.env, &salt(&farm.env));
+    let result = farm
+        .client
+        .order_cow(user, &name, &external_ref, &breed, &commitment);
+    assert_eq!(result.status, Status::Ok);
+    result.order.get(0).unwrap()
+}
+
+// order a Jersey cow and hatch it as soon as possible.
+fn buy_cow(farm: &Farm, user: &Address, name: Symbol) -> CowData {
+    let order = order_cow(farm, user, name, CowBreed::Jersey);
+    jump(&farm.env, HATCH_DELAY);
+    let result = farm.client.hatch_cow(user, &order.cow_id, &salt(&farm.env));
+    assert_eq!(result.status, Status::Ok);
+    result.cow_data.get(0).unwrap()
+}
+
+// replace the genome drawn when the cow was hatched.
+fn set_genome(farm: &Farm, cow_id: &String, genes: [u8; 16]) {
+    let env = &farm.env;
+    env.as_contract(&farm.contract_id, || {
+        let mut cow_data: CowData = env.storage().temporary().get(cow_id).unwrap();
+        cow_data.genome = BytesN::from_array(env, &genes);
+        env.storage().temporary().set(cow_id, &cow_data);
+    });
+}
+
+// replace the gender drawn when the cow was hatched.
+fn set_gender(farm: &Farm, cow_id: &String, gender: CowGender) {
+    let env = &farm.env;
+    env.as_contract(&farm.contract_id, || {
+        let mut cow_data: CowData = env.storage().temporary().get(cow_id).unwrap();
+        cow_data.gender = gender;
+        env.storage().temporary().set(cow_id, &cow_data);
+    });
+}
+
+// move the birth of the cow back in time.
+fn set_born_ledger(farm: &Farm, cow_id: &String, born_ledger: u32) {
+    let env = &farm.env;
+    env.as_contract(&farm.contract_id, || {
+        let mut cow_data: CowData = env.storage().temporary().get(cow_id).unwrap();
+        cow_data.born_ledger = born_ledger;
+        env.storage().temporary().set(cow_id, &cow_data);
+    });
+}
+
+fn jump(env: &Env, ledger_amount: u32) {
+    env.ledger()
+        .with_mut(|ledger| ledger.sequence_number += ledger_amount);
+}
+
+// feed the cow on time until it has grown out of calf stage.
+fn raise_cow(farm: &Farm, user: &Address, cow_id: &String) {
+    let mut age: u32 = 0;
+    while age <= LEDGER_AMOUNT_IN_3_DAYS {
+        jump(&farm.env, WELL_FED + 180);
+        age += WELL_FED + 180;
+        assert_eq!(farm.client.feed_the_cow(user, cow_id).status, Status::Ok);
+    }
+}
+
+fn register_auction(farm: &Farm, user: &Address, cow_id: &String, price: u32) -> AuctionData {
+    let external_ref = String::from_str(&farm.env, "");
+    let result = farm
+        .client
+        .register_auction(user, cow_id, &external_ref, &price);
+    assert_eq!(result.status, Status::Ok);
+    result.auction_data.get(0).unwrap()
+}
+
+// save a cow the way the first contract version did, before any cow data field was added.
+fn legacy_cow(farm: &Farm, user: &Address, cow_id: &str, name: Symbol) -> String {
+    let env = &farm.env;
+    let cow_id = String::from_str(env, cow_id);
+    let legacy_cow_data = LegacyCowData {
+        id: cow_id.clone(),
+        name: name.clone(),
+        breed: CowBreed::Jersey,
+        gender: CowGender::Female,
+        born_ledger: env.ledger().sequence(),
+        last_fed_ledger: env.ledger().sequence(),
+        feeding_stats: LegacyCowFeedingStats {
+            on_time: 3,
+            late: 1,
+            forget: 0,
+        },
+        auction_id: String::from_str(env, ""),
+    };
+    env.as_contract(&farm.contract_id, || {
+        env.storage().temporary().set(&cow_id, &legacy_cow_data);
+        env.storage().temporary().extend_ttl(
+            &cow_id,
+            LEDGER_AMOUNT_IN_24_HOURS,
+            LEDGER_AMOUNT_IN_24_HOURS,
+        );
+        env.storage().temporary().set(&name, &cow_id);
+        let mut ownership: Vec<String> = env
+            .storage()
+            .persistent()
+            .get(user)
+            .unwrap_or(Vec::new(env));
+        ownership.push_back(cow_id.clone());
+        env.storage().persistent().set(user, &ownership);
+    });
+    cow_id
+}
+
+// feeding plan deposits tracked apart from the treasury.
+fn plan_deposits(farm: &Farm) -> i128 {
+    farm.env.as_contract(&farm.contract_id, || {
+        farm.env
+            .storage()
+            .instance()
+            .get(&DataKey::PlanDeposits)
+            .unwrap_or(0)
+    })
+}
+
+fn auction_escrow(farm: &Farm) -> i128 {
+    farm.env.as_contract(&farm.contract_id, || {
+        farm.env
+            .storage()
+            .instance()
+            .get(&DataKey::AuctionEscrow)
+            .unwrap_or(0)
+    })
+}
+
+// burn the treasury, only the money held for users stays in the contract.
+fn empty_treasury(farm: &Farm) {
+    let held = farm.env.as_contract(&farm.contract_id, || {
+        let storage = farm.env.storage().instance();
+        storage.get(&DataKey::AuctionEscrow).unwrap_or(0i128)
+            + storage.get(&DataKey::PlanDeposits).unwrap_or(0i128)
+    });
+    let treasury = farm.token.balance(&farm.contract_id) - held;
+    farm.token.burn(&farm.contract_id, &treasury);
+}
 
 #[test]
-fn test() {
+fn buy_cow_pays_supplier_and_saves_cow() {
+    let farm = setup();
+    let user = new_user(&farm);
+
+    let cow_data = buy_cow(&farm, &user, symbol_short!("bessie"));
+    assert_eq!(cow_data.id, String::from_str(&farm.env, "COW1"));
+    assert_eq!(
+        farm.token.balance(&user),
+        100_000 * XLM - JERSEY_PRICE * XLM
+    );
+    assert_eq!(
+        farm.client.get_owner_of(&cow_data.id).owner,
+        vec![&farm.env, user]
+    );
+}
+
+#[test]
+fn buy_cow_event_has_versioned_topics() {
+    let farm = setup();
+    let user = new_user(&farm);
+
+    let cow_data = buy_cow(&farm, &user, symbol_short!("bessie"));
+    let details = CowEventDetails {
+        id: cow_data.id.clone(),
+        name: cow_data.name,
+        owner: user.clone(),
+        last_fed_ledger: cow_data.last_fed_ledger,
+        condition: cow_data.condition,
+    };
+    // the first cow also unlocks an achievement.
+    let achievement_details = AchievementEventDetails {
+        owner: user.clone(),
+        achievement: Achievement::FirstCow,
+        reward: 0,
+    };
+    let achievement_topic = Symbol::new(&farm.env, "achievement_unlocked");
+    assert_eq!(
+        farm.env
+            .events()
+            .all()
+            .filter_by_contract(&farm.contract_id),
+        vec![
+            &farm.env,
+            (
+                farm.contract_id.clone(),
+                (
+                    symbol_short!("buy"),
+                    EVENT_VERSION,
+                    user.clone(),
+                    cow_data.id
+                )
+                    .into_val(&farm.env),
+                details.into_val(&farm.env),
+            ),
+            (
+                farm.contract_id.clone(),
+                (achievement_topic, EVENT_VERSION, user).into_val(&farm.env),
+                achievement_details.into_val(&farm.env),
+            ),
+        ]
+    );
+}
+
+#[test]
+fn feeding_extends_cow_lifetime() {
+    let farm = setup();
+    let user = new_user(&farm);
+    let cow_data = buy_cow(&farm, &user, symbol_short!("bessie"));
+
+    // still full, no lifetime extension.
+    jump(&farm.env, WELL_FED);
+    assert_eq!(
+        farm.client.feed_the_cow(&user, &cow_data.id).status,
+        Status::FullStomach
+    );
+
+    jump(&farm.env, 1);
+    assert_eq!(
+        farm.client.feed_the_cow(&user, &cow_data.id).status,
+        Status::Ok
+    );
+    farm.env.as_contract(&farm.contract_id, || {
+        let storage = farm.env.storage();
+        assert_eq!(
+            storage.temporary().get_ttl(&cow_data.id),
+            LEDGER_AMOUNT_IN_24_HOURS
+        );
+        // ownership was bumped at hatch time, and is bumped at most once a day.
+        assert_eq!(
+            storage.persistent().get_ttl(&user),
+            LEDGER_AMOUNT_IN_1_WEEK - (WELL_FED + 1)
+        );
+    });
+}
+
+#[test]
+fn hungry_cow_dies_when_its_lifetime_ends() {
+    let farm = setup();
+    let user = new_user(&farm);
+    let cow_data = buy_cow(&farm, &user, symbol_short!("bessie"));
+
+    jump(&farm.env, LEDGER_AMOUNT_IN_24_HOURS + 1);
+    assert_eq!(farm.client.get_cow(&cow_data.id).status, Status::NotFound);
+    assert_eq!(
+        farm.client.feed_the_cow(&user, &cow_data.id).status,
+        Status::NotFound
+    );
+}
+
+#[test]
+fn transfer_moves_cow_between_farmers() {
+    let farm = setup();
+    let user = new_user(&farm);
+    let recipient = new_user(&farm);
+    let cow_data = buy_cow(&farm, &user, symbol_short!("bessie"));
+
+    assert_eq!(
+        farm.client
+            .transfer_cow(&user, &recipient, &cow_data.id)
+            .status,
+        Status::Ok
+    );
+    assert_eq!(farm.client.owner_of(&cow_data.id), Some(recipient.clone()));
+    assert_eq!(farm.client.balance_of(&user), 0);
+    assert_eq!(farm.client.balance_of(&recipient), 1);
+
+    // the cow moves to the recipient's plot.
+    let plot_data = farm.client.get_plots(&recipient).data.get(0).unwrap();
+    let moved_cow = farm.client.get_cow(&cow_data.id).data.get(0).unwrap();
+    assert_eq!(moved_cow.plot_id, plot_data.id);
+    assert_eq!(plot_data.cow_ids, vec![&farm.env, cow_data.id]);
+}
+
+#[test]
+fn transfer_into_full_farm_is_rejected() {
+    let farm = setup();
+    let user = new_user(&farm);
+    let recipient = new_tenant(&farm);
+    let farmless = Address::generate(&farm.env);
+    let cow_data = buy_cow(&farm, &user, symbol_short!("bessie"));
+    for index in 0..PLOT_CAPACITY[0] {
+        let name = Symbol::new(&farm.env, &std::format!("cow{}", index));
+        order_cow(&farm, &recipient, name, CowBreed::Jersey);
+    }
+
+    // neither a full farm nor an address without plots has a place for the cow.
+    for to in [&recipient, &farmless] {
+        assert_eq!(
+            farm.client.transfer_cow(&user, to, &cow_data.id).status,
+            Status::NoCapacity
+        );
+        assert_eq!(
+            farm.client.offer_gift(&user, to, &cow_data.id).status,
+            Status::NoCapacity
+        );
+        assert_eq!(
+            farm.client
+                .transfer_from(&user, &user, to, &cow_data.id)
+                .status,
+            Status::NoCapacity
+        );
+    }
+    assert_eq!(farm.client.owner_of(&cow_data.id), Some(user));
+}
+
+#[test]
+fn gift_moves_cow_once_accepted() {
+    let farm = setup();
+    let user = new_user(&farm);
+    let friend = new_user(&farm);
+    let stranger = new_user(&farm);
+    let cow_data = buy_cow(&farm, &user, symbol_short!("bessie"));
+
+    // only the owner can offer the cow, and not to themselves.
+    assert_eq!(
+        farm.client.offer_gift(&user, &user, &cow_data.id).status,
+        Status::Fail
+    );
+    assert_eq!(
+        farm.client
+            .offer_gift(&stranger, &friend, &cow_data.id)
+            .status,
+        Status::MissingOwnership
+    );
+
+    // the cow stays with the owner until the recipient accepts it.
+    assert_eq!(
+        farm.client.offer_gift(&user, &friend, &cow_data.id).status,
+        Status::Saved
+    );
+    assert_eq!(farm.client.owner_of(&cow_data.id), Some(user.clone()));
+    assert_eq!(
+        farm.client.accept_gift(&stranger, &cow_data.id).status,
+        Status::Unauthorized
+    );
+    assert_eq!(
+        farm.client.accept_gift(&friend, &cow_data.id).status,
+        Status::Ok
+    );
+    assert_eq!(farm.client.owner_of(&cow_data.id), Some(friend.clone()));
+    assert_eq!(farm.client.balance_of(&user), 0);
+    assert_eq!(farm.client.balance_of(&friend), 1);
+
+    // the gift is fulfilled once, even when the cow comes back to the sender.
+    farm.client.transfer_cow(&friend, &user, &cow_data.id);
+    assert_eq!(
+        farm.client.accept_gift(&friend, &cow_data.id).status,
+        Status::NotFound
+    );
+    assert_eq!(farm.client.owner_of(&cow_data.id), Some(user));
+}
+
+#[test]
+fn declined_gift_is_cancelled() {
+    let farm = setup();
+    let user = new_user(&farm);
+    let friend = new_user(&farm);
+    let stranger = new_user(&farm);
+    let cow_data = buy_cow(&farm, &user, symbol_short!("bessie"));
+
+    // only the recipient can decline the gift.
+    farm.client.offer_gift(&user, &friend, &cow_data.id);
+    assert_eq!(
+        farm.client.decline_gift(&stranger, &cow_data.id).status,
+        Status::Unauthorized
+    );
+    assert_eq!(
+        farm.client.decline_gift(&friend, &cow_data.id).status,
+        Status::Ok
+    );
+    assert_eq!(
+        farm.client.accept_gift(&friend, &cow_data.id).status,
+        Status::NotFound
+    );
+    assert_eq!(farm.client.owner_of(&cow_data.id), Some(user.clone()));
+
+    // a gift offer is cancelled when the cow goes on auction before it is accepted.
+    raise_cow(&farm, &user, &cow_data.id);
+    farm.client.offer_gift(&user, &friend, &cow_data.id);
+    register_auction(&farm, &user, &cow_data.id, 500);
+    assert_eq!(
+        farm.client.accept_gift(&friend, &cow_data.id).status,
+        Status::OnAuction
+    );
+    assert_eq!(
+        farm.client.accept_gift(&friend, &cow_data.id).status,
+        Status::NotFound
+    );
+    assert_eq!(farm.client.owner_of(&cow_data.id), Some(user));
+}
+
+#[test]
+fn approved_address_transfers_cow_until_expiry() {
+    let farm = setup();
+    let user = new_user(&farm);
+    let spender = new_user(&farm);
+    let friend = new_user(&farm);
+    let bessie = buy_cow(&farm, &user, symbol_short!("bessie"));
+    let daisy = buy_cow(&farm, &user, symbol_short!("daisy"));
+    let expiry_ledger = farm.env.ledger().sequence() + 10;
+
+    // approval is checked before it is saved.
+    let current_ledger = farm.env.ledger().sequence();
+    assert_eq!(
+        farm.client
+            .approve(&user, &spender, &bessie.id, &current_ledger),
+        Status::Expired
+    );
+    assert_eq!(
+        farm.client
+            .approve(&friend, &spender, &bessie.id, &expiry_ledger),
+        Status::MissingOwnership
+    );
+
+    // only the approved address can transfer the cow on behalf of its owner.
+    assert_eq!(
+        farm.client
+            .approve(&user, &spender, &bessie.id, &expiry_ledger),
+        Status::Saved
+    );
+    assert_eq!(farm.client.get_approved(&bessie.id), Some(spender.clone()));
+    assert_eq!(
+        farm.client
+            .transfer_from(&friend, &user, &friend, &bessie.id)
+            .status,
+        Status::Unauthorized
+    );
+    assert_eq!(
+        farm.client
+            .transfer_from(&spender, &user, &friend, &bessie.id)
+            .status,
+        Status::Ok
+    );
+    assert_eq!(farm.client.owner_of(&bessie.id), Some(friend.clone()));
+
+    // the approval does not come back with the cow.
+    assert_eq!(farm.client.get_approved(&bessie.id), None);
+    farm.client
+        .transfer_from(&friend, &friend, &user, &bessie.id);
+    assert_eq!(farm.client.get_approved(&bessie.id), None);
+
+    // approval ends at its expiry ledger.
+    farm.client
+        .approve(&user, &spender, &daisy.id, &expiry_ledger);
+    jump(&farm.env, 10);
+    assert_eq!(farm.client.get_approved(&daisy.id), None);
+    assert_eq!(
+        farm.client
+            .transfer_from(&spender, &user, &friend, &daisy.id)
+            .status,
+        Status::Unauthorized
+    );
+}
+
+#[test]
+fn operator_transfers_every_cow_until_revoked() {
+    let farm = setup();
+    let user = new_user(&farm);
+    let operator = new_user(&farm);
+    let friend = new_user(&farm);
+    let bessie = buy_cow(&farm, &user, symbol_short!("bessie"));
+    let daisy = buy_cow(&farm, &user, symbol_short!("daisy"));
+
+    assert_eq!(
+        farm.client.set_approval_for_all(&user, &user, &true),
+        Status::Fail
+    );
+    farm.client.set_approval_for_all(&user, &operator, &true);
+    assert!(farm.client.is_approved_for_all(&user, &operator));
+    assert_eq!(
+        farm.client
+            .transfer_from(&operator, &user, &friend, &bessie.id)
+            .status,
+        Status::Ok
+    );
+
+    // the operator cannot act for the new owner.
+    assert_eq!(
+        farm.client
+            .transfer_from(&operator, &friend, &user, &bessie.id)
+            .status,
+        Status::Unauthorized
+    );
+
+    farm.client.set_approval_for_all(&user, &operator, &false);
+    assert!(!farm.client.is_approved_for_all(&user, &operator));
+    assert_eq!(
+        farm.client
+            .transfer_from(&operator, &user, &friend, &daisy.id)
+            .status,
+        Status::Unauthorized
+    );
+    assert_eq!(farm.client.owner_of(&daisy.id), Some(user));
+}
+
+#[test]
+fn rename_cow_charges_fee_and_waits_for_cooldown() {
+    let farm = setup();
+    let user = new_user(&farm);
+    let stranger = new_user(&farm);
+    let bessie = buy_cow(&farm, &user, symbol_short!("bessie"));
+    buy_cow(&farm, &user, symbol_short!("daisy"));
+
+    assert_eq!(
+        farm.client
+            .rename_cow(&stranger, &bessie.id, &symbol_short!("lulu"))
+            .status,
+        Status::MissingOwnership
+    );
+    assert_eq!(
+        farm.client
+            .rename_cow(&user, &bessie.id, &symbol_short!("bessie"))
+            .status,
+        Status::Duplicate
+    );
+    assert_eq!(
+        farm.client
+            .rename_cow(&user, &bessie.id, &symbol_short!("daisy"))
+            .status,
+        Status::NameAlreadyExist
+    );
+
+    // the new name is taken and the old one is released.
+    let user_balance = farm.token.balance(&user);
+    let result = farm
+        .client
+        .rename_cow(&user, &bessie.id, &symbol_short!("lulu"));
+    assert_eq!(result.status, Status::Ok);
+    assert_eq!(farm.token.balance(&user), user_balance - RENAME_FEE);
+    let named_cow = farm.client.get_cow_by_name(&symbol_short!("lulu"));
+    assert_eq!(named_cow.data.get(0).unwrap().id, bessie.id);
+    assert_eq!(
+        farm.client.get_cow_by_name(&symbol_short!("bessie")).status,
+        Status::NotFound
+    );
+    order_cow(&farm, &stranger, symbol_short!("bessie"), CowBreed::Jersey);
+
+    // a cow is renamed once in every cooldown period.
+    assert_eq!(
+        farm.client
+            .rename_cow(&user, &bessie.id, &symbol_short!("rosie"))
+            .status,
+        Status::TryAgain
+    );
+    let renamed_ledger = farm.env.ledger().sequence();
+    while farm.env.ledger().sequence() < renamed_ledger + RENAME_COOLDOWN {
+        jump(&farm.env, WELL_FED + 180);
+        farm.client.feed_the_cow(&user, &bessie.id);
+    }
+    assert_eq!(
+        farm.client
+            .rename_cow(&user, &bessie.id, &symbol_short!("rosie"))
+            .status,
+        Status::Ok
+    );
+}
+
+#[test]
+fn moderator_restricts_cow_names() {
+    let farm = setup();
+    let user = new_user(&farm);
+    let moderator = new_user(&farm);
+    let external_ref = String::from_str(&farm.env, "");
+    let commitment = commitment(&farm.env, &salt(&farm.env));
+    let order_status = |user: &Address, name: Symbol| -> Status {
+        farm.client
+            .order_cow(user, &name, &external_ref, &CowBreed::Jersey, &commitment)
+            .status
+    };
+
+    // names are restricted by the Moderator set by Admin only.
+    assert_eq!(
+        farm.client.set_name_restriction(
+            &moderator,
+            &symbol_short!("moo"),
+            &NameRestriction::Banned
+        ),
+        Status::NotFound
+    );
+    assert_eq!(farm.client.set_moderator(&moderator), Status::Saved);
+    assert_eq!(
+        farm.client
+            .set_name_restriction(&user, &symbol_short!("moo"), &NameRestriction::Banned),
+        Status::Unauthorized
+    );
+
+    // banned names cannot be used by anyone, reserved names only by the Moderator.
+    farm.client
+        .set_name_restriction(&moderator, &symbol_short!("moo"), &NameRestriction::Banned);
+    farm.client.set_name_restriction(
+        &moderator,
+        &symbol_short!("king"),
+        &NameRestriction::Reserved,
+    );
+    assert_eq!(
+        farm.client.get_name_restriction(&symbol_short!("king")),
+        NameRestriction::Reserved
+    );
+    assert_eq!(
+        order_status(&user, symbol_short!("moo")),
+        Status::NameRestricted
+    );
+    assert_eq!(
+        order_status(&moderator, symbol_short!("moo")),
+        Status::NameRestricted
+    );
+    assert_eq!(
+        order_status(&user, symbol_short!("king")),
+        Status::NameRestricted
+    );
+    assert_eq!(order_status(&moderator, symbol_short!("king")), Status::Ok);
+    let cow_data = buy_cow(&farm, &user, symbol_short!("bessie"));
+    assert_eq!(
+        farm.client
+            .rename_cow(&user, &cow_data.id, &symbol_short!("moo"))
+            .status,
+        Status::NameRestricted
+    );
+
+    // a freed name can be used again.
+    farm.client
+        .set_name_restriction(&moderator, &symbol_short!("moo"), &NameRestriction::Free);
+    assert_eq!(
+        farm.client.get_name_restriction(&symbol_short!("moo")),
+        NameRestriction::Free
+    );
+    assert_eq!(order_status(&user, symbol_short!("moo")), Status::Ok);
+}
+
+#[test]
+fn cow_metadata_is_set_by_owner() {
+    let farm = setup();
+    let user = new_user(&farm);
+    let stranger = new_user(&farm);
+    let cow_data = buy_cow(&farm, &user, symbol_short!("bessie"));
+    let metadata = CowMetadata {
+        description: String::from_str(&farm.env, "loves the morning grass"),
+        color: String::from_str(&farm.env, "brown"),
+        pattern: String::from_str(&farm.env, "spotted"),
+    };
+    assert_eq!(
+        farm.client.get_cow_metadata(&cow_data.id).status,
+        Status::NotFound
+    );
+    assert_eq!(
+        farm.client
+            .set_cow_metadata(&stranger, &cow_data.id, &metadata),
+        Status::MissingOwnership
+    );
+
+    // every field is limited in length.
+    let too_long = CowMetadata {
+        color: String::from_str(&farm.env, &"b".repeat(MAX_COW_TRAIT_LENGTH as usize + 1)),
+        ..metadata.clone()
+    };
+    assert_eq!(
+        farm.client.set_cow_metadata(&user, &cow_data.id, &too_long),
+        Status::Fail
+    );
+
+    assert_eq!(
+        farm.client.set_cow_metadata(&user, &cow_data.id, &metadata),
+        Status::Saved
+    );
+    let result = farm.client.get_cow_metadata(&cow_data.id);
+    assert_eq!(result.status, Status::Ok);
+    assert_eq!(result.data.get(0).unwrap(), metadata);
+}
+
+#[test]
+fn token_uri_joins_base_uri_and_cow_id() {
+    let farm = setup();
+    let user = new_user(&farm);
+    let cow_data = buy_cow(&farm, &user, symbol_short!("bessie"));
+
+    let base_uri = String::from_str(&farm.env, "https://cowchain.farm/cow/");
+    assert_eq!(farm.client.set_base_uri(&base_uri), Status::Saved);
+    assert_eq!(
+        farm.client.token_uri(&cow_data.id),
+        String::from_str(&farm.env, "https://cowchain.farm/cow/COW1")
+    );
+}
+
+#[test]
+fn donation_publishes_event() {
+    let farm = setup();
+    let user = new_user(&farm);
+
+    assert_eq!(farm.client.open_donation(&user, &25), Status::Ok);
+    let details = DonationEventDetails {
+        from: user.clone(),
+        amount: 25 * XLM,
+    };
+    assert_eq!(
+        farm.env
+            .events()
+            .all()
+            .filter_by_contract(&farm.contract_id),
+        vec![
+            &farm.env,
+            (
+                farm.contract_id.clone(),
+                (symbol_short!("donation"), EVENT_VERSION, user).into_val(&farm.env),
+                details.into_val(&farm.env),
+            ),
+        ]
+    );
+}
+
+#[test]
+fn init_requires_password() {
     let env = Env::default();
-    let contract_id = env.register_contract(None, CowContract);
-    let _client = CowContractClient::new(&env, &contract_id);
+    env.mock_all_auths();
+    let contract_id = env.register(CowContract, ());
+    let client = CowContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let native_token = env.register_stellar_asset_contract_v2(admin.clone());
+
+    let message = String::from_str(&env, "let me in");
+    assert_eq!(
+        client.init(&admin, &native_token.address(), &message),
+        Status::TryAgain
+    );
+    assert_eq!(client.bump_instance(&100), Status::NotInitialized);
+}
+
+#[test]
+fn init_only_once() {
+    let farm = setup();
+    let admin = Address::generate(&farm.env);
+    let message = String::from_str(&farm.env, INIT_MESSAGE);
+
+    assert_eq!(
+        farm.client.init(&admin, &farm.token.address, &message),
+        Status::AlreadyInitialized
+    );
+}
+
+#[test]
+fn order_cow_keeps_minimum_balance() {
+    let farm = setup();
+    let user = Address::generate(&farm.env);
+    let external_ref = String::from_str(&farm.env, "");
+    let commitment = commitment(&farm.env, &salt(&farm.env));
+
+    // the cow needs a place on a plot first.
+    farm.token_admin.mint(
+        &user,
+        &(PLOT_PRICE[0] * XLM + JERSEY_PRICE * XLM + MINIMUM_USER_BALANCE),
+    );
+    let order = || {
+        farm.client
+            .order_cow(
+                &user,
+                &symbol_short!("bessie"),
+                &external_ref,
+                &CowBreed::Jersey,
+                &commitment,
+            )
+            .status
+    };
+    assert_eq!(order(), Status::NoCapacity);
+    farm.client.buy_plot(&user, &PlotTier::Small);
+
+    // price plus minimum balance is not enough, the user must have more.
+    assert_eq!(order(), Status::InsufficientFund);
+    assert_eq!(farm.client.balance_of(&user), 0);
+
+    farm.token_admin.mint(&user, &1);
+    assert_eq!(order(), Status::Ok);
+    assert_eq!(farm.token.balance(&user), MINIMUM_USER_BALANCE + 1);
+}
+
+#[test]
+fn order_cow_rejects_taken_name() {
+    let farm = setup();
+    let user = new_user(&farm);
+    let other_user = new_user(&farm);
+    buy_cow(&farm, &user, symbol_short!("bessie"));
+    order_cow(&farm, &user, symbol_short!("daisy"), CowBreed::Jersey);
+
+    // names of hatched cows and of orders waiting to be hatched are both taken.
+    let external_ref = String::from_str(&farm.env, "");
+    let commitment = commitment(&farm.env, &salt(&farm.env));
+    for name in [symbol_short!("bessie"), symbol_short!("daisy")] {
+        let result = farm.client.order_cow(
+            &other_user,
+            &name,
+            &external_ref,
+            &CowBreed::Jersey,
+            &commitment,
+        );
+        assert_eq!(result.status, Status::NameAlreadyExist);
+    }
+    assert_eq!(farm.token.balance(&other_user), 100_000 * XLM);
+}
+
+#[test]
+fn hatch_cow_checks_owner_delay_and_salt() {
+    let farm = setup();
+    let user = new_user(&farm);
+    let stranger = new_user(&farm);
+    let order = order_cow(&farm, &user, symbol_short!("bessie"), CowBreed::Jersey);
+    assert_eq!(
+        farm.token.balance(&user),
+        100_000 * XLM - JERSEY_PRICE * XLM
+    );
+    assert_eq!(farm.client.get_cow(&order.cow_id).status, Status::NotFound);
+
+    let good_salt = salt(&farm.env);
+    let wrong_salt = BytesN::from_array(&farm.env, &[8; 32]);
+    let hatch =
+        |user: &Address, salt: &BytesN<32>| farm.client.hatch_cow(user, &order.cow_id, salt).status;
+    assert_eq!(hatch(&user, &good_salt), Status::NotReady);
+
+    jump(&farm.env, HATCH_DELAY);
+    assert_eq!(hatch(&stranger, &good_salt), Status::Unauthorized);
+    assert_eq!(hatch(&user, &wrong_salt), Status::Fail);
+    assert_eq!(hatch(&user, &good_salt), Status::Ok);
+
+    // the order is fulfilled only once.
+    assert_eq!(hatch(&user, &good_salt), Status::NotFound);
+    assert_eq!(
+        farm.client.get_cow_order(&order.cow_id).status,
+        Status::NotFound
+    );
+    assert_eq!(farm.client.get_cow(&order.cow_id).status, Status::Ok);
+    assert_eq!(farm.client.owner_of(&order.cow_id), Some(user));
+}
+
+#[test]
+fn order_reveals_nothing_about_the_cow() {
+    // the order only holds what the user gave, plus the IDs and ledgers assigned by the contract.
+    let hatch_with = |salt: [u8; 32]| {
+        let farm = setup();
+        let user = new_user(&farm);
+        let salt = BytesN::from_array(&farm.env, &salt);
+        let external_ref = String::from_str(&farm.env, "");
+        let commitment = commitment(&farm.env, &salt);
+        let result = farm.client.order_cow(
+            &user,
+            &symbol_short!("bessie"),
+            &external_ref,
+            &CowBreed::Jersey,
+            &commitment,
+        );
+        assert_eq!(result.status, Status::Ok);
+        let order = result.order.get(0).unwrap();
+        assert_eq!(
+            order,
+            CowOrder {
+                cow_id: String::from_str(&farm.env, "COW1"),
+                external_ref,
+                owner: user.clone(),
+                name: symbol_short!("bessie"),
+                breed: CowBreed::Jersey,
+                plot_id: String::from_str(&farm.env, "PLT1"),
+                commitment,
+                order_ledger: 100,
+                hatch_ledger: 100 + HATCH_DELAY,
+            }
+        );
+
+        jump(&farm.env, HATCH_DELAY);
+        let result = farm.client.hatch_cow(&user, &order.cow_id, &salt);
+        assert_eq!(result.status, Status::Ok);
+        result.cow_data.get(0).unwrap().genome.to_array()
+    };
+
+    // the cow is drawn from the revealed salt together with the hatch entropy.
+    assert_ne!(hatch_with([7; 32]), hatch_with([8; 32]));
+}
+
+// a contract account that keeps a hatched cow only when it has the gender it wants.
+#[contract]
+struct PickyHatcher;
 
-    env.as_contract(&contract_id, || {
-        let mut gender = CowGender::Male;
+#[contractimpl]
+impl PickyHatcher {
+    pub fn hatch(env: Env, farm: Address, cow_id: String, salt: BytesN<32>, gender: CowGender) {
+        let client = CowContractClient::new(&env, &farm);
+        let result = client.hatch_cow(&env.current_contract_address(), &cow_id, &salt);
+        if result.cow_data.get(0).unwrap().gender.ne(&gender) {
+            panic!("unwanted gender");
+        }
+    }
+}
+
+#[test]
+fn hatch_retry_gets_the_same_cow() {
+    let order_farm = || {
+        let farm = setup();
+        let hatcher = farm.env.register(PickyHatcher, ());
+        farm.token_admin.mint(&hatcher, &(100_000 * XLM));
+        farm.client.buy_plot(&hatcher, &PlotTier::Large);
+        let order = order_cow(&farm, &hatcher, symbol_short!("bessie"), CowBreed::Jersey);
+        jump(&farm.env, HATCH_DELAY);
+        (farm, hatcher, order)
+    };
+
+    // the cow drawn for the order when nobody rolls the hatch back.
+    let (farm, hatcher, order) = order_farm();
+    let result = farm
+        .client
+        .hatch_cow(&hatcher, &order.cow_id, &salt(&farm.env));
+    let cow_data = result.cow_data.get(0).unwrap();
+    let (gender, genome) = (cow_data.gender, cow_data.genome.to_array());
+    let unwanted_gender = match gender {
+        CowGender::Male => CowGender::Female,
+        CowGender::Female => CowGender::Male,
+    };
+
+    // rolling the hatch back and retrying on later ledgers never draws another cow.
+    let (farm, hatcher, order) = order_farm();
+    let picky_hatcher = PickyHatcherClient::new(&farm.env, &hatcher);
+    let salt = salt(&farm.env);
+    for _ in 0..8 {
+        let result =
+            picky_hatcher.try_hatch(&farm.contract_id, &order.cow_id, &salt, &unwanted_gender);
+        assert!(result.is_err());
+        jump(&farm.env, 1);
+    }
+    picky_hatcher.hatch(&farm.contract_id, &order.cow_id, &salt, &gender);
+    let cow_data = farm.client.get_cow(&order.cow_id).data.get(0).unwrap();
+    assert_eq!(cow_data.genome.to_array(), genome);
+}
+
+#[test]
+fn unhatched_order_is_forfeited() {
+    let farm = setup();
+    let user = new_user(&farm);
+    let order = order_cow(&farm, &user, symbol_short!("bessie"), CowBreed::Jersey);
+    assert_eq!(
+        farm.client.get_cow_order(&order.cow_id).order.get(0),
+        Some(order.clone())
+    );
+
+    // after the hatching window the payment stays with supplier and the name is free again.
+    jump(&farm.env, HATCH_WINDOW + 1);
+    let result = farm
+        .client
+        .hatch_cow(&user, &order.cow_id, &salt(&farm.env));
+    assert_eq!(result.status, Status::NotFound);
+    assert_eq!(farm.client.balance_of(&user), 0);
+    buy_cow(&farm, &user, symbol_short!("bessie"));
+}
+
+#[test]
+fn feeding_windows_update_stats_and_condition() {
+    let farm = setup();
+    let user = new_user(&farm);
+    let cow_data = buy_cow(&farm, &user, symbol_short!("bessie"));
+    set_genome(&farm, &cow_data.id, [0; 16]);
 
-        let value = env.prng().u64_in_range(1..=6);
-        if value % 2 == 0 {
-            gender = CowGender::Female;
+    // on time feeding.
+    jump(&farm.env, ON_TIME_FEED);
+    assert_eq!(
+        farm.client.feed_the_cow(&user, &cow_data.id).status,
+        Status::Ok
+    );
+    // late feeding.
+    jump(&farm.env, LATE_FEED);
+    assert_eq!(
+        farm.client.feed_the_cow(&user, &cow_data.id).status,
+        Status::Ok
+    );
+    // forgotten feeding, right before the cow dies.
+    jump(&farm.env, LEDGER_AMOUNT_IN_24_HOURS - 1);
+    assert_eq!(
+        farm.client.feed_the_cow(&user, &cow_data.id).status,
+        Status::Ok
+    );
+
+    let cow_data = farm.client.get_cow(&cow_data.id).data.get(0).unwrap();
+    assert_eq!(
+        cow_data.feeding_stats,
+        CowFeedingStats {
+            on_time: 1,
+            late: 1,
+            forget: 1,
+            current_streak: 0,
+            best_streak: 1,
+            streak_start_ledger: cow_data.born_ledger,
+        }
+    );
+    assert_eq!(
+        cow_data.condition,
+        CowCondition {
+            weight: INITIAL_WEIGHT + ON_TIME_WEIGHT_GAIN + LATE_WEIGHT_GAIN - FORGET_WEIGHT_LOSS,
+            health: INITIAL_HEALTH + ON_TIME_HEALTH_GAIN - FORGET_HEALTH_LOSS,
+            mood: INITIAL_MOOD + ON_TIME_MOOD_GAIN - LATE_MOOD_LOSS - FORGET_MOOD_LOSS,
+        }
+    );
+}
+
+#[test]
+fn feeding_tolerance_extends_feeding_windows() {
+    let farm = setup();
+    let user = new_user(&farm);
+    let cow_data = buy_cow(&farm, &user, symbol_short!("bessie"));
+    let mut genes = [0; 16];
+    genes[FEEDING_TOLERANCE_GENE] = u8::MAX;
+    set_genome(&farm, &cow_data.id, genes);
+
+    // late for most cows, still on time for this one.
+    jump(&farm.env, ON_TIME_FEED + MAX_FEEDING_TOLERANCE);
+    farm.client.feed_the_cow(&user, &cow_data.id);
+    jump(&farm.env, ON_TIME_FEED + MAX_FEEDING_TOLERANCE + 1);
+    farm.client.feed_the_cow(&user, &cow_data.id);
+
+    let cow_data = farm.client.get_cow(&cow_data.id).data.get(0).unwrap();
+    assert_eq!(
+        cow_data.feeding_stats,
+        CowFeedingStats {
+            on_time: 1,
+            late: 1,
+            forget: 0,
+            current_streak: 0,
+            best_streak: 1,
+            streak_start_ledger: cow_data.born_ledger,
         }
+    );
+}
+
+#[test]
+fn feeding_requires_ownership() {
+    let farm = setup();
+    let user = new_user(&farm);
+    let stranger = new_user(&farm);
+    let cow_data = buy_cow(&farm, &user, symbol_short!("bessie"));
+    buy_cow(&farm, &stranger, symbol_short!("daisy"));
+
+    jump(&farm.env, ON_TIME_FEED);
+    assert_eq!(
+        farm.client.feed_the_cow(&stranger, &cow_data.id).status,
+        Status::MissingOwnership
+    );
+}
+
+#[test]
+fn feed_many_reports_every_cow() {
+    let farm = setup();
+    let user = new_user(&farm);
+    let stranger = new_user(&farm);
+    let bessie = buy_cow(&farm, &user, symbol_short!("bessie"));
+    let daisy = buy_cow(&farm, &user, symbol_short!("daisy"));
+    let molly = buy_cow(&farm, &stranger, symbol_short!("molly"));
+
+    // the batch is limited to stay under resource limits.
+    let mut cow_ids: Vec<String> = Vec::new(&farm.env);
+    for _ in 0..=MAX_COWS_PER_FEEDING {
+        cow_ids.push_back(bessie.id.clone());
+    }
+    assert_eq!(
+        farm.client.feed_many(&user, &cow_ids).status,
+        Status::ExceedBatchLimit
+    );
+
+    // the result follows the given cow ID list.
+    jump(&farm.env, WELL_FED + 180);
+    let cow_ids = vec![&farm.env, bessie.id.clone(), molly.id, daisy.id.clone()];
+    let result = farm.client.feed_many(&user, &cow_ids);
+    assert_eq!(result.status, Status::Ok);
+    let statuses: std::vec::Vec<Status> = result.data.iter().map(|data| data.status).collect();
+    assert_eq!(statuses, [Status::Ok, Status::MissingOwnership, Status::Ok]);
+    for cow_id in [&bessie.id, &daisy.id] {
+        let cow_data = farm.client.get_cow(cow_id).data.get(0).unwrap();
+        assert_eq!(cow_data.last_fed_ledger, farm.env.ledger().sequence());
+    }
+
+    // fed cows are still full.
+    let result = farm.client.feed_many(&user, &cow_ids);
+    assert_eq!(result.data.get(0).unwrap().status, Status::FullStomach);
+}
+
+#[test]
+fn feed_all_pages_through_living_cows() {
+    let farm = setup();
+    let user = new_user(&farm);
+    farm.client.buy_plot(&user, &PlotTier::Small);
+    let herd: std::vec::Vec<CowData> = (0..MAX_COWS_PER_FEEDING + 2)
+        .map(|index| {
+            buy_cow(
+                &farm,
+                &user,
+                Symbol::new(&farm.env, &std::format!("cow{}", index)),
+            )
+        })
+        .collect();
+
+    // the first cow has died, it does not take a place in the batch.
+    farm.env.as_contract(&farm.contract_id, || {
+        farm.env.storage().temporary().remove(&herd[0].id);
+    });
+    jump(&farm.env, WELL_FED + 180);
+    let result = farm.client.feed_all(&user, &0);
+    assert_eq!(result.status, Status::Ok);
+    assert_eq!(result.data.len(), MAX_COWS_PER_FEEDING);
+    assert_eq!(result.next, MAX_COWS_PER_FEEDING + 1);
+    assert!(result.data.iter().all(|data| data.status == Status::Ok));
+
+    // the next call covers the rest of the herd.
+    let result = farm.client.feed_all(&user, &result.next);
+    assert_eq!(result.data.len(), 1);
+    assert_eq!(result.next, 0);
+    let cow_data = farm
+        .client
+        .get_cow(&herd.last().unwrap().id)
+        .data
+        .get(0)
+        .unwrap();
+    assert_eq!(cow_data.last_fed_ledger, farm.env.ledger().sequence());
+}
+
+// footprint and write limits of a single transaction on the network.
+fn assert_fits_network_limits(farm: &Farm) {
+    let resources = farm.env.cost_estimate().resources();
+    assert!(resources.disk_read_entries + resources.memory_read_entries <= 100);
+    assert!(resources.write_entries <= 50);
+    assert!(resources.write_bytes <= 132_096);
+    assert!(resources.contract_events_size_bytes <= 16_384);
+}
+
+#[test]
+fn batch_feeding_at_the_cap_fits_network_limits() {
+    let farm = setup();
+    let user = new_user(&farm);
+    let keeper = Address::generate(&farm.env);
+    let mut cow_ids: Vec<String> = Vec::new(&farm.env);
+    for index in 0..MAX_COWS_PER_FEEDING {
+        let name = Symbol::new(&farm.env, &std::format!("cow{}", index));
+        cow_ids.push_back(buy_cow(&farm, &user, name).id);
+    }
+    farm.client.register_feeding_plan(&user, &cow_ids, &1);
+
+    // every batch runs within the default budget and the network limits.
+    farm.env.cost_estimate().budget().reset_default();
+    jump(&farm.env, WELL_FED + 180);
+    let result = farm.client.feed_many(&user, &cow_ids);
+    assert!(result.data.iter().all(|data| data.status == Status::Ok));
+    assert_fits_network_limits(&farm);
+
+    jump(&farm.env, WELL_FED + 180);
+    let result = farm.client.feed_all(&user, &0);
+    assert_eq!(result.data.len(), MAX_COWS_PER_FEEDING);
+    assert!(result.data.iter().all(|data| data.status == Status::Ok));
+    assert_fits_network_limits(&farm);
+
+    jump(&farm.env, WELL_FED + 180);
+    let result = farm.client.run_feeding_plan(&keeper, &user);
+    assert_eq!(result.data.len(), MAX_COWS_PER_FEEDING);
+    assert!(result.data.iter().all(|data| data.status == Status::Ok));
+    assert_fits_network_limits(&farm);
+}
+
+#[test]
+fn caretaker_feeds_only_approved_cows() {
+    let farm = setup();
+    let owner = new_user(&farm);
+    let caretaker = new_user(&farm);
+    let bessie = buy_cow(&farm, &owner, symbol_short!("bessie"));
+    let daisy = buy_cow(&farm, &owner, symbol_short!("daisy"));
+    let molly = buy_cow(&farm, &caretaker, symbol_short!("molly"));
+    let expiry_ledger = farm.env.ledger().sequence() + LEDGER_AMOUNT_IN_24_HOURS;
+    let only_bessie = vec![&farm.env, bessie.id.clone()];
+
+    // approval is checked before it is saved.
+    assert_eq!(
+        farm.client
+            .approve_caretaker(&owner, &owner, &only_bessie, &false, &expiry_ledger),
+        Status::Fail
+    );
+    let past_ledger = farm.env.ledger().sequence();
+    assert_eq!(
+        farm.client
+            .approve_caretaker(&owner, &caretaker, &only_bessie, &false, &past_ledger),
+        Status::Expired
+    );
+    let with_molly = vec![&farm.env, molly.id.clone()];
+    assert_eq!(
+        farm.client
+            .approve_caretaker(&owner, &caretaker, &with_molly, &false, &expiry_ledger),
+        Status::MissingOwnership
+    );
+
+    // no approval, no feeding.
+    jump(&farm.env, WELL_FED + 180);
+    let cow_ids = vec![&farm.env, bessie.id.clone(), daisy.id.clone()];
+    assert_eq!(
+        farm.client
+            .feed_as_caretaker(&caretaker, &owner, &cow_ids)
+            .status,
+        Status::Unauthorized
+    );
+
+    // only the approved cow is fed.
+    assert_eq!(
+        farm.client
+            .approve_caretaker(&owner, &caretaker, &only_bessie, &false, &expiry_ledger),
+        Status::Saved
+    );
+    let result = farm.client.feed_as_caretaker(&caretaker, &owner, &cow_ids);
+    assert_eq!(result.status, Status::Ok);
+    let statuses: std::vec::Vec<Status> = result.data.iter().map(|data| data.status).collect();
+    assert_eq!(statuses, [Status::Ok, Status::Unauthorized]);
 
-        std::println!("gender: {:#?}", &gender);
+    // revoked approval is gone.
+    assert_eq!(farm.client.revoke_caretaker(&owner, &caretaker), Status::Ok);
+    assert_eq!(
+        farm.client.revoke_caretaker(&owner, &caretaker),
+        Status::NotFound
+    );
+    assert_eq!(
+        farm.client
+            .feed_as_caretaker(&caretaker, &owner, &cow_ids)
+            .status,
+        Status::Unauthorized
+    );
+}
+
+#[test]
+fn caretaker_approval_expires() {
+    let farm = setup();
+    let owner = new_user(&farm);
+    let caretaker = new_user(&farm);
+    let bessie = buy_cow(&farm, &owner, symbol_short!("bessie"));
+    let daisy = buy_cow(&farm, &owner, symbol_short!("daisy"));
+    let expiry_ledger = farm.env.ledger().sequence() + 2 * (WELL_FED + 180);
+
+    // an approval for all cows covers the whole herd.
+    let no_cow_ids: Vec<String> = Vec::new(&farm.env);
+    assert_eq!(
+        farm.client
+            .approve_caretaker(&owner, &caretaker, &no_cow_ids, &true, &expiry_ledger),
+        Status::Saved
+    );
+    jump(&farm.env, WELL_FED + 180);
+    let cow_ids = vec![&farm.env, bessie.id.clone(), daisy.id.clone()];
+    let result = farm.client.feed_as_caretaker(&caretaker, &owner, &cow_ids);
+    assert!(result.data.iter().all(|data| data.status == Status::Ok));
+
+    // the expired approval is removed on use.
+    jump(&farm.env, WELL_FED + 180);
+    assert_eq!(
+        farm.client
+            .feed_as_caretaker(&caretaker, &owner, &cow_ids)
+            .status,
+        Status::Expired
+    );
+    assert_eq!(
+        farm.client.revoke_caretaker(&owner, &caretaker),
+        Status::NotFound
+    );
+}
+
+#[test]
+fn feeding_plan_pays_keeper_from_deposit() {
+    let farm = setup();
+    let user = new_user(&farm);
+    let keeper = Address::generate(&farm.env);
+    let bessie = buy_cow(&farm, &user, symbol_short!("bessie"));
+    let daisy = buy_cow(&farm, &user, symbol_short!("daisy"));
+    let cow_ids = vec![&farm.env, bessie.id.clone(), daisy.id.clone()];
+
+    // the deposit moves from user to contract and is tracked as plan deposits.
+    assert_eq!(
+        farm.client
+            .register_feeding_plan(&user, &cow_ids, &-1)
+            .status,
+        Status::Fail
+    );
+    let user_balance = farm.token.balance(&user);
+    let contract_balance = farm.token.balance(&farm.contract_id);
+    let result = farm.client.register_feeding_plan(&user, &cow_ids, &1);
+    assert_eq!(result.status, Status::Saved);
+    assert_eq!(result.data.get(0).unwrap().balance, XLM);
+    assert_eq!(farm.token.balance(&user), user_balance - XLM);
+    assert_eq!(
+        farm.token.balance(&farm.contract_id),
+        contract_balance + XLM
+    );
+    assert_eq!(plan_deposits(&farm), XLM);
+
+    // nothing to pay for while the cows are still full.
+    assert_eq!(
+        farm.client.run_feeding_plan(&keeper, &user).status,
+        Status::TryAgain
+    );
+    assert_eq!(farm.token.balance(&keeper), 0);
+
+    // every fed cow earns the keeper one fee.
+    jump(&farm.env, WELL_FED + 180);
+    let result = farm.client.run_feeding_plan(&keeper, &user);
+    assert_eq!(result.status, Status::Ok);
+    assert!(result.data.iter().all(|data| data.status == Status::Ok));
+    assert_eq!(farm.token.balance(&keeper), 2 * FEEDING_PLAN_KEEPER_FEE);
+    let plan_data = farm.client.get_feeding_plan(&user).data.get(0).unwrap();
+    assert_eq!(plan_data.balance, XLM - 2 * FEEDING_PLAN_KEEPER_FEE);
+    assert_eq!(plan_deposits(&farm), plan_data.balance);
+
+    // a cow past its ON TIME window is left for the owner, the keeper is not paid for it.
+    jump(&farm.env, WELL_FED + 180);
+    farm.client.feed_the_cow(&user, &bessie.id);
+    jump(
+        &farm.env,
+        ON_TIME_FEED + MAX_FEEDING_TOLERANCE + 1 - (WELL_FED + 180),
+    );
+    let result = farm.client.run_feeding_plan(&keeper, &user);
+    assert_eq!(result.status, Status::Ok);
+    assert_eq!(result.data.get(0).unwrap().status, Status::Ok);
+    assert_eq!(result.data.get(1).unwrap().status, Status::Expired);
+    assert_eq!(farm.token.balance(&keeper), 3 * FEEDING_PLAN_KEEPER_FEE);
+    let daisy_data = farm.client.get_cow(&daisy.id).data.get(0).unwrap();
+    assert_eq!(daisy_data.feeding_stats.late, 0);
+}
+
+#[test]
+fn feeding_plan_stops_when_deposit_runs_out() {
+    let farm = setup();
+    let user = new_user(&farm);
+    let keeper = Address::generate(&farm.env);
+    let bessie = buy_cow(&farm, &user, symbol_short!("bessie"));
+    let cow_ids = vec![&farm.env, bessie.id.clone()];
+
+    // a plan without deposit never pays the keeper.
+    farm.client.register_feeding_plan(&user, &cow_ids, &0);
+    jump(&farm.env, WELL_FED + 180);
+    let result = farm.client.run_feeding_plan(&keeper, &user);
+    assert_eq!(result.status, Status::TryAgain);
+    assert_eq!(result.data.get(0).unwrap().status, Status::InsufficientFund);
+    assert_eq!(farm.token.balance(&keeper), 0);
+}
+
+#[test]
+fn cancel_feeding_plan_refunds_balance() {
+    let farm = setup();
+    let user = new_user(&farm);
+    let keeper = Address::generate(&farm.env);
+    let bessie = buy_cow(&farm, &user, symbol_short!("bessie"));
+    let cow_ids = vec![&farm.env, bessie.id.clone()];
+    assert_eq!(
+        farm.client.cancel_feeding_plan(&user).status,
+        Status::NotFound
+    );
+
+    // deposits add up, and the remaining balance goes back to user.
+    farm.client.register_feeding_plan(&user, &cow_ids, &1);
+    farm.client.register_feeding_plan(&user, &cow_ids, &2);
+    assert_eq!(plan_deposits(&farm), 3 * XLM);
+    jump(&farm.env, WELL_FED + 180);
+    farm.client.run_feeding_plan(&keeper, &user);
+    let user_balance = farm.token.balance(&user);
+    let result = farm.client.cancel_feeding_plan(&user);
+    assert_eq!(result.status, Status::Ok);
+    let remaining_balance = 3 * XLM - FEEDING_PLAN_KEEPER_FEE;
+    assert_eq!(result.data.get(0).unwrap().balance, remaining_balance);
+    assert_eq!(farm.token.balance(&user), user_balance + remaining_balance);
+    assert_eq!(plan_deposits(&farm), 0);
+    assert_eq!(farm.client.get_feeding_plan(&user).status, Status::NotFound);
+    assert_eq!(
+        farm.client.run_feeding_plan(&keeper, &user).status,
+        Status::NotFound
+    );
+}
+
+#[test]
+fn migrate_cow_upgrades_legacy_cow_data() {
+    let farm = setup();
+    let user = new_user(&farm);
+    let stranger = new_user(&farm);
+    let cow_id = legacy_cow(&farm, &user, "COW-LEGACY", symbol_short!("bessie"));
+
+    // only the owner listed in the ownership list can migrate the cow.
+    assert_eq!(
+        farm.client.migrate_cow(&stranger, &cow_id).status,
+        Status::MissingOwnership
+    );
+
+    // the older fields are kept, and the newer fields start from their hatching values.
+    let result = farm.client.migrate_cow(&user, &cow_id);
+    assert_eq!(result.status, Status::Ok);
+    let cow_data = result.data.get(0).unwrap();
+    assert_eq!(cow_data.feeding_stats.on_time, 3);
+    assert_eq!(cow_data.feeding_stats.late, 1);
+    assert_eq!(cow_data.condition, CowCondition::new());
+    assert_eq!(farm.client.get_cow(&cow_id).data.get(0).unwrap(), cow_data);
+    let plot_data = farm.client.get_plot(&cow_data.plot_id).data.get(0).unwrap();
+    assert!(plot_data.cow_ids.contains(&cow_id));
+    assert_eq!(farm.client.owner_of(&cow_id), Some(user.clone()));
+    let named_cow = farm.client.get_cow_by_name(&symbol_short!("bessie"));
+    assert_eq!(named_cow.data.get(0).unwrap().id, cow_id);
+
+    // the cow is a regular farm cow from now on.
+    assert_eq!(
+        farm.client.migrate_cow(&user, &cow_id).status,
+        Status::Duplicate
+    );
+    jump(&farm.env, WELL_FED + 180);
+    assert_eq!(farm.client.feed_the_cow(&user, &cow_id).status, Status::Ok);
+}
+
+#[test]
+fn legacy_cow_is_left_out_until_migrated() {
+    let farm = setup();
+    let user = new_user(&farm);
+    let seller = new_user(&farm);
+    let rosie = buy_cow(&farm, &seller, symbol_short!("rosie"));
+    raise_cow(&farm, &seller, &rosie.id);
+    let auction_id = register_auction(&farm, &seller, &rosie.id, 500).auction_id;
+    let molly = buy_cow(&farm, &seller, symbol_short!("molly"));
+    let daisy = buy_cow(&farm, &user, symbol_short!("daisy"));
+    let cow_id = legacy_cow(&farm, &user, "COW-LEGACY", symbol_short!("bessie"));
+
+    // single cow views report the cow as not migrated.
+    assert_eq!(farm.client.get_cow(&cow_id).status, Status::NotMigrated);
+    assert_eq!(farm.client.get_traits(&cow_id).status, Status::NotMigrated);
+    assert_eq!(
+        farm.client.cow_appraisal(&cow_id).status,
+        Status::NotMigrated
+    );
+
+    // cow lists and farm entries leave the cow out.
+    let cow_ids = |result: GetAllCowResult| -> std::vec::Vec<String> {
+        result.data.iter().map(|cow_data| cow_data.id).collect()
+    };
+    assert_eq!(
+        cow_ids(farm.client.get_all_cow(&user)),
+        std::vec![daisy.id.clone()]
+    );
+    assert_eq!(
+        cow_ids(farm.client.get_cows(&user, &0, &10)),
+        std::vec![daisy.id.clone()]
+    );
+    let is_legacy_entry = |entry_ttl: EntryTtl| entry_ttl.entry == FarmEntry::Cow(cow_id.clone());
+    let result = farm.client.get_farm_ttl(&user);
+    assert_eq!(result.status, Status::Ok);
+    assert!(!result.data.iter().any(is_legacy_entry));
+    let result = farm.client.extend_my_farm(&user);
+    assert_eq!(result.status, Status::Bumped);
+    assert!(!result.data.iter().any(is_legacy_entry));
+
+    // feeding needs the cow owner record that only migration writes.
+    let result = farm
+        .client
+        .feed_many(&user, &vec![&farm.env, cow_id.clone(), daisy.id.clone()]);
+    let statuses: std::vec::Vec<Status> = result.data.iter().map(|data| data.status).collect();
+    assert_eq!(statuses, [Status::MissingOwnership, Status::FullStomach]);
+    let result = farm.client.feed_all(&user, &0);
+    let statuses: std::vec::Vec<Status> = result.data.iter().map(|data| data.status).collect();
+    assert_eq!(statuses, [Status::FullStomach, Status::MissingOwnership]);
+
+    // herd scans of the owner skip the cow on hatching, transfer, and auction.
+    buy_cow(&farm, &user, symbol_short!("lulu"));
+    assert_eq!(
+        farm.client.transfer_cow(&seller, &user, &molly.id).status,
+        Status::Ok
+    );
+    assert_eq!(
+        farm.client.bidding(&user, &auction_id, &600).status,
+        Status::Ok
+    );
+    jump(&farm.env, LEDGER_AMOUNT_IN_12_HOURS + 1);
+    assert_eq!(farm.client.finalize_auction(&auction_id).status, Status::Ok);
+    assert_eq!(farm.client.owner_of(&rosie.id), Some(user.clone()));
+
+    // the cow is back on every path once migrated.
+    assert_eq!(farm.client.migrate_cow(&user, &cow_id).status, Status::Ok);
+    assert_eq!(farm.client.get_cow(&cow_id).status, Status::Ok);
+    assert_eq!(farm.client.get_all_cow(&user).data.len(), 5);
+}
+
+#[test]
+fn legacy_auction_is_closed_with_a_refund() {
+    let farm = setup();
+    let env = &farm.env;
+    let owner = new_user(&farm);
+    let bidder = new_user(&farm);
+    let other_bidder = new_user(&farm);
+    let cow_id = legacy_cow(&farm, &owner, "COW-LEGACY", symbol_short!("bessie"));
+
+    // save an auction the way the first contract version did, holding the highest bid.
+    let auction_id = String::from_str(env, "AUC-LEGACY");
+    let legacy_auction_data = LegacyAuctionData {
+        auction_id: auction_id.clone(),
+        cow_id: cow_id.clone(),
+        cow_name: symbol_short!("bessie"),
+        cow_breed: CowBreed::Jersey,
+        cow_gender: CowGender::Female,
+        cow_born_ledger: env.ledger().sequence(),
+        owner: owner.clone(),
+        start_price: 500,
+        highest_bidder: Bidder {
+            user: bidder.clone(),
+            price: 600,
+        },
+        bid_history: Vec::new(env),
+        auction_limit_ledger: env.ledger().sequence() + LEDGER_AMOUNT_IN_12_HOURS,
+    };
+    farm.token
+        .transfer(&bidder, &farm.contract_id, &(600 * XLM));
+    env.as_contract(&farm.contract_id, || {
+        env.storage()
+            .temporary()
+            .set(&auction_id, &legacy_auction_data);
+        let mut cow_data: LegacyCowData = env.storage().temporary().get(&cow_id).unwrap();
+        cow_data.auction_id = auction_id.clone();
+        env.storage().temporary().set(&cow_id, &cow_data);
+        let auction_list = vec![env, auction_id.clone()];
+        env.storage()
+            .persistent()
+            .set(&DataKey::AuctionList, &auction_list);
+    });
+    let bidder_balance = farm.token.balance(&bidder);
+
+    // the older auction takes no more bids, and holds its cow until it is closed.
+    assert_eq!(
+        farm.client.bidding(&other_bidder, &auction_id, &700).status,
+        Status::BidIsClosed
+    );
+    assert_eq!(
+        farm.client.migrate_cow(&owner, &cow_id).status,
+        Status::OnAuction
+    );
+
+    // anyone can close it before its deadline, the highest bid goes back to the bidder.
+    assert_eq!(farm.client.finalize_auction(&auction_id).status, Status::Ok);
+    assert_eq!(farm.token.balance(&bidder), bidder_balance + 600 * XLM);
+    assert_eq!(auction_escrow(&farm), 0);
+    assert_eq!(
+        farm.client.finalize_auction(&auction_id).status,
+        Status::NotFound
+    );
+    let auction_list: Vec<String> = env.as_contract(&farm.contract_id, || {
+        env.storage()
+            .persistent()
+            .get(&DataKey::AuctionList)
+            .unwrap()
+    });
+    assert!(auction_list.is_empty());
+
+    // the cow stays with its owner and can be migrated now.
+    assert_eq!(farm.client.migrate_cow(&owner, &cow_id).status, Status::Ok);
+    assert_eq!(farm.client.owner_of(&cow_id), Some(owner.clone()));
+}
+
+#[test]
+fn harvest_milk_accrues_from_adult_stage() {
+    let farm = setup();
+    let user = new_user(&farm);
+    let bessie = buy_cow(&farm, &user, symbol_short!("bessie"));
+    let daisy = buy_cow(&farm, &user, symbol_short!("daisy"));
+    set_gender(&farm, &bessie.id, CowGender::Female);
+    set_gender(&farm, &daisy.id, CowGender::Male);
+    set_genome(&farm, &bessie.id, [0; 16]);
+    let cow_ids = vec![&farm.env, bessie.id.clone(), daisy.id.clone()];
+
+    // calf cannot be milked.
+    let result = farm.client.harvest_milk(&user, &cow_ids);
+    assert_eq!(result.amount, 0);
+    assert!(result
+        .data
+        .iter()
+        .all(|data| data.status == Status::Underage));
+
+    // milk accrues from the day the cow becomes adult, and only female cows produce milk.
+    let adult_ledger = bessie.born_ledger + get_cow_life_cycle(&CowBreed::Jersey)[1];
+    while farm.env.ledger().sequence() < adult_ledger + LEDGER_AMOUNT_IN_12_HOURS {
+        jump(&farm.env, WELL_FED + 180);
+        farm.client.feed_many(&user, &cow_ids);
+    }
+    let cow_data = farm.client.get_cow(&bessie.id).data.get(0).unwrap();
+    assert_eq!(cow_data.condition.health, MAX_HEALTH);
+    let milking_ledger = farm.env.ledger().sequence() - adult_ledger;
+    let result = farm.client.harvest_milk(&user, &cow_ids);
+    assert_eq!(result.status, Status::Ok);
+    let statuses: std::vec::Vec<Status> = result.data.iter().map(|data| data.status).collect();
+    assert_eq!(statuses, [Status::Ok, Status::NoMilk]);
+    let milk_amount =
+        JERSEY_DAILY_MILK * milking_ledger as i128 / LEDGER_AMOUNT_IN_24_HOURS as i128;
+    assert_eq!(result.amount, milk_amount);
+    assert_eq!(result.milk_balance, milk_amount);
+
+    // milked cow needs time to produce milk again.
+    let result = farm.client.harvest_milk(&user, &cow_ids);
+    assert_eq!(result.amount, 0);
+    assert_eq!(result.milk_balance, milk_amount);
+
+    // unharvested milk spoils after 24 hours.
+    let milked_ledger = farm.env.ledger().sequence();
+    while farm.env.ledger().sequence() < milked_ledger + LEDGER_AMOUNT_IN_24_HOURS {
+        jump(&farm.env, WELL_FED + 180);
+        farm.client.feed_many(&user, &cow_ids);
+    }
+    let result = farm.client.harvest_milk(&user, &cow_ids);
+    assert_eq!(result.amount, JERSEY_DAILY_MILK);
+    assert_eq!(
+        farm.client.get_milk_balance(&user).milk_balance,
+        milk_amount + JERSEY_DAILY_MILK
+    );
+}
+
+#[test]
+fn sell_milk_pays_from_treasury() {
+    let farm = setup();
+    let user = new_user(&farm);
+    let bessie = buy_cow(&farm, &user, symbol_short!("bessie"));
+    farm.env.as_contract(&farm.contract_id, || {
+        let milk_key = DataKey::MilkBalance(user.clone());
+        farm.env.storage().persistent().set(&milk_key, &20_000i128);
+    });
+
+    assert_eq!(farm.client.sell_milk(&user, &0).status, Status::Fail);
+    assert_eq!(
+        farm.client.sell_milk(&user, &20_001).status,
+        Status::InsufficientFund
+    );
+
+    // milk is sold to supplier at a fixed price.
+    let user_balance = farm.token.balance(&user);
+    let result = farm.client.sell_milk(&user, &10_000);
+    assert_eq!(result.status, Status::Ok);
+    assert_eq!(result.milk_balance, 10_000);
+    assert_eq!(
+        farm.token.balance(&user),
+        user_balance + 10_000 * MILK_PRICE_PER_MILLILITER
+    );
+
+    // feeding plan deposits are not part of the treasury.
+    let cow_ids = vec![&farm.env, bessie.id];
+    farm.client.register_feeding_plan(&user, &cow_ids, &100);
+    empty_treasury(&farm);
+    assert!(farm.token.balance(&farm.contract_id) > 10_000 * MILK_PRICE_PER_MILLILITER);
+    assert_eq!(
+        farm.client.sell_milk(&user, &10_000).status,
+        Status::InsufficientFund
+    );
+}
+
+#[test]
+fn get_cows_pages_through_living_cows() {
+    let farm = setup();
+    let user = new_user(&farm);
+    let herd: std::vec::Vec<CowData> = ["bessie", "daisy", "molly", "rosie"]
+        .iter()
+        .map(|name| buy_cow(&farm, &user, Symbol::new(&farm.env, name)))
+        .collect();
+    let stranger = Address::generate(&farm.env);
+    assert_eq!(
+        farm.client.get_cows(&stranger, &0, &10).status,
+        Status::NotFound
+    );
+
+    // pages follow the ownership list.
+    let page_ids = |start: u32, limit: u32| -> std::vec::Vec<String> {
+        let result = farm.client.get_cows(&user, &start, &limit);
+        assert_eq!(result.status, Status::Ok);
+        result.data.iter().map(|cow_data| cow_data.id).collect()
+    };
+    assert_eq!(page_ids(0, 2), [herd[0].id.clone(), herd[1].id.clone()]);
+    assert_eq!(page_ids(2, 10), [herd[2].id.clone(), herd[3].id.clone()]);
+    assert!(page_ids(4, 10).is_empty());
+    assert!(page_ids(0, 0).is_empty());
+
+    // dead cows are skipped, so a page can be shorter than its limit.
+    farm.env.as_contract(&farm.contract_id, || {
+        farm.env.storage().temporary().remove(&herd[1].id);
+    });
+    assert_eq!(page_ids(0, 2), [herd[0].id.clone()]);
+}
+
+#[test]
+fn check_ownership_finds_inconsistent_records() {
+    let farm = setup();
+    let user = new_user(&farm);
+    let stranger = new_user(&farm);
+    let bessie = buy_cow(&farm, &user, symbol_short!("bessie"));
+    let daisy = buy_cow(&farm, &user, symbol_short!("daisy"));
+    let molly = buy_cow(&farm, &user, symbol_short!("molly"));
+    let rosie = buy_cow(&farm, &user, symbol_short!("rosie"));
+    assert_eq!(
+        farm.client
+            .check_ownership(&Address::generate(&farm.env))
+            .status,
+        Status::NotFound
+    );
+    assert_eq!(farm.client.check_ownership(&user).status, Status::Ok);
+
+    // break the records of every cow but bessie.
+    farm.env.as_contract(&farm.contract_id, || {
+        let storage = farm.env.storage().temporary();
+        storage.remove(&daisy.id);
+        storage.remove(&DataKey::CowOwner(molly.id.clone()));
+        storage.set(&DataKey::CowOwner(rosie.id.clone()), &stranger);
+    });
+    let result = farm.client.check_ownership(&user);
+    assert_eq!(result.status, Status::Fail);
+    assert_eq!(result.dead_cow_ids, vec![&farm.env, daisy.id.clone()]);
+    assert_eq!(result.missing_owner_ids, vec![&farm.env, molly.id.clone()]);
+    assert_eq!(result.wrong_owner_ids, vec![&farm.env, rosie.id.clone()]);
+
+    // ownership checks never write a missing cow owner record.
+    jump(&farm.env, WELL_FED + 180);
+    assert_eq!(
+        farm.client.feed_the_cow(&user, &molly.id).status,
+        Status::MissingOwnership
+    );
+    assert_eq!(farm.client.get_approved(&molly.id), None);
+    assert_eq!(farm.client.owner_of(&molly.id), None);
+    assert_eq!(
+        farm.client.feed_the_cow(&user, &bessie.id).status,
+        Status::Ok
+    );
+
+    // cleaning removes dead cows only.
+    let result = farm.client.clean_ownership(&user);
+    assert_eq!(result.dead_cow_ids, vec![&farm.env, daisy.id]);
+    let result = farm.client.check_ownership(&user);
+    assert!(result.dead_cow_ids.is_empty());
+    assert_eq!(result.missing_owner_ids, vec![&farm.env, molly.id]);
+}
+
+// actual remaining lifetime of the storage entry behind a farm entry.
+fn actual_ttl(farm: &Farm, entry: &FarmEntry) -> u32 {
+    let env = &farm.env;
+    env.as_contract(&farm.contract_id, || {
+        let storage = env.storage();
+        match entry {
+            FarmEntry::Ownership(user) => storage.persistent().get_ttl(user),
+            FarmEntry::AuctionList => storage.persistent().get_ttl(&DataKey::AuctionList),
+            FarmEntry::Auction(auction_id) => storage.persistent().get_ttl(auction_id),
+            FarmEntry::Cow(cow_id) => storage.temporary().get_ttl(cow_id),
+            FarmEntry::CowName(cow_name) => storage
+                .persistent()
+                .get_ttl(&DataKey::CowName(cow_name.clone())),
+        }
     })
 }
+
+#[test]
+fn farm_ttl_reports_every_entry() {
+    let farm = setup();
+    let user = new_user(&farm);
+    let cow_data = buy_cow(&farm, &user, symbol_short!("bessie"));
+
+    jump(&farm.env, WELL_FED + 1);
+    assert_eq!(
+        farm.client.feed_the_cow(&user, &cow_data.id).status,
+        Status::Ok
+    );
+
+    let result = farm.client.get_farm_ttl(&user);
+    assert_eq!(result.status, Status::Ok);
+    let entries: Vec<FarmEntry> = vec![
+        &farm.env,
+        FarmEntry::Ownership(user.clone()),
+        FarmEntry::Cow(cow_data.id.clone()),
+        FarmEntry::CowName(cow_data.name.clone()),
+    ];
+    assert_eq!(result.data.len(), entries.len());
+    for (entry_ttl, entry) in result.data.iter().zip(entries.iter()) {
+        assert_eq!(entry_ttl.entry, entry);
+        assert!(entry_ttl.remaining_ledger > 0);
+        assert_eq!(entry_ttl.remaining_ledger, actual_ttl(&farm, &entry));
+    }
+
+    // unknown farmer has no farm.
+    let stranger = Address::generate(&farm.env);
+    assert_eq!(farm.client.get_farm_ttl(&stranger).status, Status::NotFound);
+}
+
+#[test]
+fn extend_my_farm_bumps_every_entry() {
+    let farm = setup();
+    let user = new_user(&farm);
+    let cow_data = buy_cow(&farm, &user, symbol_short!("bessie"));
+
+    jump(&farm.env, LEDGER_AMOUNT_IN_24_HOURS / 2);
+    let result = farm.client.extend_my_farm(&user);
+    assert_eq!(result.status, Status::Bumped);
+    assert_eq!(result.data.len(), 3);
+    for entry_ttl in result.data.iter() {
+        let expected_ledger = match entry_ttl.entry {
+            // bumping a cow never feeds it.
+            FarmEntry::Cow(_) => cow_data.last_fed_ledger + LEDGER_AMOUNT_IN_24_HOURS,
+            _ => farm.env.ledger().sequence() + LEDGER_AMOUNT_IN_1_WEEK,
+        };
+        assert_eq!(entry_ttl.live_until_ledger, expected_ledger);
+        assert_eq!(
+            entry_ttl.remaining_ledger,
+            actual_ttl(&farm, &entry_ttl.entry)
+        );
+    }
+    let farm_ttl = farm.client.get_farm_ttl(&user);
+    assert_eq!(farm_ttl.data, result.data);
+
+    // feeding within a day of the extension leaves the ownership lifetime record alone.
+    let ownership = FarmEntry::Ownership(user.clone());
+    let ownership_ttl = result.data.get(0).unwrap();
+    jump(&farm.env, WELL_FED + 1);
+    assert_eq!(
+        farm.client.feed_the_cow(&user, &cow_data.id).status,
+        Status::Ok
+    );
+    let entries = vec![&farm.env, ownership.clone()];
+    let result = farm.client.get_entries_ttl(&entries);
+    assert_eq!(
+        result.data.get(0).unwrap().live_until_ledger,
+        ownership_ttl.live_until_ledger
+    );
+    assert_eq!(
+        result.data.get(0).unwrap().remaining_ledger,
+        actual_ttl(&farm, &ownership)
+    );
+
+    // admin extension skips missing entries and limits the batch size.
+    let missing = FarmEntry::Cow(String::from_str(&farm.env, "COW-MISSING"));
+    let result = farm
+        .client
+        .extend_entries(&vec![&farm.env, ownership.clone(), missing.clone()]);
+    assert_eq!(result.status, Status::Bumped);
+    assert_eq!(result.data.get(1).unwrap().live_until_ledger, 0);
+    let mut entries: Vec<FarmEntry> = Vec::new(&farm.env);
+    for _ in 0..=MAX_ENTRIES_PER_EXTEND {
+        entries.push_back(missing.clone());
+    }
+    assert_eq!(
+        farm.client.extend_entries(&entries).status,
+        Status::ExceedBatchLimit
+    );
+}
+
+#[test]
+fn sell_cow_rejects_calf() {
+    let farm = setup();
+    let user = new_user(&farm);
+    let cow_data = buy_cow(&farm, &user, symbol_short!("bessie"));
+
+    let result = farm.client.sell_cow(&user, &cow_data.id);
+    assert_eq!(result.status, Status::Underage);
+    assert_eq!(
+        farm.client.cow_appraisal(&cow_data.id).status,
+        Status::Underage
+    );
+}
+
+#[test]
+fn sell_cow_pays_appraisal_price() {
+    let farm = setup();
+    let user = new_user(&farm);
+    let cow_data = buy_cow(&farm, &user, symbol_short!("bessie"));
+    raise_cow(&farm, &user, &cow_data.id);
+
+    let appraisal = farm.client.cow_appraisal(&cow_data.id);
+    assert_eq!(appraisal.status, Status::Ok);
+    assert!(appraisal.price > JERSEY_PRICE * XLM);
+
+    let user_balance = farm.token.balance(&user);
+    let result = farm.client.sell_cow(&user, &cow_data.id);
+    assert_eq!(result.status, Status::Ok);
+    assert_eq!(result.ownership.len(), 0);
+    assert_eq!(farm.token.balance(&user), user_balance + appraisal.price);
+    assert_eq!(farm.client.get_cow(&cow_data.id).status, Status::NotFound);
+    assert_eq!(
+        farm.client.get_cow_by_name(&symbol_short!("bessie")).status,
+        Status::NotFound
+    );
+}
+
+#[test]
+fn get_traits_decodes_genome() {
+    let env = Env::default();
+    let common = get_cow_traits(&BytesN::from_array(&env, &[0; 16]));
+    assert_eq!(
+        (common.coat, common.horns, common.size, common.rarity),
+        (
+            CowCoat::Brown,
+            CowHorns::Polled,
+            CowSize::Small,
+            CowRarity::Common
+        )
+    );
+    assert_eq!(
+        (
+            common.feeding_tolerance,
+            common.milk_yield_bonus,
+            common.appraisal_bonus
+        ),
+        (0, 0, 0)
+    );
+
+    let legendary = get_cow_traits(&BytesN::from_array(&env, &[u8::MAX; 16]));
+    assert_eq!(
+        (
+            legendary.coat,
+            legendary.horns,
+            legendary.size,
+            legendary.rarity
+        ),
+        (
+            CowCoat::Golden,
+            CowHorns::Curved,
+            CowSize::Giant,
+            CowRarity::Legendary
+        )
+    );
+    assert_eq!(
+        (
+            legendary.feeding_tolerance,
+            legendary.milk_yield_bonus,
+            legendary.appraisal_bonus
+        ),
+        (
+            MAX_FEEDING_TOLERANCE,
+            MAX_MILK_YIELD_BONUS,
+            MAX_APPRAISAL_BONUS + RARITY_APPRAISAL_BONUS[4]
+        )
+    );
+
+    // every hatched cow has its traits decoded from its own genome.
+    let farm = setup();
+    let user = new_user(&farm);
+    let cow_data = buy_cow(&farm, &user, symbol_short!("bessie"));
+    let result = farm.client.get_traits(&cow_data.id);
+    assert_eq!(result.status, Status::Ok);
+    assert_eq!(
+        result.data.get(0).unwrap(),
+        get_cow_traits(&cow_data.genome)
+    );
+    assert_eq!(
+        farm.client
+            .get_traits(&String::from_str(&farm.env, "COW404"))
+            .status,
+        Status::NotFound
+    );
+}
+
+#[test]
+fn traits_add_appraisal_bonus() {
+    let farm = setup();
+    let user = new_user(&farm);
+    let plain_cow = buy_cow(&farm, &user, symbol_short!("bessie"));
+    let rare_cow = buy_cow(&farm, &user, symbol_short!("daisy"));
+    set_genome(&farm, &plain_cow.id, [0; 16]);
+    set_genome(&farm, &rare_cow.id, [u8::MAX; 16]);
+
+    // both cows are fed the same way, only their genes differ.
+    let cow_ids = vec![&farm.env, plain_cow.id.clone(), rare_cow.id.clone()];
+    for _ in 0..12 {
+        jump(&farm.env, WELL_FED + 180);
+        farm.client.feed_many(&user, &cow_ids);
+    }
+    let plain_price = farm.client.cow_appraisal(&plain_cow.id).price;
+    let rare_price = farm.client.cow_appraisal(&rare_cow.id).price;
+    let bonus = MAX_APPRAISAL_BONUS + RARITY_APPRAISAL_BONUS[4];
+    assert_eq!(
+        rare_price - plain_price,
+        JERSEY_PRICE * XLM * bonus / PRECISION_100_PERCENT
+    );
+}
+
+#[test]
+fn sell_cow_needs_enough_treasury() {
+    // the treasury only holds what the user paid for the plot rent and the cow.
+    let farm = setup_with_treasury(Env::default(), 0);
+    let user = new_tenant(&farm);
+    let cow_data = buy_cow(&farm, &user, symbol_short!("bessie"));
+    raise_cow(&farm, &user, &cow_data.id);
+
+    // plan deposits held by the contract belong to the neighbour, not the treasury.
+    let neighbour = new_user(&farm);
+    let daisy = buy_cow(&farm, &neighbour, symbol_short!("daisy"));
+    let cow_ids = vec![&farm.env, daisy.id.clone()];
+    farm.client
+        .register_feeding_plan(&neighbour, &cow_ids, &10_000);
+    empty_treasury(&farm);
+
+    let user_balance = farm.token.balance(&user);
+    let result = farm.client.sell_cow(&user, &cow_data.id);
+    assert_eq!(result.status, Status::InsufficientFund);
+    assert_eq!(farm.token.balance(&user), user_balance);
+    assert_eq!(farm.client.owner_of(&cow_data.id), Some(user));
+    assert_eq!(plan_deposits(&farm), 10_000 * XLM);
+}
+
+#[test]
+fn sell_cow_requires_ownership() {
+    let farm = setup();
+    let user = new_user(&farm);
+    let stranger = new_user(&farm);
+    let cow_data = buy_cow(&farm, &user, symbol_short!("bessie"));
+    buy_cow(&farm, &stranger, symbol_short!("daisy"));
+    raise_cow(&farm, &user, &cow_data.id);
+
+    assert_eq!(
+        farm.client.sell_cow(&stranger, &cow_data.id).status,
+        Status::MissingOwnership
+    );
+}
+
+#[test]
+fn auction_moves_cow_to_highest_bidder() {
+    let farm = setup();
+    let owner = new_user(&farm);
+    let first_bidder = new_user(&farm);
+    let second_bidder = new_user(&farm);
+    let cow_data = buy_cow(&farm, &owner, symbol_short!("bessie"));
+
+    // calf cannot be auctioned.
+    let external_ref = String::from_str(&farm.env, "");
+    assert_eq!(
+        farm.client
+            .register_auction(&owner, &cow_data.id, &external_ref, &500)
+            .status,
+        Status::Underage
+    );
+
+    raise_cow(&farm, &owner, &cow_data.id);
+    let auction_data = register_auction(&farm, &owner, &cow_data.id, 500);
+    let auction_id = auction_data.auction_id;
+    assert_eq!(auction_id, String::from_str(&farm.env, "AUC1"));
+
+    // cow on auction cannot be sold or auctioned again.
+    assert_eq!(
+        farm.client.sell_cow(&owner, &cow_data.id).status,
+        Status::OnAuction
+    );
+    assert_eq!(
+        farm.client
+            .register_auction(&owner, &cow_data.id, &external_ref, &500)
+            .status,
+        Status::OnAuction
+    );
+
+    // owner cannot bid on their own cow.
+    assert_eq!(
+        farm.client.bidding(&owner, &auction_id, &600).status,
+        Status::Unauthorized
+    );
+
+    // bid must be higher than the start price and the highest bid.
+    assert_eq!(
+        farm.client.bidding(&first_bidder, &auction_id, &500).status,
+        Status::CannotBidLower
+    );
+    assert_eq!(
+        farm.client.bidding(&first_bidder, &auction_id, &600).status,
+        Status::Ok
+    );
+    assert_eq!(farm.token.balance(&first_bidder), 99_400 * XLM);
+    assert_eq!(
+        farm.client
+            .bidding(&second_bidder, &auction_id, &600)
+            .status,
+        Status::CannotBidLower
+    );
+
+    // outbid bidder is refunded.
+    let result = farm.client.bidding(&second_bidder, &auction_id, &700);
+    assert_eq!(result.status, Status::Ok);
+    assert_eq!(farm.token.balance(&first_bidder), 100_000 * XLM);
+    assert_eq!(farm.token.balance(&second_bidder), 99_300 * XLM);
+    let auction_data = result.auction_data.get(0).unwrap();
+    assert_eq!(auction_data.bid_history.len(), 2);
+    assert_eq!(auction_data.highest_bidder.user, second_bidder);
+
+    // auction can only be finalized after bidding is closed.
+    assert_eq!(
+        farm.client.finalize_auction(&auction_id).status,
+        Status::BidIsOpen
+    );
+    jump(&farm.env, LEDGER_AMOUNT_IN_12_HOURS + 1);
+    assert_eq!(
+        farm.client.bidding(&first_bidder, &auction_id, &800).status,
+        Status::BidIsClosed
+    );
+
+    let owner_balance = farm.token.balance(&owner);
+    assert_eq!(farm.client.finalize_auction(&auction_id).status, Status::Ok);
+    assert_eq!(farm.token.balance(&owner), owner_balance + 700 * XLM);
+    assert_eq!(
+        farm.client.owner_of(&cow_data.id),
+        Some(second_bidder.clone())
+    );
+    assert_eq!(farm.client.balance_of(&owner), 0);
+    assert_eq!(farm.client.balance_of(&second_bidder), 1);
+    assert_eq!(
+        farm.client.get_auction(&auction_id).status,
+        Status::NotFound
+    );
+    let cow_data = farm.client.get_cow(&cow_data.id).data.get(0).unwrap();
+    assert_eq!(cow_data.auction_id, String::from_str(&farm.env, ""));
+}
+
+#[test]
+fn auction_without_bid_returns_cow_to_owner() {
+    let farm = setup();
+    let owner = new_user(&farm);
+    let cow_data = buy_cow(&farm, &owner, symbol_short!("bessie"));
+    raise_cow(&farm, &owner, &cow_data.id);
+    let auction_data = register_auction(&farm, &owner, &cow_data.id, 500);
+
+    jump(&farm.env, LEDGER_AMOUNT_IN_12_HOURS + 1);
+    assert_eq!(
+        farm.client
+            .finalize_auction(&auction_data.auction_id)
+            .status,
+        Status::Ok
+    );
+    assert_eq!(farm.client.owner_of(&cow_data.id), Some(owner.clone()));
+
+    // cow is no longer on auction and can be sold.
+    assert_eq!(
+        farm.client.sell_cow(&owner, &cow_data.id).status,
+        Status::Ok
+    );
+}
+
+#[test]
+fn late_finalization_still_settles_the_highest_bid() {
+    let farm = setup();
+    let owner = new_user(&farm);
+    let bidder = new_user(&farm);
+    let cow_data = buy_cow(&farm, &owner, symbol_short!("bessie"));
+    raise_cow(&farm, &owner, &cow_data.id);
+
+    let auction_id = register_auction(&farm, &owner, &cow_data.id, 500).auction_id;
+    assert_eq!(
+        farm.client.bidding(&bidder, &auction_id, &600).status,
+        Status::Ok
+    );
+    assert_eq!(auction_escrow(&farm), 600 * XLM);
+
+    // nobody finalizes the auction for days, the owner keeps the cow alive meanwhile.
+    for _ in 0..6 {
+        jump(&farm.env, WELL_FED + 180);
+        assert_eq!(
+            farm.client.feed_the_cow(&owner, &cow_data.id).status,
+            Status::Ok
+        );
+    }
+    assert_eq!(farm.client.get_auction(&auction_id).status, Status::Ok);
+
+    let owner_balance = farm.token.balance(&owner);
+    assert_eq!(farm.client.finalize_auction(&auction_id).status, Status::Ok);
+    assert_eq!(farm.token.balance(&owner), owner_balance + 600 * XLM);
+    assert_eq!(auction_escrow(&farm), 0);
+    assert_eq!(
+        farm.client.get_owner_of(&cow_data.id).owner,
+        vec![&farm.env, bidder]
+    );
+    assert_eq!(
+        farm.client.get_auction(&auction_id).status,
+        Status::NotFound
+    );
+}
+
+#[test]
+fn auction_of_dead_cow_refunds_bidder() {
+    let farm = setup();
+    let owner = new_user(&farm);
+    let bidder = new_user(&farm);
+    let cow_data = buy_cow(&farm, &owner, symbol_short!("bessie"));
+    raise_cow(&farm, &owner, &cow_data.id);
+
+    // the cow is hungry and will die before the auction entry expires.
+    jump(&farm.env, WELL_FED);
+    let auction_data = register_auction(&farm, &owner, &cow_data.id, 500);
+    let auction_id = auction_data.auction_id;
+    assert_eq!(
+        farm.client.bidding(&bidder, &auction_id, &600).status,
+        Status::Ok
+    );
+
+    jump(&farm.env, LEDGER_AMOUNT_IN_24_HOURS - WELL_FED + 1);
+    assert_eq!(farm.client.get_cow(&cow_data.id).status, Status::NotFound);
+
+    let owner_balance = farm.token.balance(&owner);
+    assert_eq!(farm.client.finalize_auction(&auction_id).status, Status::Ok);
+
+    let details = AuctionEventDetails {
+        auction_id: auction_id.clone(),
+        cow_id: cow_data.id.clone(),
+        name: cow_data.name,
+        owner: owner.clone(),
+        bidder: bidder.clone(),
+        price: 600,
+        auction_limit_ledger: auction_data.auction_limit_ledger,
+    };
+    assert_eq!(
+        farm.env
+            .events()
+            .all()
+            .filter_by_contract(&farm.contract_id),
+        vec![
+            &farm.env,
+            (
+                farm.contract_id.clone(),
+                (
+                    symbol_short!("dead_cow"),
+                    EVENT_VERSION,
+                    owner.clone(),
+                    cow_data.id.clone()
+                )
+                    .into_val(&farm.env),
+                details.clone().into_val(&farm.env),
+            ),
+            (
+                farm.contract_id.clone(),
+                (
+                    symbol_short!("refund"),
+                    EVENT_VERSION,
+                    bidder.clone(),
+                    cow_data.id
+                )
+                    .into_val(&farm.env),
+                details.into_val(&farm.env),
+            ),
+        ]
+    );
+    assert_eq!(farm.token.balance(&bidder), 100_000 * XLM);
+    assert_eq!(farm.token.balance(&owner), owner_balance);
+}
+
+#[test]
+fn auction_of_dead_cow_without_bid() {
+    let farm = setup();
+    let owner = new_user(&farm);
+    let cow_data = buy_cow(&farm, &owner, symbol_short!("bessie"));
+    raise_cow(&farm, &owner, &cow_data.id);
+
+    jump(&farm.env, WELL_FED);
+    let auction_data = register_auction(&farm, &owner, &cow_data.id, 500);
+    jump(&farm.env, LEDGER_AMOUNT_IN_24_HOURS - WELL_FED + 1);
+
+    assert_eq!(
+        farm.client
+            .finalize_auction(&auction_data.auction_id)
+            .status,
+        Status::Ok
+    );
+    assert_eq!(
+        farm.client
+            .finalize_auction(&auction_data.auction_id)
+            .status,
+        Status::NotFound
+    );
+}
+
+#[test]
+fn generated_ids_skip_taken_ids() {
+    let farm = setup();
+    let owner = new_user(&farm);
+
+    // ids saved by older contract versions are never handed out again.
+    legacy_cow(
+        &farm,
+        &Address::generate(&farm.env),
+        "COW1",
+        symbol_short!("daisy"),
+    );
+    let cow_data = buy_cow(&farm, &owner, symbol_short!("bessie"));
+    assert_eq!(cow_data.id, String::from_str(&farm.env, "COW2"));
+    raise_cow(&farm, &owner, &cow_data.id);
+
+    let auction_data = register_auction(&farm, &owner, &cow_data.id, 500);
+    assert_eq!(auction_data.auction_id, String::from_str(&farm.env, "AUC1"));
+    let legacy_auction_id = String::from_str(&farm.env, "AUC2");
+    farm.env.as_contract(&farm.contract_id, || {
+        let legacy_auction_data = AuctionData {
+            auction_id: legacy_auction_id.clone(),
+            ..auction_data.clone()
+        };
+        let storage = farm.env.storage().temporary();
+        storage.set(&legacy_auction_id, &legacy_auction_data);
+        storage.extend_ttl(
+            &legacy_auction_id,
+            LEDGER_AMOUNT_IN_1_WEEK,
+            LEDGER_AMOUNT_IN_1_WEEK,
+        );
+    });
+
+    // the second cow is raised while the first one is on auction.
+    let second_cow_data = buy_cow(&farm, &owner, symbol_short!("clara"));
+    raise_cow(&farm, &owner, &second_cow_data.id);
+    let auction_data = register_auction(&farm, &owner, &second_cow_data.id, 500);
+    assert_eq!(auction_data.auction_id, String::from_str(&farm.env, "AUC3"));
+}
+
+#[test]
+fn farmer_profile_tracks_farm_activity() {
+    let farm = setup();
+    let owner = new_user(&farm);
+    let bidder = new_user(&farm);
+    assert_eq!(farm.client.get_profile(&owner).status, Status::NotFound);
+
+    // display name is limited in length.
+    let long_name = String::from_str(&farm.env, "a farmer name that is far too long");
+    assert_eq!(
+        farm.client.set_display_name(&owner, &long_name),
+        Status::Fail
+    );
+    let display_name = String::from_str(&farm.env, "Old MacDonald");
+    assert_eq!(
+        farm.client.set_display_name(&owner, &display_name),
+        Status::Saved
+    );
+    let join_ledger = farm.env.ledger().sequence();
+
+    // daisy is left hungry while bessie is raised.
+    let cow_data = buy_cow(&farm, &owner, symbol_short!("bessie"));
+    let dead_cow = buy_cow(&farm, &owner, symbol_short!("daisy"));
+    raise_cow(&farm, &owner, &cow_data.id);
+    let result = farm.client.clean_ownership(&owner);
+    assert_eq!(result.dead_cow_ids.len(), 1);
+    assert_eq!(result.dead_cow_ids.get(0).unwrap(), dead_cow.id);
+    let on_time_feeds = farm
+        .client
+        .get_cow(&cow_data.id)
+        .data
+        .get(0)
+        .unwrap()
+        .feeding_stats
+        .on_time;
+
+    // bessie is auctioned, then sold to the supplier by the winner.
+    let auction_id = register_auction(&farm, &owner, &cow_data.id, 500).auction_id;
+    farm.client.bidding(&bidder, &auction_id, &600);
+    jump(&farm.env, LEDGER_AMOUNT_IN_12_HOURS + 1);
+    assert_eq!(farm.client.finalize_auction(&auction_id).status, Status::Ok);
+    let appraisal = farm.client.cow_appraisal(&cow_data.id).price;
+    assert_eq!(
+        farm.client.sell_cow(&bidder, &cow_data.id).status,
+        Status::Ok
+    );
+
+    let result = farm.client.get_profile(&owner);
+    assert_eq!(result.status, Status::Ok);
+    assert_eq!(
+        result.data.get(0).unwrap(),
+        FarmerProfile {
+            display_name,
+            join_ledger,
+            cows_bought: 2,
+            cows_sold: 1,
+            auctions_won: 0,
+            on_time_feeds,
+            deaths: 1,
+            earnings: 600 * XLM,
+            achievements: vec![&farm.env, Achievement::FirstCow, Achievement::RaisedCalf],
+        }
+    );
+    let profile = farm.client.get_profile(&bidder).data.get(0).unwrap();
+    assert!(profile.display_name.is_empty());
+    assert_eq!(profile.cows_bought, 0);
+    assert_eq!(profile.cows_sold, 1);
+    assert_eq!(profile.auctions_won, 1);
+    assert_eq!(profile.earnings, appraisal);
+
+    // dead cows are only counted once.
+    farm.client.clean_ownership(&owner);
+    let profile = farm.client.get_profile(&owner).data.get(0).unwrap();
+    assert_eq!(profile.deaths, 1);
+}
+
+#[test]
+fn leaderboards_rank_farm_activity() {
+    let farm = setup();
+    let owner = new_user(&farm);
+    let bidder = new_user(&farm);
+    let result = farm.client.get_leaderboard(&LeaderboardKind::BestFedCow);
+    assert_eq!(result.status, Status::NotFound);
+    assert_eq!(result.season, 1);
+
+    let cow_data = buy_cow(&farm, &owner, symbol_short!("bessie"));
+    let other_cow = buy_cow(&farm, &bidder, symbol_short!("daisy"));
+    set_genome(&farm, &cow_data.id, [0; 16]);
+    set_genome(&farm, &other_cow.id, [0; 16]);
+
+    // equal scores keep their order, a higher score moves up.
+    jump(&farm.env, WELL_FED + 180);
+    farm.client.feed_the_cow(&bidder, &other_cow.id);
+    farm.client.feed_the_cow(&owner, &cow_data.id);
+    jump(&farm.env, WELL_FED + 180);
+    farm.client.feed_the_cow(&owner, &cow_data.id);
+    let result = farm.client.get_leaderboard(&LeaderboardKind::BestFedCow);
+    assert_eq!(result.status, Status::Ok);
+    assert_eq!(
+        result.entries,
+        vec![
+            &farm.env,
+            LeaderboardEntry {
+                owner: owner.clone(),
+                cow_id: cow_data.id.clone(),
+                score: 2,
+            },
+            LeaderboardEntry {
+                owner: bidder.clone(),
+                cow_id: other_cow.id.clone(),
+                score: 1,
+            },
+        ]
+    );
+    let entries = farm
+        .client
+        .get_leaderboard(&LeaderboardKind::RichestHerd)
+        .entries;
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries.get(0).unwrap().owner, owner);
+    assert!(entries.get(0).unwrap().score > entries.get(1).unwrap().score);
+
+    // daisy dies while bessie is raised, then bessie is auctioned to the bidder.
+    raise_cow(&farm, &owner, &cow_data.id);
+    let auction_id = register_auction(&farm, &owner, &cow_data.id, 500).auction_id;
+    farm.client.bidding(&bidder, &auction_id, &600);
+    jump(&farm.env, LEDGER_AMOUNT_IN_12_HOURS + 1);
+    assert_eq!(farm.client.finalize_auction(&auction_id).status, Status::Ok);
+
+    // dead and moved cows leave the best fed cow leaderboard.
+    let result = farm.client.get_leaderboard(&LeaderboardKind::BestFedCow);
+    assert_eq!(result.status, Status::Ok);
+    assert!(result.entries.is_empty());
+    let entries = farm
+        .client
+        .get_leaderboard(&LeaderboardKind::RichestHerd)
+        .entries;
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries.get(0).unwrap().owner, bidder);
+    let entries = farm
+        .client
+        .get_leaderboard(&LeaderboardKind::MostAuctionsWon)
+        .entries;
+    assert_eq!(
+        entries,
+        vec![
+            &farm.env,
+            LeaderboardEntry {
+                owner: bidder.clone(),
+                cow_id: String::from_str(&farm.env, ""),
+                score: 1,
+            },
+        ]
+    );
+
+    // a new season starts with empty leaderboards.
+    assert_eq!(farm.client.reset_leaderboards(), Status::Ok);
+    let result = farm
+        .client
+        .get_leaderboard(&LeaderboardKind::MostAuctionsWon);
+    assert_eq!(result.status, Status::NotFound);
+    assert_eq!(result.season, 2);
+}
+
+#[test]
+fn feeding_adds_fed_cow_value_to_herd() {
+    let farm = setup();
+    let user = new_user(&farm);
+    let bessie = buy_cow(&farm, &user, symbol_short!("bessie"));
+    let daisy = buy_cow(&farm, &user, symbol_short!("daisy"));
+    // calves count towards the herd value too, so appraise them directly.
+    let appraisal = |cow_id: &String| {
+        farm.env
+            .as_contract(&farm.contract_id, || get_herd_cow_value(&farm.env, cow_id))
+    };
+    let herd_value = || {
+        let entries = farm
+            .client
+            .get_leaderboard(&LeaderboardKind::RichestHerd)
+            .entries;
+        entries.get(0).unwrap().score
+    };
+
+    // hatching scans the whole herd.
+    let daisy_value = appraisal(&daisy.id);
+    assert_eq!(herd_value(), appraisal(&bessie.id) + daisy_value);
+
+    // feeding only re-appraises the fed cow, so a change to another cow waits for the next scan.
+    set_genome(&farm, &daisy.id, [0; 16]);
+    assert_ne!(appraisal(&daisy.id), daisy_value);
+    jump(&farm.env, WELL_FED + 180);
+    let bessie_value = appraisal(&bessie.id);
+    assert_eq!(
+        farm.client.feed_the_cow(&user, &bessie.id).status,
+        Status::Ok
+    );
+    assert!(appraisal(&bessie.id) > bessie_value);
+    assert_eq!(herd_value(), appraisal(&bessie.id) + daisy_value);
+
+    // a cow leaving the herd scans it again.
+    let friend = new_user(&farm);
+    assert_eq!(
+        farm.client.transfer_cow(&user, &friend, &bessie.id).status,
+        Status::Ok
+    );
+    let entries = farm
+        .client
+        .get_leaderboard(&LeaderboardKind::RichestHerd)
+        .entries;
+    let user_entry = entries.iter().find(|entry| entry.owner == user).unwrap();
+    assert_eq!(user_entry.score, appraisal(&daisy.id));
+}
+
+#[test]
+fn achievements_unlock_once_and_pay_rewards() {
+    let farm = setup();
+    let user = new_user(&farm);
+    assert_eq!(
+        farm.client
+            .set_achievement_reward(&Achievement::RaisedCalf, &(MAX_ACHIEVEMENT_REWARD + 1)),
+        Status::Fail
+    );
+    assert_eq!(
+        farm.client
+            .set_achievement_reward(&Achievement::RaisedCalf, &(5 * XLM)),
+        Status::Saved
+    );
+    assert_eq!(
+        farm.client.get_achievement_reward(&Achievement::RaisedCalf),
+        5 * XLM
+    );
+
+    // the first cow is only celebrated once.
+    let cow_data = buy_cow(&farm, &user, symbol_short!("bessie"));
+    buy_cow(&farm, &user, symbol_short!("daisy"));
+    set_genome(&farm, &cow_data.id, [0; 16]);
+    let profile = farm.client.get_profile(&user).data.get(0).unwrap();
+    assert_eq!(profile.achievements, vec![&farm.env, Achievement::FirstCow]);
+
+    // raising a calf pays its reward from the treasury.
+    let user_balance = farm.token.balance(&user);
+    raise_cow(&farm, &user, &cow_data.id);
+    assert_eq!(farm.token.balance(&user), user_balance + 5 * XLM);
+
+    // keep the streak going until it lasts 30 days.
+    let streak = |farm: &Farm| {
+        let cow_data = farm.client.get_cow(&cow_data.id).data.get(0).unwrap();
+        cow_data.feeding_stats
+    };
+    assert_eq!(streak(&farm).current_streak, streak(&farm).on_time);
+    assert_eq!(streak(&farm).streak_start_ledger, cow_data.born_ledger);
+    let streak_end_ledger = cow_data.born_ledger + STREAK_ACHIEVEMENT_DURATION;
+
+    // feeding as often as possible still takes 30 days.
+    while farm.env.ledger().sequence() + WELL_FED + 1 < streak_end_ledger {
+        jump(&farm.env, WELL_FED + 1);
+        farm.client.feed_the_cow(&user, &cow_data.id);
+    }
+    let profile = farm.client.get_profile(&user).data.get(0).unwrap();
+    assert!(!profile.achievements.contains(Achievement::ThirtyDayStreak));
+    jump(&farm.env, WELL_FED + 1);
+    farm.client.feed_the_cow(&user, &cow_data.id);
+    let streak_length = streak(&farm).current_streak;
+    let profile = farm.client.get_profile(&user).data.get(0).unwrap();
+    assert_eq!(
+        profile.achievements,
+        vec![
+            &farm.env,
+            Achievement::FirstCow,
+            Achievement::RaisedCalf,
+            Achievement::ThirtyDayStreak,
+        ]
+    );
+    let result = farm.client.get_leaderboard(&LeaderboardKind::BestFedCow);
+    assert_eq!(result.entries.get(0).unwrap().score, streak_length as i128);
+
+    // a late feeding breaks the streak, the best streak is kept.
+    jump(&farm.env, ON_TIME_FEED + 1);
+    farm.client.feed_the_cow(&user, &cow_data.id);
+    let feeding_stats = streak(&farm);
+    assert_eq!(feeding_stats.current_streak, 0);
+    assert_eq!(feeding_stats.best_streak, streak_length);
+    let result = farm.client.get_leaderboard(&LeaderboardKind::BestFedCow);
+    assert!(result.entries.is_empty());
+}
+
+#[test]
+fn plot_capacity_limits_cows() {
+    let farm = setup();
+    let user = new_tenant(&farm);
+    let friend = new_user(&farm);
+    let plot_data = farm.client.get_plots(&user).data.get(0).unwrap();
+    assert_eq!(plot_data.capacity, PLOT_CAPACITY[0]);
+
+    // a pending order takes its place on the plot too.
+    let mut herd = std::vec::Vec::new();
+    for name in ["bessie", "daisy", "molly", "rosie"] {
+        herd.push(buy_cow(&farm, &user, Symbol::new(&farm.env, name)));
+    }
+    order_cow(&farm, &user, symbol_short!("lulu"), CowBreed::Jersey);
+    let external_ref = String::from_str(&farm.env, "");
+    let commitment = commitment(&farm.env, &salt(&farm.env));
+    let order = |name: Symbol| {
+        farm.client
+            .order_cow(&user, &name, &external_ref, &CowBreed::Jersey, &commitment)
+            .status
+    };
+    assert_eq!(order(symbol_short!("clover")), Status::NoCapacity);
+
+    // a cow that leaves the farm frees its place.
+    farm.client.transfer_cow(&user, &friend, &herd[0].id);
+    assert_eq!(order(symbol_short!("clover")), Status::Ok);
+
+    // rented plot holds no new cows after its rent is over, until it is renewed.
+    jump(&farm.env, PLOT_RENT_DURATION + 1);
+    assert_eq!(order(symbol_short!("pansy")), Status::NoCapacity);
+    let result = farm.client.renew_plot(&user, &plot_data.id);
+    assert_eq!(result.status, Status::Ok);
+    assert_eq!(
+        result.data.get(0).unwrap().rent_until_ledger,
+        farm.env.ledger().sequence() + PLOT_RENT_DURATION
+    );
+    assert_eq!(order(symbol_short!("pansy")), Status::Ok);
+
+    // owned plot cannot be renewed.
+    let owned_plot = farm.client.get_plots(&friend).data.get(0).unwrap();
+    assert_eq!(
+        farm.client.renew_plot(&friend, &owned_plot.id).status,
+        Status::Fail
+    );
+}
+
+#[test]
+fn auction_bid_holds_a_place_on_the_bidder_plot() {
+    let farm = setup();
+    let owner = new_user(&farm);
+    let cow_data = buy_cow(&farm, &owner, symbol_short!("bessie"));
+    raise_cow(&farm, &owner, &cow_data.id);
+    let auction_id = register_auction(&farm, &owner, &cow_data.id, 500).auction_id;
+    let bidder = new_tenant(&farm);
+    let other_bidder = new_tenant(&farm);
+    for (index, name) in ["daisy", "molly", "rosie", "lulu"].iter().enumerate() {
+        buy_cow(&farm, &bidder, Symbol::new(&farm.env, name));
+        let other_name = std::format!("{}{}", name, index);
+        buy_cow(&farm, &other_bidder, Symbol::new(&farm.env, &other_name));
+    }
+
+    // the highest bid holds the last place on bidder's plot until the auction is finalized.
+    assert_eq!(
+        farm.client.bidding(&bidder, &auction_id, &600).status,
+        Status::Ok
+    );
+    let external_ref = String::from_str(&farm.env, "");
+    let commitment = commitment(&farm.env, &salt(&farm.env));
+    let order = |user: &Address, name: Symbol| {
+        farm.client
+            .order_cow(user, &name, &external_ref, &CowBreed::Jersey, &commitment)
+            .status
+    };
+    assert_eq!(order(&bidder, symbol_short!("clover")), Status::NoCapacity);
+
+    // an outbid bidder gets the place back.
+    assert_eq!(
+        farm.client.bidding(&other_bidder, &auction_id, &700).status,
+        Status::Ok
+    );
+    assert_eq!(
+        order(&other_bidder, symbol_short!("pansy")),
+        Status::NoCapacity
+    );
+    assert_eq!(order(&bidder, symbol_short!("clover")), Status::Ok);
+    assert_eq!(
+        farm.client.bidding(&bidder, &auction_id, &800).status,
+        Status::NoCapacity
+    );
+
+    // the cow moves to the place held for the winner.
+    jump(&farm.env, LEDGER_AMOUNT_IN_12_HOURS + 1);
+    assert_eq!(farm.client.finalize_auction(&auction_id).status, Status::Ok);
+    assert_eq!(
+        farm.client.owner_of(&cow_data.id),
+        Some(other_bidder.clone())
+    );
+    let cow_data = farm.client.get_cow(&cow_data.id).data.get(0).unwrap();
+    let plot_data = farm.client.get_plots(&other_bidder).data.get(0).unwrap();
+    assert_eq!(cow_data.plot_id, plot_data.id);
+    assert_eq!(plot_data.cow_ids.len(), plot_data.capacity);
+
+    // a finalized auction leaves the auction list.
+    let auction_list: Vec<String> = farm.env.as_contract(&farm.contract_id, || {
+        farm.env
+            .storage()
+            .persistent()
+            .get(&DataKey::AuctionList)
+            .unwrap()
+    });
+    assert!(!auction_list.contains(&auction_id));
+}
+
+#[test]
+fn plot_upgrades_extend_feeding_windows() {
+    let farm = setup();
+    let user = new_user(&farm);
+    let cow_data = buy_cow(&farm, &user, symbol_short!("bessie"));
+    set_genome(&farm, &cow_data.id, [0; 16]);
+    let plot_id = cow_data.plot_id.clone();
+    for upgrade in [PlotUpgrade::Barn, PlotUpgrade::Pasture] {
+        let result = farm.client.upgrade_plot(&user, &plot_id, &upgrade);
+        assert_eq!(result.status, Status::Ok);
+        assert_eq!(
+            farm.client.upgrade_plot(&user, &plot_id, &upgrade).status,
+            Status::Duplicate
+        );
+    }
+
+    // late for a cow without a barn and pasture, still on time for this one.
+    let feeding_tolerance = BARN_FEEDING_TOLERANCE + PASTURE_FEEDING_TOLERANCE;
+    jump(&farm.env, ON_TIME_FEED + feeding_tolerance);
+    farm.client.feed_the_cow(&user, &cow_data.id);
+
+    // a cow moved to a plot without upgrades loses the extension.
+    let other_plot = farm
+        .client
+        .buy_plot(&user, &PlotTier::Small)
+        .data
+        .get(0)
+        .unwrap();
+    let result = farm.client.assign_cow(&user, &cow_data.id, &other_plot.id);
+    assert_eq!(result.status, Status::Ok);
+    assert_eq!(
+        farm.client
+            .assign_cow(&user, &cow_data.id, &other_plot.id)
+            .status,
+        Status::Duplicate
+    );
+    jump(&farm.env, ON_TIME_FEED + feeding_tolerance);
+    farm.client.feed_the_cow(&user, &cow_data.id);
+
+    let cow_data = farm.client.get_cow(&cow_data.id).data.get(0).unwrap();
+    assert_eq!(cow_data.plot_id, other_plot.id);
+    assert_eq!(
+        cow_data.feeding_stats,
+        CowFeedingStats {
+            on_time: 1,
+            late: 1,
+            forget: 0,
+            current_streak: 0,
+            best_streak: 1,
+            streak_start_ledger: cow_data.born_ledger,
+        }
+    );
+}
@@ -0,0 +1,476 @@
+// Property tests of the farm economy.
+// every case drives a random sequence of farm actions across several users,
+// and checks the money and ownership invariants after each action.
+
+use super::*;
+use proptest::prelude::*;
+use soroban_sdk::testutils::EnvTestConfig;
+use std::collections::BTreeMap;
+// prop_oneof! with more than ten strategies builds a std vec.
+use std::vec;
+
+const USER_COUNT: usize = 4;
+const TREASURY: i128 = 10_000 * XLM;
+
+// the run starts late enough for cows to be born as adults.
+const START_LEDGER: u32 = LEDGER_AMOUNT_IN_1_MONTH;
+
+// the whole run stays inside one week so no ownership list or name registry expires,
+// leaving time to settle every auction at the end.
+const MAX_ELAPSED_LEDGER: u32 = LEDGER_AMOUNT_IN_1_WEEK - LEDGER_AMOUNT_IN_24_HOURS;
+
+#[derive(Clone, Debug)]
+enum Action {
+    Buy {
+        user: usize,
+        breed: u32,
+        adult: bool,
+    },
+    Feed {
+        user: usize,
+        cow: usize,
+    },
+    FeedAll {
+        user: usize,
+    },
+    Raise {
+        user: usize,
+        cow: usize,
+    },
+    Jump {
+        ledger_amount: u32,
+    },
+    Sell {
+        user: usize,
+        cow: usize,
+    },
+    Register {
+        user: usize,
+        cow: usize,
+        price: u32,
+    },
+    Bid {
+        user: usize,
+        auction: usize,
+        price: u32,
+    },
+    Finalize {
+        auction: usize,
+    },
+    Clean {
+        user: usize,
+    },
+    Plan {
+        user: usize,
+        deposit: u32,
+    },
+    RunPlan {
+        keeper: usize,
+        user: usize,
+    },
+    CancelPlan {
+        user: usize,
+    },
+    Harvest {
+        user: usize,
+    },
+    SellMilk {
+        user: usize,
+        amount: u32,
+    },
+}
+
+fn action() -> impl Strategy<Value = Action> {
+    let user = 0..USER_COUNT;
+    prop_oneof![
+        3 => (user.clone(), 1..=6u32, any::<bool>())
+            .prop_map(|(user, breed, adult)| Action::Buy { user, breed, adult }),
+        4 => (user.clone(), any::<usize>()).prop_map(|(user, cow)| Action::Feed { user, cow }),
+        2 => user.clone().prop_map(|user| Action::FeedAll { user }),
+        3 => (user.clone(), any::<usize>()).prop_map(|(user, cow)| Action::Raise { user, cow }),
+        3 => (1..=ON_TIME_FEED).prop_map(|ledger_amount| Action::Jump { ledger_amount }),
+        2 => (user.clone(), any::<usize>()).prop_map(|(user, cow)| Action::Sell { user, cow }),
+        3 => (user.clone(), any::<usize>(), 1..=2_000u32)
+            .prop_map(|(user, cow, price)| Action::Register { user, cow, price }),
+        4 => (user.clone(), any::<usize>(), 1..=3_000u32)
+            .prop_map(|(user, auction, price)| Action::Bid { user, auction, price }),
+        2 => any::<usize>().prop_map(|auction| Action::Finalize { auction }),
+        1 => user.clone().prop_map(|user| Action::Clean { user }),
+        3 => (user.clone(), 0..=5u32).prop_map(|(user, deposit)| Action::Plan { user, deposit }),
+        3 => (user.clone(), user.clone())
+            .prop_map(|(keeper, user)| Action::RunPlan { keeper, user }),
+        1 => user.clone().prop_map(|user| Action::CancelPlan { user }),
+        3 => user.clone().prop_map(|user| Action::Harvest { user }),
+        2 => (user, 1..=5_000u32).prop_map(|(user, amount)| Action::SellMilk { user, amount }),
+    ]
+}
+
+// what the farm should look like from the outside.
+struct Economy<'a> {
+    farm: Farm<'a>,
+    users: std::vec::Vec<Address>,
+    cow_ids: std::vec::Vec<String>,
+    auction_ids: std::vec::Vec<String>,
+    // highest bid held by the contract for every open auction, in stroops.
+    escrow: BTreeMap<usize, i128>,
+    // feeding plan balance held by the contract for every user, in stroops.
+    deposits: BTreeMap<usize, i128>,
+    treasury: i128,
+    minted: i128,
+    elapsed_ledger: u32,
+}
+
+impl Economy<'_> {
+    fn new() -> Self {
+        // generated cases are not worth a ledger snapshot each.
+        let env = Env::new_with_config(EnvTestConfig {
+            capture_snapshot_at_drop: false,
+        });
+        let farm = setup_at_ledger(env, TREASURY, START_LEDGER);
+        let users = (0..USER_COUNT).map(|_| new_user(&farm)).collect();
+        Economy {
+            farm,
+            users,
+            cow_ids: std::vec::Vec::new(),
+            auction_ids: std::vec::Vec::new(),
+            escrow: BTreeMap::new(),
+            deposits: BTreeMap::new(),
+            treasury: TREASURY + (USER_COUNT as i128) * PLOT_PRICE[2] * XLM,
+            minted: TREASURY + (USER_COUNT as i128) * (100_000 + PLOT_PRICE[2]) * XLM,
+            elapsed_ledger: 0,
+        }
+    }
+
+    fn pick_cow(&self, index: usize) -> Option<String> {
+        if self.cow_ids.is_empty() {
+            return None;
+        }
+        Some(self.cow_ids[index % self.cow_ids.len()].clone())
+    }
+
+    // prefer the cows listed by the user, so most actions get past the ownership check.
+    fn pick_owned_cow(&self, user: usize, index: usize) -> Option<String> {
+        let ownership = self.ownership(user);
+        if ownership.is_empty() {
+            return self.pick_cow(index);
+        }
+        ownership.get(index as u32 % ownership.len())
+    }
+
+    // the first cows listed by the user, as many as a single call accepts.
+    fn owned_cows(&self, user: usize, limit: u32) -> Vec<String> {
+        let ownership = self.ownership(user);
+        ownership.slice(0..ownership.len().min(limit))
+    }
+
+    fn pick_auction(&self, index: usize) -> Option<usize> {
+        if self.auction_ids.is_empty() {
+            return None;
+        }
+        Some(index % self.auction_ids.len())
+    }
+
+    fn jump(&mut self, ledger_amount: u32) -> bool {
+        if self.elapsed_ledger + ledger_amount > MAX_ELAPSED_LEDGER {
+            return false;
+        }
+        self.elapsed_ledger += ledger_amount;
+        jump(&self.farm.env, ledger_amount);
+        true
+    }
+
+    fn apply(&mut self, action: &Action) {
+        match *action {
+            Action::Buy { user, breed, adult } => {
+                let breed = match breed {
+                    1 => CowBreed::Jersey,
+                    2 => CowBreed::Limousin,
+                    3 => CowBreed::Hallikar,
+                    4 => CowBreed::Hereford,
+                    5 => CowBreed::Holstein,
+                    _ => CowBreed::Simmental,
+                };
+                let name = Symbol::new(
+                    &self.farm.env,
+                    &std::format!("cow{}", self.cow_ids.len() + 1),
+                );
+                let external_ref = String::from_str(&self.farm.env, "");
+                let commitment = commitment(&self.farm.env, &salt(&self.farm.env));
+                let result = self.farm.client.order_cow(
+                    &self.users[user],
+                    &name,
+                    &external_ref,
+                    &breed,
+                    &commitment,
+                );
+                if result.status != Status::Ok {
+                    return;
+                }
+                self.treasury += get_cow_base_price_in_stroops(&breed);
+
+                // an order left unhatched at the end of the run is simply forfeited.
+                let cow_id = result.order.get(0).unwrap().cow_id;
+                if !self.jump(HATCH_DELAY) {
+                    return;
+                }
+                let result =
+                    self.farm
+                        .client
+                        .hatch_cow(&self.users[user], &cow_id, &salt(&self.farm.env));
+                assert_eq!(result.status, Status::Ok);
+                if adult {
+                    // adult cow can be auctioned and milked right away.
+                    let current_ledger = self.farm.env.ledger().sequence();
+                    let adult_age = get_cow_life_cycle(&breed)[1];
+                    set_born_ledger(&self.farm, &cow_id, current_ledger - adult_age);
+                }
+                self.cow_ids.push(cow_id);
+            }
+            Action::Feed { user, cow } => {
+                if let Some(cow_id) = self.pick_cow(cow) {
+                    self.farm.client.feed_the_cow(&self.users[user], &cow_id);
+                }
+            }
+            Action::FeedAll { user } => {
+                self.farm.client.feed_all(&self.users[user], &0);
+            }
+            Action::Raise { user, cow } => {
+                let Some(cow_id) = self.pick_owned_cow(user, cow) else {
+                    return;
+                };
+                for _ in 0..12 {
+                    if !self.jump(WELL_FED + 180) {
+                        return;
+                    }
+                    let status = self
+                        .farm
+                        .client
+                        .feed_the_cow(&self.users[user], &cow_id)
+                        .status;
+                    if status != Status::Ok {
+                        return;
+                    }
+                }
+            }
+            Action::Jump { ledger_amount } => {
+                self.jump(ledger_amount);
+            }
+            Action::Sell { user, cow } => {
+                let Some(cow_id) = self.pick_owned_cow(user, cow) else {
+                    return;
+                };
+                let appraisal = self.farm.client.cow_appraisal(&cow_id);
+                let user_balance = self.farm.token.balance(&self.users[user]);
+                let result = self.farm.client.sell_cow(&self.users[user], &cow_id);
+                if result.status == Status::Ok {
+                    assert_eq!(appraisal.status, Status::Ok);
+                    assert!(appraisal.price >= 0);
+                    assert_eq!(
+                        self.farm.token.balance(&self.users[user]),
+                        user_balance + appraisal.price
+                    );
+                    self.treasury -= appraisal.price;
+                }
+            }
+            Action::Register { user, cow, price } => {
+                let Some(cow_id) = self.pick_owned_cow(user, cow) else {
+                    return;
+                };
+                let external_ref = String::from_str(&self.farm.env, "");
+                let result = self.farm.client.register_auction(
+                    &self.users[user],
+                    &cow_id,
+                    &external_ref,
+                    &price,
+                );
+                if result.status == Status::Ok {
+                    self.auction_ids
+                        .push(result.auction_data.get(0).unwrap().auction_id.clone());
+                }
+            }
+            Action::Bid {
+                user,
+                auction,
+                price,
+            } => {
+                let Some(index) = self.pick_auction(auction) else {
+                    return;
+                };
+                let result =
+                    self.farm
+                        .client
+                        .bidding(&self.users[user], &self.auction_ids[index], &price);
+                if result.status == Status::Ok {
+                    // the previous highest bid is refunded.
+                    self.escrow.insert(index, (price as i128) * XLM);
+                }
+            }
+            Action::Finalize { auction } => {
+                let Some(index) = self.pick_auction(auction) else {
+                    return;
+                };
+                let result = self.farm.client.finalize_auction(&self.auction_ids[index]);
+                if result.status == Status::Ok {
+                    // the highest bid goes to the owner, or back to the bidder for a dead cow.
+                    self.escrow.remove(&index);
+                }
+            }
+            Action::Clean { user } => {
+                let result = self.farm.client.clean_ownership(&self.users[user]);
+                if result.status == Status::Ok {
+                    for cow_id in self.ownership(user).iter() {
+                        assert_eq!(self.farm.client.get_cow(&cow_id).status, Status::Ok);
+                    }
+                }
+            }
+            Action::Plan { user, deposit } => {
+                let cow_ids = self.owned_cows(user, MAX_COWS_PER_FEEDING);
+                let result = self.farm.client.register_feeding_plan(
+                    &self.users[user],
+                    &cow_ids,
+                    &(deposit as i128),
+                );
+                if result.status == Status::Saved {
+                    *self.deposits.entry(user).or_insert(0) += (deposit as i128) * XLM;
+                    assert_eq!(result.data.get(0).unwrap().balance, self.deposits[&user]);
+                }
+            }
+            Action::RunPlan { keeper, user } => {
+                // keeper comes by once the planned cows are hungry again.
+                if !self.jump(WELL_FED + 180) {
+                    return;
+                }
+                let result = self
+                    .farm
+                    .client
+                    .run_feeding_plan(&self.users[keeper], &self.users[user]);
+                if result.status == Status::Ok {
+                    // the keeper fee is paid from the plan balance, never from the treasury.
+                    let plan_result = self.farm.client.get_feeding_plan(&self.users[user]);
+                    let plan_data = plan_result.data.get(0).unwrap();
+                    let keeper_fee = self.deposits[&user] - plan_data.balance;
+                    let fed_count = result
+                        .data
+                        .iter()
+                        .filter(|data| data.status == Status::Ok)
+                        .count() as i128;
+                    assert_eq!(keeper_fee, fed_count * FEEDING_PLAN_KEEPER_FEE);
+                    self.deposits.insert(user, plan_data.balance);
+                }
+            }
+            Action::CancelPlan { user } => {
+                let user_balance = self.farm.token.balance(&self.users[user]);
+                let result = self.farm.client.cancel_feeding_plan(&self.users[user]);
+                if result.status == Status::Ok {
+                    let refund = self.deposits.remove(&user).unwrap();
+                    assert_eq!(
+                        self.farm.token.balance(&self.users[user]),
+                        user_balance + refund
+                    );
+                }
+            }
+            Action::Harvest { user } => {
+                let cow_ids = self.owned_cows(user, MAX_COWS_PER_HARVEST);
+                self.farm.client.harvest_milk(&self.users[user], &cow_ids);
+            }
+            Action::SellMilk { user, amount } => {
+                let milk_balance = self
+                    .farm
+                    .client
+                    .get_milk_balance(&self.users[user])
+                    .milk_balance;
+                let amount = (amount as i128).min(milk_balance.max(1));
+                let result = self.farm.client.sell_milk(&self.users[user], &amount);
+                if result.status == Status::Ok {
+                    self.treasury -= amount * MILK_PRICE_PER_MILLILITER;
+                }
+            }
+        }
+    }
+
+    // close every auction, no bid may be left without an owner and no auction left listed.
+    fn settle(&mut self) {
+        jump(&self.farm.env, LEDGER_AMOUNT_IN_12_HOURS + 1);
+        for (index, auction_id) in self.auction_ids.iter().enumerate() {
+            let result = self.farm.client.finalize_auction(auction_id);
+            if self.escrow.remove(&index).is_some() {
+                assert_eq!(result.status, Status::Ok);
+            }
+        }
+        assert_eq!(auction_escrow(&self.farm), 0);
+        let auction_list: Vec<String> = self.farm.env.as_contract(&self.farm.contract_id, || {
+            let storage = self.farm.env.storage().persistent();
+            storage
+                .get(&DataKey::AuctionList)
+                .unwrap_or(Vec::new(&self.farm.env))
+        });
+        assert!(auction_list.is_empty());
+    }
+
+    fn ownership(&self, user: usize) -> Vec<String> {
+        let env = &self.farm.env;
+        env.as_contract(&self.farm.contract_id, || {
+            env.storage()
+                .persistent()
+                .get(&self.users[user])
+                .unwrap_or(Vec::new(env))
+        })
+    }
+
+    fn check_invariants(&self) {
+        let client = &self.farm.client;
+        let token = &self.farm.token;
+
+        // contract balance is the treasury plus the bids and plan deposits held for users.
+        let contract_balance = token.balance(&self.farm.contract_id);
+        let escrow: i128 = self.escrow.values().sum();
+        let deposits: i128 = self.deposits.values().sum();
+        assert_eq!(contract_balance, self.treasury + escrow + deposits);
+        assert_eq!(auction_escrow(&self.farm), escrow);
+        assert_eq!(plan_deposits(&self.farm), deposits);
+
+        // every bid held by the contract still has an auction to be settled by.
+        for index in self.escrow.keys() {
+            let auction_id = &self.auction_ids[*index];
+            assert_eq!(client.get_auction(auction_id).status, Status::Ok);
+        }
+
+        // no money is created or destroyed.
+        let user_balance: i128 = self.users.iter().map(|user| token.balance(user)).sum();
+        assert_eq!(contract_balance + user_balance, self.minted);
+
+        // every cow is listed by one user at most, and alive cows are listed by their owner.
+        let mut listed_by: BTreeMap<std::string::String, usize> = BTreeMap::new();
+        for user in 0..USER_COUNT {
+            for cow_id in self.ownership(user).iter() {
+                let key = std::format!("{:?}", cow_id);
+                assert!(listed_by.insert(key, user).is_none());
+            }
+        }
+        for cow_id in self.cow_ids.iter() {
+            if client.get_cow(cow_id).status != Status::Ok {
+                continue;
+            }
+            let owner = client.owner_of(cow_id).unwrap();
+            let user = listed_by.get(&std::format!("{:?}", cow_id)).unwrap();
+            assert_eq!(owner, self.users[*user]);
+        }
+    }
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(32))]
+
+    #[test]
+    fn farm_economy_keeps_its_invariants(
+        actions in proptest::collection::vec(action(), 1..60)
+    ) {
+        let mut economy = Economy::new();
+        for action in actions.iter() {
+            economy.apply(action);
+            economy.check_invariants();
+        }
+        economy.settle();
+        economy.check_invariants();
+    }
+}
@@ -0,0 +1,634 @@
+// Snapshot tests of the contract interface and event payloads.
+// the contract spec and the events published by every entry point are rendered as text,
+// and compared against the files in src/test/snapshots.
+// run `UPDATE_SNAPSHOTS=1 cargo test` after a deliberate interface change to rewrite them.
+
+use super::*;
+use soroban_sdk::xdr::{
+    ContractEventBody, Limits, ReadXdr, ScAddress, ScSpecEntry, ScSpecEventParamLocationV0,
+    ScSpecTypeDef, ScSpecUdtUnionCaseV0, ScVal,
+};
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::string::{String as StdString, ToString};
+
+fn assert_snapshot(name: &str, actual: &str) {
+    let path = std::format!(
+        "{}/src/test/snapshots/{}.txt",
+        env!("CARGO_MANIFEST_DIR"),
+        name
+    );
+    if std::env::var("UPDATE_SNAPSHOTS").is_ok() {
+        std::fs::write(&path, actual).unwrap();
+        return;
+    }
+
+    let expected = std::fs::read_to_string(&path).unwrap_or_default();
+    if expected == actual {
+        return;
+    }
+    let expected_lines: std::vec::Vec<&str> = expected.lines().collect();
+    let actual_lines: std::vec::Vec<&str> = actual.lines().collect();
+    let line = (0..expected_lines.len().max(actual_lines.len()))
+        .find(|index| expected_lines.get(*index) != actual_lines.get(*index))
+        .unwrap_or(0);
+    panic!(
+        "{} snapshot changed at line {}\n  expected: {}\n  actual:   {}\n\
+         run `UPDATE_SNAPSHOTS=1 cargo test` if the change is deliberate",
+        name,
+        line + 1,
+        expected_lines.get(line).unwrap_or(&"<end of file>"),
+        actual_lines.get(line).unwrap_or(&"<end of file>"),
+    );
+}
+
+// every entry point, contract type and event, in declaration order.
+// new ones must be added here to be covered by the snapshot.
+fn spec_entries() -> std::vec::Vec<std::vec::Vec<u8>> {
+    std::vec![
+        CowContract::spec_xdr_init().to_vec(),
+        CowContract::spec_xdr_upgrade().to_vec(),
+        CowContract::spec_xdr_bump_instance().to_vec(),
+        CowContract::spec_xdr_health_check().to_vec(),
+        CowContract::spec_xdr_open_donation().to_vec(),
+        CowContract::spec_xdr_order_cow().to_vec(),
+        CowContract::spec_xdr_hatch_cow().to_vec(),
+        CowContract::spec_xdr_get_cow_order().to_vec(),
+        CowContract::spec_xdr_sell_cow().to_vec(),
+        CowContract::spec_xdr_cow_appraisal().to_vec(),
+        CowContract::spec_xdr_feed_the_cow().to_vec(),
+        CowContract::spec_xdr_feed_many().to_vec(),
+        CowContract::spec_xdr_feed_all().to_vec(),
+        CowContract::spec_xdr_approve_caretaker().to_vec(),
+        CowContract::spec_xdr_revoke_caretaker().to_vec(),
+        CowContract::spec_xdr_feed_as_caretaker().to_vec(),
+        CowContract::spec_xdr_register_feeding_plan().to_vec(),
+        CowContract::spec_xdr_cancel_feeding_plan().to_vec(),
+        CowContract::spec_xdr_run_feeding_plan().to_vec(),
+        CowContract::spec_xdr_get_feeding_plan().to_vec(),
+        CowContract::spec_xdr_harvest_milk().to_vec(),
+        CowContract::spec_xdr_sell_milk().to_vec(),
+        CowContract::spec_xdr_get_milk_balance().to_vec(),
+        CowContract::spec_xdr_get_cow().to_vec(),
+        CowContract::spec_xdr_get_traits().to_vec(),
+        CowContract::spec_xdr_get_cow_by_name().to_vec(),
+        CowContract::spec_xdr_get_owner_of().to_vec(),
+        CowContract::spec_xdr_get_cows().to_vec(),
+        CowContract::spec_xdr_check_ownership().to_vec(),
+        CowContract::spec_xdr_clean_ownership().to_vec(),
+        CowContract::spec_xdr_migrate_cow().to_vec(),
+        CowContract::spec_xdr_get_all_cow().to_vec(),
+        CowContract::spec_xdr_transfer_cow().to_vec(),
+        CowContract::spec_xdr_offer_gift().to_vec(),
+        CowContract::spec_xdr_accept_gift().to_vec(),
+        CowContract::spec_xdr_decline_gift().to_vec(),
+        CowContract::spec_xdr_rename_cow().to_vec(),
+        CowContract::spec_xdr_set_cow_metadata().to_vec(),
+        CowContract::spec_xdr_get_cow_metadata().to_vec(),
+        CowContract::spec_xdr_set_moderator().to_vec(),
+        CowContract::spec_xdr_set_name_restriction().to_vec(),
+        CowContract::spec_xdr_get_name_restriction().to_vec(),
+        CowContract::spec_xdr_extend_my_farm().to_vec(),
+        CowContract::spec_xdr_extend_entries().to_vec(),
+        CowContract::spec_xdr_get_entries_ttl().to_vec(),
+        CowContract::spec_xdr_get_farm_ttl().to_vec(),
+        CowContract::spec_xdr_register_auction().to_vec(),
+        CowContract::spec_xdr_bidding().to_vec(),
+        CowContract::spec_xdr_finalize_auction().to_vec(),
+        CowContract::spec_xdr_get_auction().to_vec(),
+        CowContract::spec_xdr_get_auctions().to_vec(),
+        CowContract::spec_xdr_get_all_auction().to_vec(),
+        CowContract::spec_xdr_buy_plot().to_vec(),
+        CowContract::spec_xdr_rent_plot().to_vec(),
+        CowContract::spec_xdr_renew_plot().to_vec(),
+        CowContract::spec_xdr_upgrade_plot().to_vec(),
+        CowContract::spec_xdr_assign_cow().to_vec(),
+        CowContract::spec_xdr_get_plot().to_vec(),
+        CowContract::spec_xdr_get_plots().to_vec(),
+        CowContract::spec_xdr_set_display_name().to_vec(),
+        CowContract::spec_xdr_get_profile().to_vec(),
+        CowContract::spec_xdr_get_leaderboard().to_vec(),
+        CowContract::spec_xdr_reset_leaderboards().to_vec(),
+        CowContract::spec_xdr_set_achievement_reward().to_vec(),
+        CowContract::spec_xdr_get_achievement_reward().to_vec(),
+        CowContract::spec_xdr_name().to_vec(),
+        CowContract::spec_xdr_symbol().to_vec(),
+        CowContract::spec_xdr_set_base_uri().to_vec(),
+        CowContract::spec_xdr_token_uri().to_vec(),
+        CowContract::spec_xdr_owner_of().to_vec(),
+        CowContract::spec_xdr_balance_of().to_vec(),
+        CowContract::spec_xdr_approve().to_vec(),
+        CowContract::spec_xdr_get_approved().to_vec(),
+        CowContract::spec_xdr_set_approval_for_all().to_vec(),
+        CowContract::spec_xdr_is_approved_for_all().to_vec(),
+        CowContract::spec_xdr_transfer_from().to_vec(),
+        CowStatus::spec_xdr().to_vec(),
+        FeedManyResult::spec_xdr().to_vec(),
+        BuyCowResult::spec_xdr().to_vec(),
+        CowOrderResult::spec_xdr().to_vec(),
+        SellCowResult::spec_xdr().to_vec(),
+        CowAppraisalResult::spec_xdr().to_vec(),
+        GetAllCowResult::spec_xdr().to_vec(),
+        GetCowResult::spec_xdr().to_vec(),
+        OwnerResult::spec_xdr().to_vec(),
+        OwnershipCheckResult::spec_xdr().to_vec(),
+        CowData::spec_xdr().to_vec(),
+        LegacyCowData::spec_xdr().to_vec(),
+        LegacyCowFeedingStats::spec_xdr().to_vec(),
+        CowTraits::spec_xdr().to_vec(),
+        CowTraitsResult::spec_xdr().to_vec(),
+        CowOrder::spec_xdr().to_vec(),
+        CowFeedingStats::spec_xdr().to_vec(),
+        CowCondition::spec_xdr().to_vec(),
+        CowEventDetails::spec_xdr().to_vec(),
+        FeedManyEventDetails::spec_xdr().to_vec(),
+        CaretakerData::spec_xdr().to_vec(),
+        FeedingPlanData::spec_xdr().to_vec(),
+        FeedingPlanResult::spec_xdr().to_vec(),
+        FeedingPlanEventDetails::spec_xdr().to_vec(),
+        MilkResult::spec_xdr().to_vec(),
+        MilkEventDetails::spec_xdr().to_vec(),
+        GiftData::spec_xdr().to_vec(),
+        ApprovalData::spec_xdr().to_vec(),
+        TransferEventDetails::spec_xdr().to_vec(),
+        AuctionData::spec_xdr().to_vec(),
+        Bidder::spec_xdr().to_vec(),
+        AuctionEventDetails::spec_xdr().to_vec(),
+        AuctionResult::spec_xdr().to_vec(),
+        CowMetadata::spec_xdr().to_vec(),
+        CowMetadataResult::spec_xdr().to_vec(),
+        RenameEventDetails::spec_xdr().to_vec(),
+        EntryTtl::spec_xdr().to_vec(),
+        EntryTtlResult::spec_xdr().to_vec(),
+        NameRuleEventDetails::spec_xdr().to_vec(),
+        InitEventDetails::spec_xdr().to_vec(),
+        UpgradeEventDetails::spec_xdr().to_vec(),
+        BumpEventDetails::spec_xdr().to_vec(),
+        DonationEventDetails::spec_xdr().to_vec(),
+        PlotData::spec_xdr().to_vec(),
+        PlotResult::spec_xdr().to_vec(),
+        FarmerProfile::spec_xdr().to_vec(),
+        ProfileResult::spec_xdr().to_vec(),
+        LeaderboardEntry::spec_xdr().to_vec(),
+        LeaderboardResult::spec_xdr().to_vec(),
+        SeasonEventDetails::spec_xdr().to_vec(),
+        AchievementEventDetails::spec_xdr().to_vec(),
+        DataKey::spec_xdr().to_vec(),
+        Status::spec_xdr().to_vec(),
+        CowBreed::spec_xdr().to_vec(),
+        CowGender::spec_xdr().to_vec(),
+        CowLifeStage::spec_xdr().to_vec(),
+        CowCoat::spec_xdr().to_vec(),
+        CowHorns::spec_xdr().to_vec(),
+        CowSize::spec_xdr().to_vec(),
+        CowRarity::spec_xdr().to_vec(),
+        PlotTier::spec_xdr().to_vec(),
+        PlotUpgrade::spec_xdr().to_vec(),
+        LeaderboardKind::spec_xdr().to_vec(),
+        Achievement::spec_xdr().to_vec(),
+        AuctionFilter::spec_xdr().to_vec(),
+        NameRestriction::spec_xdr().to_vec(),
+        FarmEntry::spec_xdr().to_vec(),
+        InitEvent::spec_xdr().to_vec(),
+        UpgradeEvent::spec_xdr().to_vec(),
+        BumpInstanceEvent::spec_xdr().to_vec(),
+        DonationEvent::spec_xdr().to_vec(),
+        OrderEvent::spec_xdr().to_vec(),
+        BuyEvent::spec_xdr().to_vec(),
+        MigrateEvent::spec_xdr().to_vec(),
+        SellEvent::spec_xdr().to_vec(),
+        FeedEvent::spec_xdr().to_vec(),
+        FeedManyEvent::spec_xdr().to_vec(),
+        CaretakerEvent::spec_xdr().to_vec(),
+        RevokeEvent::spec_xdr().to_vec(),
+        PlanEvent::spec_xdr().to_vec(),
+        PlanStopEvent::spec_xdr().to_vec(),
+        PlanRunEvent::spec_xdr().to_vec(),
+        HarvestEvent::spec_xdr().to_vec(),
+        SellMilkEvent::spec_xdr().to_vec(),
+        GiftEvent::spec_xdr().to_vec(),
+        GiftDenyEvent::spec_xdr().to_vec(),
+        TransferEvent::spec_xdr().to_vec(),
+        RenameEvent::spec_xdr().to_vec(),
+        NameRuleEvent::spec_xdr().to_vec(),
+        RegisterEvent::spec_xdr().to_vec(),
+        RefundEvent::spec_xdr().to_vec(),
+        AuctionEvent::spec_xdr().to_vec(),
+        NoBidEvent::spec_xdr().to_vec(),
+        DeadCowEvent::spec_xdr().to_vec(),
+        PlotEvent::spec_xdr().to_vec(),
+        SeasonEvent::spec_xdr().to_vec(),
+        AchievementEvent::spec_xdr().to_vec(),
+        NftTransferEvent::spec_xdr().to_vec(),
+        ApproveEvent::spec_xdr().to_vec(),
+        ApproveForAllEvent::spec_xdr().to_vec(),
+    ]
+}
+
+fn render_type(type_def: &ScSpecTypeDef) -> StdString {
+    match type_def {
+        ScSpecTypeDef::Option(option) => {
+            std::format!("Option<{}>", render_type(&option.value_type))
+        }
+        ScSpecTypeDef::Result(result) => std::format!(
+            "Result<{}, {}>",
+            render_type(&result.ok_type),
+            render_type(&result.error_type)
+        ),
+        ScSpecTypeDef::Vec(vec) => std::format!("Vec<{}>", render_type(&vec.element_type)),
+        ScSpecTypeDef::Map(map) => std::format!(
+            "Map<{}, {}>",
+            render_type(&map.key_type),
+            render_type(&map.value_type)
+        ),
+        ScSpecTypeDef::Tuple(tuple) => {
+            let types: std::vec::Vec<StdString> =
+                tuple.value_types.iter().map(render_type).collect();
+            std::format!("({})", types.join(", "))
+        }
+        ScSpecTypeDef::BytesN(bytes) => std::format!("BytesN<{}>", bytes.n),
+        ScSpecTypeDef::Udt(udt) => udt.name.to_string(),
+        primitive => std::format!("{:?}", primitive),
+    }
+}
+
+fn render_spec_entry(entry: &ScSpecEntry) -> StdString {
+    let mut out = StdString::new();
+    match entry {
+        ScSpecEntry::FunctionV0(function) => {
+            let inputs: std::vec::Vec<StdString> = function
+                .inputs
+                .iter()
+                .map(|input| std::format!("{}: {}", input.name, render_type(&input.type_)))
+                .collect();
+            let outputs: std::vec::Vec<StdString> =
+                function.outputs.iter().map(render_type).collect();
+            write!(
+                out,
+                "fn {}({})",
+                function.name.to_utf8_string_lossy(),
+                inputs.join(", ")
+            )
+            .unwrap();
+            if !outputs.is_empty() {
+                write!(out, " -> {}", outputs.join(", ")).unwrap();
+            }
+            out.push('\n');
+        }
+        ScSpecEntry::UdtStructV0(udt) => {
+            writeln!(out, "struct {} {{", udt.name).unwrap();
+            for field in udt.fields.iter() {
+                writeln!(out, "    {}: {},", field.name, render_type(&field.type_)).unwrap();
+            }
+            out.push_str("}\n");
+        }
+        ScSpecEntry::UdtUnionV0(udt) => {
+            writeln!(out, "union {} {{", udt.name).unwrap();
+            for case in udt.cases.iter() {
+                match case {
+                    ScSpecUdtUnionCaseV0::VoidV0(case) => {
+                        writeln!(out, "    {},", case.name).unwrap()
+                    }
+                    ScSpecUdtUnionCaseV0::TupleV0(case) => {
+                        let types: std::vec::Vec<StdString> =
+                            case.type_.iter().map(render_type).collect();
+                        writeln!(out, "    {}({}),", case.name, types.join(", ")).unwrap()
+                    }
+                }
+            }
+            out.push_str("}\n");
+        }
+        ScSpecEntry::UdtEnumV0(udt) => {
+            writeln!(out, "enum {} {{", udt.name).unwrap();
+            for case in udt.cases.iter() {
+                writeln!(out, "    {} = {},", case.name, case.value).unwrap();
+            }
+            out.push_str("}\n");
+        }
+        ScSpecEntry::EventV0(event) => {
+            let prefix: std::vec::Vec<StdString> = event
+                .prefix_topics
+                .iter()
+                .map(|topic| topic.to_string())
+                .collect();
+            writeln!(
+                out,
+                "event {} [{}] {:?} {{",
+                event.name.to_utf8_string_lossy(),
+                prefix.join(", "),
+                event.data_format
+            )
+            .unwrap();
+            for param in event.params.iter() {
+                let location = match param.location {
+                    ScSpecEventParamLocationV0::TopicList => "topic",
+                    ScSpecEventParamLocationV0::Data => "data",
+                };
+                writeln!(
+                    out,
+                    "    {} {}: {},",
+                    location,
+                    param.name,
+                    render_type(&param.type_)
+                )
+                .unwrap();
+            }
+            out.push_str("}\n");
+        }
+        other => writeln!(out, "{:?}", other).unwrap(),
+    }
+    out
+}
+
+#[test]
+fn contract_spec_matches_snapshot() {
+    let mut out = StdString::new();
+    for xdr in spec_entries() {
+        let entry = ScSpecEntry::from_xdr(xdr, Limits::none()).unwrap();
+        out.push_str(&render_spec_entry(&entry));
+    }
+    assert_snapshot("contract_spec", &out);
+}
+
+// renders the events published by the last invocation, with known addresses by their name.
+struct EventLog<'a> {
+    farm: &'a Farm<'a>,
+    names: BTreeMap<StdString, &'static str>,
+    out: StdString,
+}
+
+impl<'a> EventLog<'a> {
+    fn new(farm: &'a Farm<'a>) -> Self {
+        let mut event_log = EventLog {
+            farm,
+            names: BTreeMap::new(),
+            out: StdString::new(),
+        };
+        event_log.name(&farm.contract_id, "farm");
+        event_log.name(&farm.admin, "admin");
+        event_log.name(&farm.token.address, "native_token");
+        event_log
+    }
+
+    fn name(&mut self, address: &Address, name: &'static str) {
+        self.names.insert(address.to_string().to_string(), name);
+    }
+
+    fn record(&mut self, call: &str) {
+        writeln!(self.out, "{}", call).unwrap();
+        let events = self
+            .farm
+            .env
+            .events()
+            .all()
+            .filter_by_contract(&self.farm.contract_id);
+        if events.events().is_empty() {
+            self.out.push_str("    no event\n");
+        }
+        for event in events.events() {
+            let ContractEventBody::V0(body) = &event.body;
+            let topics: std::vec::Vec<StdString> =
+                body.topics.iter().map(|topic| self.render(topic)).collect();
+            writeln!(self.out, "    topics: ({})", topics.join(", ")).unwrap();
+            writeln!(self.out, "    data: {}", self.render(&body.data)).unwrap();
+        }
+    }
+
+    fn render(&self, value: &ScVal) -> StdString {
+        match value {
+            ScVal::Bool(value) => value.to_string(),
+            ScVal::Void => "()".to_string(),
+            ScVal::U32(value) => value.to_string(),
+            ScVal::I32(value) => value.to_string(),
+            ScVal::U64(value) => value.to_string(),
+            ScVal::I64(value) => value.to_string(),
+            ScVal::I128(parts) => (((parts.hi as i128) << 64) | parts.lo as i128).to_string(),
+            ScVal::U128(parts) => (((parts.hi as u128) << 64) | parts.lo as u128).to_string(),
+            ScVal::String(value) => std::format!("{:?}", value.to_utf8_string_lossy()),
+            ScVal::Symbol(value) => value.to_utf8_string_lossy(),
+            ScVal::Bytes(bytes) => bytes
+                .iter()
+                .map(|byte| std::format!("{:02x}", byte))
+                .collect(),
+            ScVal::Address(address) => self.render_address(address),
+            ScVal::Vec(Some(vec)) => {
+                let items: std::vec::Vec<StdString> =
+                    vec.iter().map(|item| self.render(item)).collect();
+                std::format!("[{}]", items.join(", "))
+            }
+            ScVal::Map(Some(map)) => {
+                let entries: std::vec::Vec<StdString> = map
+                    .iter()
+                    .map(|entry| {
+                        std::format!("{}: {}", self.render(&entry.key), self.render(&entry.val))
+                    })
+                    .collect();
+                std::format!("{{{}}}", entries.join(", "))
+            }
+            other => std::format!("{:?}", other),
+        }
+    }
+
+    fn render_address(&self, address: &ScAddress) -> StdString {
+        let strkey = address.to_string();
+        match self.names.get(&strkey) {
+            Some(name) => name.to_string(),
+            None => strkey,
+        }
+    }
+}
+
+fn cow_ids(farm: &Farm, cows: &[&CowData]) -> Vec<String> {
+    let mut result = Vec::new(&farm.env);
+    for cow_data in cows {
+        result.push_back(cow_data.id.clone());
+    }
+    result
+}
+
+// upgrade is left out, it needs an uploaded contract Wasm.
+#[test]
+fn events_match_snapshot() {
+    let farm = setup();
+    let mut event_log = EventLog::new(&farm);
+    event_log.record("init");
+
+    let alice = new_user(&farm);
+    let bob = new_user(&farm);
+    let carol = new_user(&farm);
+    let keeper = new_user(&farm);
+    let moderator = Address::generate(&farm.env);
+    event_log.name(&alice, "alice");
+    event_log.name(&bob, "bob");
+    event_log.name(&carol, "carol");
+    event_log.name(&keeper, "keeper");
+    event_log.name(&moderator, "moderator");
+
+    farm.client.bump_instance(&LEDGER_AMOUNT_IN_1_MONTH);
+    event_log.record("bump_instance");
+    farm.client.open_donation(&alice, &25);
+    event_log.record("open_donation");
+    farm.client
+        .set_achievement_reward(&Achievement::FirstCow, &(5 * XLM));
+    event_log.record("set_achievement_reward");
+
+    let order = order_cow(&farm, &alice, symbol_short!("bessie"), CowBreed::Jersey);
+    event_log.record("order_cow");
+    jump(&farm.env, HATCH_DELAY);
+    let result = farm
+        .client
+        .hatch_cow(&alice, &order.cow_id, &salt(&farm.env));
+    event_log.record("hatch_cow");
+    let bessie = result.cow_data.get(0).unwrap();
+    let mut herd = std::vec![bessie];
+    for name in ["daisy", "molly", "rosie", "lulu", "clover"] {
+        herd.push(buy_cow(&farm, &alice, Symbol::new(&farm.env, name)));
+    }
+
+    // land plots.
+    let plot_data = farm
+        .client
+        .buy_plot(&alice, &PlotTier::Small)
+        .data
+        .get(0)
+        .unwrap();
+    event_log.record("buy_plot");
+    farm.client
+        .upgrade_plot(&alice, &plot_data.id, &PlotUpgrade::Barn);
+    event_log.record("upgrade_plot");
+    farm.client.assign_cow(&alice, &herd[5].id, &plot_data.id);
+    event_log.record("assign_cow");
+    let plot_data = farm
+        .client
+        .rent_plot(&carol, &PlotTier::Medium)
+        .data
+        .get(0)
+        .unwrap();
+    event_log.record("rent_plot");
+    farm.client.renew_plot(&carol, &plot_data.id);
+    event_log.record("renew_plot");
+
+    let female = herd
+        .iter()
+        .find(|cow_data| cow_data.gender == CowGender::Female)
+        .unwrap()
+        .clone();
+
+    // feeding.
+    jump(&farm.env, WELL_FED + 180);
+    farm.client.feed_the_cow(&alice, &herd[0].id);
+    event_log.record("feed_the_cow");
+    farm.client.feed_many(&alice, &cow_ids(&farm, &[&herd[1]]));
+    event_log.record("feed_many");
+    let expiry_ledger = farm.env.ledger().sequence() + LEDGER_AMOUNT_IN_1_WEEK;
+    farm.client.approve_caretaker(
+        &alice,
+        &carol,
+        &cow_ids(&farm, &[&herd[2]]),
+        &false,
+        &expiry_ledger,
+    );
+    event_log.record("approve_caretaker");
+    farm.client
+        .feed_as_caretaker(&carol, &alice, &cow_ids(&farm, &[&herd[2]]));
+    event_log.record("feed_as_caretaker");
+    farm.client.revoke_caretaker(&alice, &carol);
+    event_log.record("revoke_caretaker");
+    farm.client
+        .register_feeding_plan(&alice, &cow_ids(&farm, &[&herd[3]]), &10);
+    event_log.record("register_feeding_plan");
+    farm.client.run_feeding_plan(&keeper, &alice);
+    event_log.record("run_feeding_plan");
+    farm.client.cancel_feeding_plan(&alice);
+    event_log.record("cancel_feeding_plan");
+    farm.client.feed_all(&alice, &0);
+    event_log.record("feed_all");
+
+    // names and metadata.
+    farm.client
+        .rename_cow(&alice, &herd[0].id, &symbol_short!("bella"));
+    event_log.record("rename_cow");
+    let metadata = CowMetadata {
+        description: String::from_str(&farm.env, "loves apples"),
+        color: String::from_str(&farm.env, "brown"),
+        pattern: String::from_str(&farm.env, "spotted"),
+    };
+    farm.client.set_cow_metadata(&alice, &herd[0].id, &metadata);
+    event_log.record("set_cow_metadata");
+    farm.client.set_moderator(&moderator);
+    event_log.record("set_moderator");
+    farm.client
+        .set_name_restriction(&moderator, &symbol_short!("king"), &NameRestriction::Banned);
+    event_log.record("set_name_restriction");
+
+    // gifts and transfers.
+    farm.client.offer_gift(&alice, &bob, &herd[5].id);
+    event_log.record("offer_gift");
+    farm.client.decline_gift(&bob, &herd[5].id);
+    event_log.record("decline_gift");
+    farm.client.offer_gift(&alice, &bob, &herd[5].id);
+    farm.client.accept_gift(&bob, &herd[5].id);
+    event_log.record("accept_gift");
+    farm.client.transfer_cow(&bob, &alice, &herd[5].id);
+    event_log.record("transfer_cow");
+    farm.client
+        .approve(&alice, &bob, &herd[5].id, &expiry_ledger);
+    event_log.record("approve");
+    farm.client.set_approval_for_all(&alice, &carol, &true);
+    event_log.record("set_approval_for_all");
+    farm.client.transfer_from(&bob, &alice, &bob, &herd[5].id);
+    event_log.record("transfer_from");
+
+    // milk from a grown female cow.
+    while farm.env.ledger().sequence() < female.born_ledger + JERSEY_LIFE_CYCLE[1] {
+        jump(&farm.env, WELL_FED + 180);
+        farm.client.feed_all(&alice, &0);
+        farm.client.feed_all(&bob, &0);
+    }
+    let milk = farm
+        .client
+        .harvest_milk(&alice, &cow_ids(&farm, &[&female]));
+    event_log.record("harvest_milk");
+    farm.client.sell_milk(&alice, &milk.milk_balance);
+    event_log.record("sell_milk");
+
+    // auction with bids.
+    let auction_data = register_auction(&farm, &alice, &herd[0].id, 500);
+    event_log.record("register_auction");
+    farm.client.bidding(&bob, &auction_data.auction_id, &600);
+    event_log.record("bidding");
+    farm.client.bidding(&carol, &auction_data.auction_id, &700);
+    event_log.record("bidding outbid");
+    jump(&farm.env, LEDGER_AMOUNT_IN_12_HOURS + 1);
+    farm.client.finalize_auction(&auction_data.auction_id);
+    event_log.record("finalize_auction");
+
+    // auction without bid.
+    farm.client.feed_all(&alice, &0);
+    let auction_data = register_auction(&farm, &alice, &herd[1].id, 500);
+    jump(&farm.env, LEDGER_AMOUNT_IN_12_HOURS + 1);
+    farm.client.finalize_auction(&auction_data.auction_id);
+    event_log.record("finalize_auction no bid");
+
+    farm.client.sell_cow(&alice, &herd[1].id);
+    event_log.record("sell_cow");
+
+    // auction of a cow that dies before it is finalized.
+    farm.client.feed_all(&alice, &0);
+    jump(&farm.env, WELL_FED);
+    let auction_data = register_auction(&farm, &alice, &herd[2].id, 500);
+    farm.client.bidding(&bob, &auction_data.auction_id, &600);
+    jump(&farm.env, LEDGER_AMOUNT_IN_24_HOURS - WELL_FED + 1);
+    farm.client.finalize_auction(&auction_data.auction_id);
+    event_log.record("finalize_auction dead cow");
+
+    // cow saved by the first contract version.
+    let cow_id = legacy_cow(&farm, &carol, "COW-LEGACY", symbol_short!("buttercup"));
+    farm.client.migrate_cow(&carol, &cow_id);
+    event_log.record("migrate_cow");
+
+    farm.client.reset_leaderboards();
+    event_log.record("reset_leaderboards");
+
+    assert_snapshot("events", &event_log.out);
+}
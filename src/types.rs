@@ -1,5 +1,9 @@
-use crate::enums::{CowBreed, CowGender, Status};
-use soroban_sdk::{contracttype, Address, Env, String, Symbol, Vec};
+use crate::constants::{INITIAL_HEALTH, INITIAL_MOOD, INITIAL_WEIGHT};
+use crate::enums::{
+    Achievement, CowBreed, CowCoat, CowGender, CowHorns, CowLifeStage, CowRarity, CowSize,
+    FarmEntry, NameRestriction, PlotTier, Status,
+};
+use soroban_sdk::{contracttype, Address, BytesN, Env, String, Symbol, Vec};
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
@@ -17,6 +21,25 @@ impl CowStatus {
     }
 }
 
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub struct FeedManyResult {
+    pub status: Status,
+    pub data: Vec<CowStatus>,
+    // ownership list index where the next feed_all call should start, 0 when every cow is covered.
+    pub next: u32,
+}
+
+impl FeedManyResult {
+    pub fn new(env: Env, return_status: Status) -> Self {
+        Self {
+            status: return_status,
+            data: Vec::new(&env),
+            next: 0,
+        }
+    }
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 pub struct BuyCowResult {
@@ -35,6 +58,22 @@ impl BuyCowResult {
     }
 }
 
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub struct CowOrderResult {
+    pub status: Status,
+    pub order: Vec<CowOrder>,
+}
+
+impl CowOrderResult {
+    pub fn new(env: Env, return_status: Status) -> Self {
+        Self {
+            status: return_status,
+            order: Vec::new(&env),
+        }
+    }
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 pub struct SellCowResult {
@@ -74,17 +113,142 @@ pub struct GetAllCowResult {
     pub data: Vec<CowData>,
 }
 
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub struct GetCowResult {
+    pub status: Status,
+    pub data: Vec<CowData>,
+}
+
+impl GetCowResult {
+    pub fn new(env: Env, return_status: Status) -> Self {
+        Self {
+            status: return_status,
+            data: Vec::new(&env),
+        }
+    }
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub struct OwnerResult {
+    pub status: Status,
+    pub owner: Vec<Address>,
+}
+
+impl OwnerResult {
+    pub fn new(env: Env, return_status: Status) -> Self {
+        Self {
+            status: return_status,
+            owner: Vec::new(&env),
+        }
+    }
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub struct OwnershipCheckResult {
+    pub status: Status,
+    pub dead_cow_ids: Vec<String>,
+    pub missing_owner_ids: Vec<String>,
+    pub wrong_owner_ids: Vec<String>,
+}
+
+impl OwnershipCheckResult {
+    pub fn new(env: Env, return_status: Status) -> Self {
+        Self {
+            status: return_status,
+            dead_cow_ids: Vec::new(&env),
+            missing_owner_ids: Vec::new(&env),
+            wrong_owner_ids: Vec::new(&env),
+        }
+    }
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 pub struct CowData {
     pub id: String,
+    pub external_ref: String,
     pub name: Symbol,
     pub breed: CowBreed,
     pub gender: CowGender,
     pub born_ledger: u32,
     pub last_fed_ledger: u32,
     pub feeding_stats: CowFeedingStats,
+    pub condition: CowCondition,
+    pub last_milked_ledger: u32,
+    pub life_stage: CowLifeStage,
+    pub last_renamed_ledger: u32,
     pub auction_id: String,
+    pub genome: BytesN<16>,
+    pub plot_id: String,
+}
+
+// cow data layout saved by the first contract version, only read by migrate_cow
+// and when its older auction is closed.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub struct LegacyCowData {
+    pub id: String,
+    pub name: Symbol,
+    pub breed: CowBreed,
+    pub gender: CowGender,
+    pub born_ledger: u32,
+    pub last_fed_ledger: u32,
+    pub feeding_stats: LegacyCowFeedingStats,
+    pub auction_id: String,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub struct LegacyCowFeedingStats {
+    pub on_time: u32,
+    pub late: u32,
+    pub forget: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub struct CowTraits {
+    pub genome: BytesN<16>,
+    pub coat: CowCoat,
+    pub horns: CowHorns,
+    pub size: CowSize,
+    pub rarity: CowRarity,
+    pub feeding_tolerance: u32,
+    pub milk_yield_bonus: i128,
+    pub appraisal_bonus: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub struct CowTraitsResult {
+    pub status: Status,
+    pub data: Vec<CowTraits>,
+}
+
+impl CowTraitsResult {
+    pub fn new(env: Env, return_status: Status) -> Self {
+        Self {
+            status: return_status,
+            data: Vec::new(&env),
+        }
+    }
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub struct CowOrder {
+    pub cow_id: String,
+    pub external_ref: String,
+    pub owner: Address,
+    pub name: Symbol,
+    pub breed: CowBreed,
+    pub plot_id: String,
+    pub commitment: BytesN<32>,
+    pub order_ledger: u32,
+    pub hatch_ledger: u32,
 }
 
 #[contracttype]
@@ -93,6 +257,9 @@ pub struct CowFeedingStats {
     pub on_time: u32,
     pub late: u32,
     pub forget: u32,
+    pub current_streak: u32,
+    pub best_streak: u32,
+    pub streak_start_ledger: u32,
 }
 
 impl CowFeedingStats {
@@ -101,10 +268,43 @@ impl CowFeedingStats {
             on_time: 0,
             late: 0,
             forget: 0,
+            current_streak: 0,
+            best_streak: 0,
+            streak_start_ledger: 0,
         }
     }
 }
 
+impl Default for CowFeedingStats {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub struct CowCondition {
+    pub weight: u32,
+    pub health: u32,
+    pub mood: u32,
+}
+
+impl CowCondition {
+    pub fn new() -> Self {
+        Self {
+            weight: INITIAL_WEIGHT,
+            health: INITIAL_HEALTH,
+            mood: INITIAL_MOOD,
+        }
+    }
+}
+
+impl Default for CowCondition {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 pub struct CowEventDetails {
@@ -112,11 +312,139 @@ pub struct CowEventDetails {
     pub name: Symbol,
     pub owner: Address,
     pub last_fed_ledger: u32,
+    pub condition: CowCondition,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub struct FeedManyEventDetails {
+    pub owner: Address,
+    pub cow_ids: Vec<String>,
+    pub last_fed_ledger: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub struct CaretakerData {
+    pub owner: Address,
+    pub caretaker: Address,
+    pub cow_ids: Vec<String>,
+    pub all_cows: bool,
+    pub expiry_ledger: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub struct FeedingPlanData {
+    pub owner: Address,
+    pub cow_ids: Vec<String>,
+    pub balance: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub struct FeedingPlanResult {
+    pub status: Status,
+    pub data: Vec<FeedingPlanData>,
+}
+
+impl FeedingPlanResult {
+    pub fn new(env: Env, return_status: Status) -> Self {
+        Self {
+            status: return_status,
+            data: Vec::new(&env),
+        }
+    }
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub struct FeedingPlanEventDetails {
+    pub owner: Address,
+    pub keeper: Address,
+    pub cow_ids: Vec<String>,
+    pub keeper_fee: i128,
+    pub remaining_balance: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub struct MilkResult {
+    pub status: Status,
+    pub amount: i128,
+    pub milk_balance: i128,
+    pub data: Vec<CowStatus>,
+}
+
+impl MilkResult {
+    pub fn new(env: Env, return_status: Status) -> Self {
+        Self {
+            status: return_status,
+            amount: 0,
+            milk_balance: 0,
+            data: Vec::new(&env),
+        }
+    }
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub struct MilkEventDetails {
+    pub owner: Address,
+    pub cow_ids: Vec<String>,
+    pub amount: i128,
+    pub price: i128,
+    pub milk_balance: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub struct GiftData {
+    pub cow_id: String,
+    pub from: Address,
+    pub to: Address,
+    pub offered_ledger: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub struct ApprovalData {
+    pub owner: Address,
+    pub approved: Address,
+    pub expiry_ledger: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub struct TransferEventDetails {
+    pub cow_id: String,
+    pub name: Symbol,
+    pub from: Address,
+    pub to: Address,
 }
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 pub struct AuctionData {
+    pub auction_id: String,
+    pub external_ref: String,
+    pub cow_id: String,
+    pub cow_name: Symbol,
+    pub cow_breed: CowBreed,
+    pub cow_gender: CowGender,
+    pub cow_born_ledger: u32,
+    pub owner: Address,
+    pub start_price: i128,
+    pub highest_bidder: Bidder,
+    pub bid_history: Vec<Bidder>,
+    pub auction_limit_ledger: u32,
+}
+
+// auction data layout saved in temporary storage by the first contract version,
+// only read to close the auction.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub struct LegacyAuctionData {
     pub auction_id: String,
     pub cow_id: String,
     pub cow_name: Symbol,
@@ -164,3 +492,213 @@ impl AuctionResult {
         }
     }
 }
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub struct CowMetadata {
+    pub description: String,
+    pub color: String,
+    pub pattern: String,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub struct CowMetadataResult {
+    pub status: Status,
+    pub data: Vec<CowMetadata>,
+}
+
+impl CowMetadataResult {
+    pub fn new(env: Env, return_status: Status) -> Self {
+        Self {
+            status: return_status,
+            data: Vec::new(&env),
+        }
+    }
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub struct RenameEventDetails {
+    pub cow_id: String,
+    pub old_name: Symbol,
+    pub new_name: Symbol,
+    pub owner: Address,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub struct EntryTtl {
+    pub entry: FarmEntry,
+    pub live_until_ledger: u32,
+    pub remaining_ledger: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub struct EntryTtlResult {
+    pub status: Status,
+    pub data: Vec<EntryTtl>,
+}
+
+impl EntryTtlResult {
+    pub fn new(env: Env, return_status: Status) -> Self {
+        Self {
+            status: return_status,
+            data: Vec::new(&env),
+        }
+    }
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub struct NameRuleEventDetails {
+    pub moderator: Address,
+    pub cow_name: Symbol,
+    pub restriction: NameRestriction,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub struct InitEventDetails {
+    pub admin: Address,
+    pub native_token: Address,
+    pub initialized_ledger: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub struct UpgradeEventDetails {
+    pub admin: Address,
+    pub new_wasm_hash: BytesN<32>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub struct BumpEventDetails {
+    pub admin: Address,
+    pub ledger_amount: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub struct DonationEventDetails {
+    pub from: Address,
+    pub amount: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub struct PlotData {
+    pub id: String,
+    pub owner: Address,
+    pub tier: PlotTier,
+    pub capacity: u32,
+    pub cow_ids: Vec<String>,
+    pub rent_until_ledger: u32,
+    pub barn: bool,
+    pub pasture: bool,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub struct PlotResult {
+    pub status: Status,
+    pub data: Vec<PlotData>,
+}
+
+impl PlotResult {
+    pub fn new(env: Env, return_status: Status) -> Self {
+        Self {
+            status: return_status,
+            data: Vec::new(&env),
+        }
+    }
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub struct FarmerProfile {
+    pub display_name: String,
+    pub join_ledger: u32,
+    pub cows_bought: u32,
+    pub cows_sold: u32,
+    pub auctions_won: u32,
+    pub on_time_feeds: u32,
+    pub deaths: u32,
+    pub earnings: i128,
+    pub achievements: Vec<Achievement>,
+}
+
+impl FarmerProfile {
+    pub fn new(env: &Env) -> Self {
+        Self {
+            display_name: String::from_str(env, ""),
+            join_ledger: env.ledger().sequence(),
+            cows_bought: 0,
+            cows_sold: 0,
+            auctions_won: 0,
+            on_time_feeds: 0,
+            deaths: 0,
+            earnings: 0,
+            achievements: Vec::new(env),
+        }
+    }
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub struct ProfileResult {
+    pub status: Status,
+    pub data: Vec<FarmerProfile>,
+}
+
+impl ProfileResult {
+    pub fn new(env: Env, return_status: Status) -> Self {
+        Self {
+            status: return_status,
+            data: Vec::new(&env),
+        }
+    }
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub struct LeaderboardEntry {
+    pub owner: Address,
+    pub cow_id: String,
+    pub score: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub struct LeaderboardResult {
+    pub status: Status,
+    pub season: u32,
+    pub entries: Vec<LeaderboardEntry>,
+}
+
+impl LeaderboardResult {
+    pub fn new(env: Env, return_status: Status) -> Self {
+        Self {
+            status: return_status,
+            season: 0,
+            entries: Vec::new(&env),
+        }
+    }
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub struct SeasonEventDetails {
+    pub admin: Address,
+    pub season: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub struct AchievementEventDetails {
+    pub owner: Address,
+    pub achievement: Achievement,
+    pub reward: i128,
+}